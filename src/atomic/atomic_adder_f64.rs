@@ -0,0 +1,228 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Striped 64-bit Floating Point Adder
+//!
+//! Provides a striped adder of 64-bit floating point values that scales
+//! under heavy write contention, similar to Java's `DoubleAdder`.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+use crate::atomic::striped::Striped;
+use crate::atomic::AtomicF64;
+
+/// Striped adder of 64-bit floating point values.
+///
+/// The equivalent of Java's `DoubleAdder`. Updates are spread over an array
+/// of cache-padded [`AtomicF64`] cells, one per hardware thread. Since
+/// floating-point addition is a CAS loop, a thread whose update had to be
+/// retried moves to another cell, so hot adders spread out on their own.
+///
+/// `sum` is not an atomic snapshot, and because floating-point addition is
+/// not associative, the result may differ in the last bits from adding the
+/// same values sequentially. For the same reasons as the integer adders,
+/// this type does not implement `Atomic` or `AtomicNumber`.
+///
+/// The policy `P` selects the orderings of the cell operations, as for
+/// [`AtomicAdderI64`](crate::atomic::AtomicAdderI64).
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicAdderF64;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let latency = Arc::new(AtomicAdderF64::new());
+/// let mut handles = vec![];
+///
+/// for _ in 0..10 {
+///     let latency = latency.clone();
+///     let handle = thread::spawn(move || {
+///         for _ in 0..100 {
+///             latency.add(0.5);
+///         }
+///     });
+///     handles.push(handle);
+/// }
+///
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// assert_eq!(latency.sum(), 500.0);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct AtomicAdderF64<P = DefaultOrdering> {
+    cells: Striped<AtomicF64<P>>,
+}
+
+impl AtomicAdderF64 {
+    /// Creates a new adder with a sum of zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicAdderF64;
+    ///
+    /// let adder = AtomicAdderF64::new();
+    /// assert_eq!(adder.sum(), 0.0);
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_policy()
+    }
+}
+
+impl<P: OrderingPolicy> AtomicAdderF64<P> {
+    /// Creates a new adder with a sum of zero, using the ordering policy
+    /// `P`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicAdderF64, RelaxedCounter};
+    ///
+    /// let adder = AtomicAdderF64::<RelaxedCounter>::with_policy();
+    /// adder.add(1.5);
+    /// assert_eq!(adder.sum(), 1.5);
+    /// ```
+    #[inline]
+    pub fn with_policy() -> Self {
+        Self {
+            cells: Striped::new(|| AtomicF64::with_policy(0.0)),
+        }
+    }
+
+    /// Adds a value to the sum.
+    ///
+    /// If the update of the current thread's cell had to be retried, the
+    /// thread moves to another cell for its next update.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - The value to add.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicAdderF64;
+    ///
+    /// let adder = AtomicAdderF64::new();
+    /// adder.add(1.5);
+    /// adder.add(2.5);
+    /// assert_eq!(adder.sum(), 4.0);
+    /// ```
+    #[inline]
+    pub fn add(&self, x: f64) {
+        let (_, attempts) = self.cells.cell().fetch_update_counted(|v| v + x);
+        if attempts > 1 {
+            self.cells.rehash();
+        }
+    }
+
+    /// Returns the current sum of all cells.
+    ///
+    /// The cells are read one after another, so updates running
+    /// concurrently with this call may or may not be included.
+    ///
+    /// # Returns
+    ///
+    /// The sum of all cells.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicAdderF64;
+    ///
+    /// let adder = AtomicAdderF64::new();
+    /// adder.add(3.0);
+    /// assert_eq!(adder.sum(), 3.0);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> f64 {
+        self.cells.iter().map(|cell| cell.load()).sum()
+    }
+
+    /// Resets the sum to zero.
+    ///
+    /// Only meaningful when there are no concurrent updates; otherwise some
+    /// of them may be lost or survive the reset.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicAdderF64;
+    ///
+    /// let adder = AtomicAdderF64::new();
+    /// adder.add(3.0);
+    /// adder.reset();
+    /// assert_eq!(adder.sum(), 0.0);
+    /// ```
+    #[inline]
+    pub fn reset(&self) {
+        for cell in self.cells.iter() {
+            cell.store(0.0);
+        }
+    }
+
+    /// Returns the current sum and resets it to zero.
+    ///
+    /// Each cell is atomically swapped with zero, so every update is counted
+    /// exactly once across successive calls, even with concurrent updates.
+    ///
+    /// # Returns
+    ///
+    /// The sum before the reset.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicAdderF64;
+    ///
+    /// let adder = AtomicAdderF64::new();
+    /// adder.add(3.0);
+    /// assert_eq!(adder.sum_then_reset(), 3.0);
+    /// assert_eq!(adder.sum(), 0.0);
+    /// ```
+    #[inline]
+    pub fn sum_then_reset(&self) -> f64 {
+        self.cells.iter().map(|cell| cell.swap(0.0)).sum()
+    }
+}
+
+impl Default for AtomicAdderF64 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: OrderingPolicy> fmt::Debug for AtomicAdderF64<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicAdderF64")
+            .field("sum", &self.sum())
+            .finish()
+    }
+}
+
+impl<P: OrderingPolicy> fmt::Display for AtomicAdderF64<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.sum())
+    }
+}
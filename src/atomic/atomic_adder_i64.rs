@@ -0,0 +1,26 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Striped 64-bit Signed Integer Adder
+//!
+//! Provides a striped adder of 64-bit signed integers that scales under
+//! heavy write contention, similar to Java's `LongAdder`.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+use crate::atomic::striped::Striped;
+use crate::atomic::AtomicI64;
+
+impl_atomic_adder!(AtomicAdderI64, AtomicI64, i64, "64-bit signed integer");
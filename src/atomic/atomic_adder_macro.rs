@@ -0,0 +1,276 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Adder Macro
+//!
+//! Provides a macro to generate striped integer adders with consistent
+//! implementations.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+/// Macro to generate striped integer adders.
+///
+/// # Parameters
+///
+/// * `$name` - The name of the adder type (e.g., `AtomicAdderI64`)
+/// * `$cell_type` - The atomic integer type of the cells (e.g., `AtomicI64`)
+/// * `$value_type` - The value type (e.g., `i64`)
+/// * `$doc_type` - The type description for documentation (e.g., "64-bit
+///   signed integer")
+macro_rules! impl_atomic_adder {
+    ($name:ident, $cell_type:ident, $value_type:ty, $doc_type:expr) => {
+        #[doc = concat!("Striped adder of ", $doc_type, "s.")]
+        ///
+        /// The equivalent of Java's `LongAdder`. Updates are spread over
+        /// an array of cache-padded
+        #[doc = concat!("[`", stringify!($cell_type), "`](crate::atomic::", stringify!($cell_type), ")")]
+        /// cells, one per hardware thread, so that threads incrementing
+        /// the same adder rarely touch the same cache line. Under
+        /// contention this scales far better than a single atomic
+        /// counter, at the cost of more memory and a slower `sum`.
+        ///
+        /// `sum` is not an atomic snapshot: updates that run concurrently
+        /// with it may or may not be included. For this reason the adder
+        /// does not implement `Atomic` or `AtomicNumber`, whose
+        /// compare-and-set operations cannot be provided on a striped
+        /// value. Use an adder for statistics that are written often and
+        /// read rarely, and a plain atomic integer for values that drive
+        /// control flow.
+        ///
+        /// The policy `P` selects the orderings of the cell operations:
+        /// `ARITHMETIC` for updates, `LOAD` for `sum`, `STORE` for `reset`
+        /// and `RMW` for `sum_then_reset`. Overflow wraps around, as with
+        /// `fetch_add`.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+        /// use std::sync::Arc;
+        /// use std::thread;
+        ///
+        #[doc = concat!("let requests = Arc::new(", stringify!($name), "::new());")]
+        /// let mut handles = vec![];
+        ///
+        /// for _ in 0..10 {
+        ///     let requests = requests.clone();
+        ///     let handle = thread::spawn(move || {
+        ///         for _ in 0..100 {
+        ///             requests.inc();
+        ///         }
+        ///     });
+        ///     handles.push(handle);
+        /// }
+        ///
+        /// for handle in handles {
+        ///     handle.join().unwrap();
+        /// }
+        ///
+        /// assert_eq!(requests.sum(), 1000);
+        /// ```
+        ///
+        /// # Author
+        ///
+        /// Haixing Hu
+        pub struct $name<P = DefaultOrdering> {
+            cells: Striped<$cell_type<P>>,
+        }
+
+        impl $name {
+            /// Creates a new adder with a sum of zero.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let adder = ", stringify!($name), "::new();")]
+            /// assert_eq!(adder.sum(), 0);
+            /// ```
+            #[inline]
+            pub fn new() -> Self {
+                Self::with_policy()
+            }
+        }
+
+        impl<P: OrderingPolicy> $name<P> {
+            /// Creates a new adder with a sum of zero, using the ordering
+            /// policy `P`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::{", stringify!($name), ", RelaxedCounter};")]
+            ///
+            #[doc = concat!("let adder = ", stringify!($name), "::<RelaxedCounter>::with_policy();")]
+            /// adder.add(5);
+            /// assert_eq!(adder.sum(), 5);
+            /// ```
+            #[inline]
+            pub fn with_policy() -> Self {
+                Self {
+                    cells: Striped::new(|| $cell_type::with_policy(0)),
+                }
+            }
+
+            /// Adds a value to the sum.
+            ///
+            /// # Parameters
+            ///
+            /// * `x` - The value to add.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let adder = ", stringify!($name), "::new();")]
+            /// adder.add(10);
+            /// adder.add(5);
+            /// assert_eq!(adder.sum(), 15);
+            /// ```
+            #[inline]
+            pub fn add(&self, x: $value_type) {
+                self.cells.cell().fetch_add(x);
+            }
+
+            /// Adds one to the sum.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let adder = ", stringify!($name), "::new();")]
+            /// adder.inc();
+            /// assert_eq!(adder.sum(), 1);
+            /// ```
+            #[inline]
+            pub fn inc(&self) {
+                self.cells.cell().fetch_inc();
+            }
+
+            /// Subtracts one from the sum.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let adder = ", stringify!($name), "::new();")]
+            /// adder.add(2);
+            /// adder.dec();
+            /// assert_eq!(adder.sum(), 1);
+            /// ```
+            #[inline]
+            pub fn dec(&self) {
+                self.cells.cell().fetch_dec();
+            }
+
+            /// Returns the current sum of all cells.
+            ///
+            /// The cells are read one after another, so updates running
+            /// concurrently with this call may or may not be included. If
+            /// there are no concurrent updates, the result is exact.
+            ///
+            /// # Returns
+            ///
+            /// The sum of all cells, wrapping around on overflow.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let adder = ", stringify!($name), "::new();")]
+            /// adder.add(3);
+            /// assert_eq!(adder.sum(), 3);
+            /// ```
+            #[inline]
+            pub fn sum(&self) -> $value_type {
+                self.cells
+                    .iter()
+                    .fold(0, |sum, cell| sum.wrapping_add(cell.load()))
+            }
+
+            /// Resets the sum to zero.
+            ///
+            /// Only meaningful when there are no concurrent updates;
+            /// otherwise some of them may be lost or survive the reset.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let adder = ", stringify!($name), "::new();")]
+            /// adder.add(3);
+            /// adder.reset();
+            /// assert_eq!(adder.sum(), 0);
+            /// ```
+            #[inline]
+            pub fn reset(&self) {
+                for cell in self.cells.iter() {
+                    cell.store(0);
+                }
+            }
+
+            /// Returns the current sum and resets it to zero.
+            ///
+            /// Each cell is atomically swapped with zero, so every update is
+            /// counted exactly once across successive calls, even with
+            /// concurrent updates. This makes it suitable for periodically
+            /// draining a statistics counter.
+            ///
+            /// # Returns
+            ///
+            /// The sum before the reset.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let adder = ", stringify!($name), "::new();")]
+            /// adder.add(3);
+            /// assert_eq!(adder.sum_then_reset(), 3);
+            /// assert_eq!(adder.sum(), 0);
+            /// ```
+            #[inline]
+            pub fn sum_then_reset(&self) -> $value_type {
+                self.cells
+                    .iter()
+                    .fold(0, |sum, cell| sum.wrapping_add(cell.swap(0)))
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<P: OrderingPolicy> fmt::Debug for $name<P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("sum", &self.sum())
+                    .finish()
+            }
+        }
+
+        impl<P: OrderingPolicy> fmt::Display for $name<P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.sum())
+            }
+        }
+    };
+}
@@ -0,0 +1,26 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Striped 64-bit Unsigned Integer Adder
+//!
+//! Provides a striped adder of 64-bit unsigned integers that scales under
+//! heavy write contention, similar to Java's `LongAdder`.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+use crate::atomic::striped::Striped;
+use crate::atomic::AtomicU64;
+
+impl_atomic_adder!(AtomicAdderU64, AtomicU64, u64, "64-bit unsigned integer");
@@ -0,0 +1,35 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic bfloat16 Floating Point
+//!
+//! Provides an easy-to-use atomic bfloat16 floating point type
+//! with sensible default memory orderings. Implemented using bit conversion
+//! with AtomicU16, exposing the value as `f32`.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::atomic::AtomicU16;
+use std::sync::atomic::Ordering;
+
+use crate::atomic::backoff::Backoff;
+use crate::atomic::float_cmp::approx_eq;
+use crate::atomic::float_cmp::select_max;
+use crate::atomic::float_cmp::select_min;
+use crate::atomic::half_float::bf16_bits_to_f32;
+use crate::atomic::half_float::f32_to_bf16_bits;
+use crate::atomic::nan_policy::NanPolicy;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_half_float!(AtomicBf16, "bfloat16", f32_to_bf16_bits, bf16_bits_to_f32);
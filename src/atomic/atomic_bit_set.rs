@@ -0,0 +1,562 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Bit Set
+//!
+//! Provides a fixed-size atomic bit set with per-bit operations, e.g. for
+//! slot allocators.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+
+use crate::atomic::atomic_u64::AtomicU64;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+/// The number of bits of a word.
+const WORD_BITS: usize = 64;
+
+/// Atomic bit set of `N` bits.
+///
+/// Every bit can be set, cleared and tested atomically. The bits are stored
+/// in an array of [`AtomicU64`](crate::atomic::AtomicU64) words, bit `i`
+/// being bit `i % 64` of word `i / 64`, and the per-bit operations are
+/// single `fetch_or`/`fetch_and` operations on a word.
+///
+/// The set also works as a lock-free slot allocator:
+/// [`acquire_free_slot`](Self::acquire_free_slot) claims a clear bit and
+/// [`release_slot`](Self::release_slot) clears it again.
+///
+/// The orderings are those of the words under the policy `P`: the per-bit
+/// operations use the `BIT_OP` ordering (`AcqRel` with the default policy),
+/// so that a thread acquiring a slot observes the writes made by the thread
+/// that released it, and reads use the `LOAD` ordering.
+///
+/// # Consistency
+///
+/// Every operation on a single bit is atomic. The operations reading several
+/// words, i.e. [`count_ones`](Self::count_ones),
+/// [`first_set_bit`](Self::first_set_bit),
+/// [`first_clear_bit`](Self::first_clear_bit) and [`iter`](Self::iter),
+/// read the words one after the other, so they may miss concurrent updates
+/// of words read earlier.
+///
+/// # Panics
+///
+/// The operations taking a bit index panic if the index is not less than
+/// `N`, like slice indexing.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicBitSet;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let slots = Arc::new(AtomicBitSet::<100>::new());
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let slots = slots.clone();
+///         thread::spawn(move || slots.acquire_free_slot().unwrap())
+///     })
+///     .collect();
+/// let mut acquired: Vec<_> =
+///     handles.into_iter().map(|h| h.join().unwrap()).collect();
+/// acquired.sort();
+/// // Every thread got its own slot
+/// assert_eq!(acquired, vec![0, 1, 2, 3]);
+///
+/// slots.release_slot(2);
+/// assert_eq!(slots.iter().collect::<Vec<_>>(), vec![0, 1, 3]);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct AtomicBitSet<const N: usize, P = DefaultOrdering> {
+    words: Box<[AtomicU64<P>]>,
+}
+
+impl<const N: usize> AtomicBitSet<N> {
+    /// Creates a new atomic bit set with all bits clear.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<10>::new();
+    /// assert_eq!(set.count_ones(), 0);
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_policy()
+    }
+}
+
+impl<const N: usize, P: OrderingPolicy> AtomicBitSet<N, P> {
+    /// The number of words storing `N` bits.
+    const WORDS: usize = (N + WORD_BITS - 1) / WORD_BITS;
+
+    /// Creates a new atomic bit set with all bits clear, using the ordering
+    /// policy `P`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicBitSet, SeqCst};
+    ///
+    /// let set = AtomicBitSet::<10, SeqCst>::with_policy();
+    /// assert!(!set.test_bit(3));
+    /// ```
+    #[inline]
+    pub fn with_policy() -> Self {
+        let words = (0..Self::WORDS)
+            .map(|_| AtomicU64::with_policy(0))
+            .collect();
+        Self { words }
+    }
+
+    /// Returns the number of bits of the set.
+    ///
+    /// # Returns
+    ///
+    /// `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<100>::new();
+    /// assert_eq!(set.capacity(), 100);
+    /// ```
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the word holding bit `index` and the mask of the bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    #[inline]
+    fn locate(&self, index: usize) -> (&AtomicU64<P>, u64) {
+        assert!(
+            index < N,
+            "bit index {} out of range for an AtomicBitSet of {} bits",
+            index,
+            N
+        );
+        (&self.words[index / WORD_BITS], 1 << (index % WORD_BITS))
+    }
+
+    /// Returns the mask of the bits of word `word` which belong to the set.
+    #[inline]
+    fn valid_mask(word: usize) -> u64 {
+        let remaining = N - word * WORD_BITS;
+        if remaining >= WORD_BITS {
+            u64::MAX
+        } else {
+            (1 << remaining) - 1
+        }
+    }
+
+    /// Tests whether a bit is set.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The index of the bit.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the bit is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<10>::new();
+    /// set.set_bit(3);
+    /// assert!(set.test_bit(3));
+    /// assert!(!set.test_bit(4));
+    /// ```
+    #[inline]
+    pub fn test_bit(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        word.load() & mask != 0
+    }
+
+    /// Sets a bit.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The index of the bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<10>::new();
+    /// set.set_bit(9);
+    /// assert!(set.test_bit(9));
+    /// ```
+    #[inline]
+    pub fn set_bit(&self, index: usize) {
+        self.test_and_set_bit(index);
+    }
+
+    /// Clears a bit.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The index of the bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<10>::new();
+    /// set.set_bit(9);
+    /// set.clear_bit(9);
+    /// assert!(!set.test_bit(9));
+    /// ```
+    #[inline]
+    pub fn clear_bit(&self, index: usize) {
+        self.test_and_clear_bit(index);
+    }
+
+    /// Sets a bit, returning whether it was already set.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The index of the bit.
+    ///
+    /// # Returns
+    ///
+    /// The previous state of the bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<10>::new();
+    /// assert!(!set.test_and_set_bit(5));
+    /// assert!(set.test_and_set_bit(5));
+    /// ```
+    #[inline]
+    pub fn test_and_set_bit(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        word.fetch_or(mask) & mask != 0
+    }
+
+    /// Clears a bit, returning whether it was set.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The index of the bit.
+    ///
+    /// # Returns
+    ///
+    /// The previous state of the bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<10>::new();
+    /// set.set_bit(5);
+    /// assert!(set.test_and_clear_bit(5));
+    /// assert!(!set.test_and_clear_bit(5));
+    /// ```
+    #[inline]
+    pub fn test_and_clear_bit(&self, index: usize) -> bool {
+        let (word, mask) = self.locate(index);
+        word.fetch_and(!mask) & mask != 0
+    }
+
+    /// Returns the index of the first set bit.
+    ///
+    /// # Returns
+    ///
+    /// The smallest index of a set bit, or `None` if no bit is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<200>::new();
+    /// assert_eq!(set.first_set_bit(), None);
+    /// set.set_bit(150);
+    /// assert_eq!(set.first_set_bit(), Some(150));
+    /// ```
+    #[inline]
+    pub fn first_set_bit(&self) -> Option<usize> {
+        self.words.iter().enumerate().find_map(|(i, word)| {
+            let bits = word.load();
+            (bits != 0).then(|| i * WORD_BITS + bits.trailing_zeros() as usize)
+        })
+    }
+
+    /// Returns the index of the first clear bit.
+    ///
+    /// # Returns
+    ///
+    /// The smallest index of a clear bit, or `None` if all bits are set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<2>::new();
+    /// set.set_bit(0);
+    /// assert_eq!(set.first_clear_bit(), Some(1));
+    /// set.set_bit(1);
+    /// assert_eq!(set.first_clear_bit(), None);
+    /// ```
+    #[inline]
+    pub fn first_clear_bit(&self) -> Option<usize> {
+        self.words.iter().enumerate().find_map(|(i, word)| {
+            let clear = !word.load() & Self::valid_mask(i);
+            (clear != 0).then(|| i * WORD_BITS + clear.trailing_zeros() as usize)
+        })
+    }
+
+    /// Returns the number of set bits.
+    ///
+    /// # Returns
+    ///
+    /// The number of set bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<100>::new();
+    /// set.set_bit(1);
+    /// set.set_bit(99);
+    /// assert_eq!(set.count_ones(), 2);
+    /// ```
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.load().count_ones() as usize)
+            .sum()
+    }
+
+    /// Clears all bits.
+    ///
+    /// Each word is cleared atomically, but not all words at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<100>::new();
+    /// set.set_bit(1);
+    /// set.set_bit(99);
+    /// set.clear();
+    /// assert_eq!(set.count_ones(), 0);
+    /// ```
+    #[inline]
+    pub fn clear(&self) {
+        for word in self.words.iter() {
+            word.store(0);
+        }
+    }
+
+    /// Acquires a free slot, i.e. sets a clear bit, returning its index.
+    ///
+    /// Lock-free: the bit is claimed with a `fetch_or` on its word, which
+    /// is retried only if another thread claimed the same bit first. Slots
+    /// with smaller indexes are preferred.
+    ///
+    /// # Returns
+    ///
+    /// The index of the acquired slot, or `None` if all slots are in use.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let slots = AtomicBitSet::<2>::new();
+    /// assert_eq!(slots.acquire_free_slot(), Some(0));
+    /// assert_eq!(slots.acquire_free_slot(), Some(1));
+    /// assert_eq!(slots.acquire_free_slot(), None);
+    /// ```
+    pub fn acquire_free_slot(&self) -> Option<usize> {
+        for (i, word) in self.words.iter().enumerate() {
+            let valid = Self::valid_mask(i);
+            let mut bits = word.load();
+            loop {
+                let clear = !bits & valid;
+                if clear == 0 {
+                    break;
+                }
+                let mask = clear & clear.wrapping_neg();
+                let old = word.fetch_or(mask);
+                if old & mask == 0 {
+                    return Some(i * WORD_BITS + mask.trailing_zeros() as usize);
+                }
+                // Another thread claimed the bit first
+                bits = old | mask;
+            }
+        }
+        None
+    }
+
+    /// Releases a slot acquired by
+    /// [`acquire_free_slot`](Self::acquire_free_slot), i.e. clears its bit.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - The index of the slot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `N`, or if the slot is not in
+    /// use, which indicates a double release.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let slots = AtomicBitSet::<2>::new();
+    /// let slot = slots.acquire_free_slot().unwrap();
+    /// slots.release_slot(slot);
+    /// assert_eq!(slots.acquire_free_slot(), Some(slot));
+    /// ```
+    #[inline]
+    pub fn release_slot(&self, index: usize) {
+        assert!(
+            self.test_and_clear_bit(index),
+            "slot {} released but not in use",
+            index
+        );
+    }
+
+    /// Returns an iterator over the indexes of the set bits, in increasing
+    /// order.
+    ///
+    /// Each word is read once, when the iterator reaches it.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the set bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBitSet;
+    ///
+    /// let set = AtomicBitSet::<200>::new();
+    /// for i in [3, 64, 199] {
+    ///     set.set_bit(i);
+    /// }
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![3, 64, 199]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> BitSetIter<'_, N, P> {
+        BitSetIter {
+            set: self,
+            word: 0,
+            bits: 0,
+        }
+    }
+}
+
+impl<const N: usize> Default for AtomicBitSet<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const N: usize, P: OrderingPolicy> IntoIterator for &'a AtomicBitSet<N, P> {
+    type Item = usize;
+    type IntoIter = BitSetIter<'a, N, P>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const N: usize, P: OrderingPolicy> fmt::Debug for AtomicBitSet<N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Iterator over the indexes of the set bits of an [`AtomicBitSet`].
+///
+/// Created by [`AtomicBitSet::iter`].
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct BitSetIter<'a, const N: usize, P = DefaultOrdering> {
+    set: &'a AtomicBitSet<N, P>,
+    /// The index of the next word to read.
+    word: usize,
+    /// The bits of the previous word not yet returned.
+    bits: u64,
+}
+
+impl<const N: usize, P: OrderingPolicy> Iterator for BitSetIter<'_, N, P> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        while self.bits == 0 {
+            let word = self.set.words.get(self.word)?;
+            self.bits = word.load();
+            self.word += 1;
+        }
+        let bit = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        Some((self.word - 1) * WORD_BITS + bit)
+    }
+}
@@ -0,0 +1,781 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Bit Fields
+//!
+//! Provides the `atomic_bitfield!` macro, which packs several small fields,
+//! e.g. a generation, an index and a state, into one atomic integer and
+//! generates typed accessors instead of hand-written masks.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+
+use crate::atomic::atomic_u32::AtomicU32;
+use crate::atomic::atomic_u64::AtomicU64;
+use crate::atomic::traits::Atomic;
+
+/// Integer types that can hold packed bit fields.
+///
+/// Implemented for `u32` and `u64`, whose values are stored in
+/// [`AtomicU32`](crate::atomic::AtomicU32) and
+/// [`AtomicU64`](crate::atomic::AtomicU64) with the default ordering
+/// policy.
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait BitFieldRepr: Copy + Eq + fmt::Debug + fmt::Display {
+    /// The number of bits of the type.
+    const BITS: u32;
+
+    /// The atomic integer type storing values of this type.
+    type Atomic: Atomic<Value = Self>;
+
+    /// Creates an atomic integer holding `value`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial value.
+    ///
+    /// # Returns
+    ///
+    /// The new atomic integer.
+    fn new_atomic(value: Self) -> Self::Atomic;
+
+    /// Converts the value to `u64`.
+    ///
+    /// # Returns
+    ///
+    /// The value as `u64`.
+    fn to_u64(self) -> u64;
+
+    /// Converts a `u64` to this type, truncating the upper bits.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to convert.
+    ///
+    /// # Returns
+    ///
+    /// The lower bits of `value`.
+    fn from_u64(value: u64) -> Self;
+}
+
+impl BitFieldRepr for u32 {
+    const BITS: u32 = u32::BITS;
+
+    type Atomic = AtomicU32;
+
+    #[inline]
+    fn new_atomic(value: Self) -> Self::Atomic {
+        AtomicU32::new(value)
+    }
+
+    #[inline]
+    fn to_u64(self) -> u64 {
+        u64::from(self)
+    }
+
+    #[inline]
+    fn from_u64(value: u64) -> Self {
+        value as u32
+    }
+}
+
+impl BitFieldRepr for u64 {
+    const BITS: u32 = u64::BITS;
+
+    type Atomic = AtomicU64;
+
+    #[inline]
+    fn new_atomic(value: Self) -> Self::Atomic {
+        AtomicU64::new(value)
+    }
+
+    #[inline]
+    fn to_u64(self) -> u64 {
+        self
+    }
+
+    #[inline]
+    fn from_u64(value: u64) -> Self {
+        value
+    }
+}
+
+/// The position of a field in a packed integer.
+///
+/// Generated by [`atomic_bitfield!`] for every field; not meant to be
+/// created by hand.
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BitFieldLayout {
+    name: &'static str,
+    shift: u32,
+    width: u32,
+}
+
+impl BitFieldLayout {
+    /// Creates the layout of a field.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - The name of the field, used in panic messages.
+    /// * `shift` - The position of the lowest bit of the field.
+    /// * `width` - The number of bits of the field.
+    #[doc(hidden)]
+    #[inline]
+    pub const fn new(name: &'static str, shift: u32, width: u32) -> Self {
+        Self { name, shift, width }
+    }
+
+    /// Returns the name of the field.
+    ///
+    /// # Returns
+    ///
+    /// The name of the field.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the position of the lowest bit of the field.
+    ///
+    /// # Returns
+    ///
+    /// The shift of the field.
+    #[inline]
+    pub const fn shift(&self) -> u32 {
+        self.shift
+    }
+
+    /// Returns the number of bits of the field.
+    ///
+    /// # Returns
+    ///
+    /// The width of the field.
+    #[inline]
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the largest value of the field.
+    #[inline]
+    fn max(&self) -> u64 {
+        u64::MAX >> (64 - self.width)
+    }
+
+    /// Extracts the field from a packed integer.
+    ///
+    /// # Parameters
+    ///
+    /// * `bits` - The packed integer.
+    ///
+    /// # Returns
+    ///
+    /// The value of the field.
+    #[doc(hidden)]
+    #[inline]
+    pub fn get<R: BitFieldRepr>(&self, bits: R) -> R {
+        R::from_u64((bits.to_u64() >> self.shift) & self.max())
+    }
+
+    /// Replaces the field in a packed integer.
+    ///
+    /// # Parameters
+    ///
+    /// * `bits` - The packed integer.
+    /// * `value` - The new value of the field.
+    ///
+    /// # Returns
+    ///
+    /// The packed integer with the field replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not fit in the field.
+    #[doc(hidden)]
+    #[inline]
+    pub fn replace<R: BitFieldRepr>(&self, bits: R, value: R) -> R {
+        let max = self.max();
+        assert!(
+            value.to_u64() <= max,
+            "value {} does not fit in the {}-bit field `{}`",
+            value,
+            self.width,
+            self.name,
+        );
+        let bits = bits.to_u64() & !(max << self.shift);
+        R::from_u64(bits | (value.to_u64() << self.shift))
+    }
+}
+
+/// A field of an atomic bit-field struct.
+///
+/// Returned by the per-field accessors generated by [`atomic_bitfield!`].
+/// Every operation reads or updates the field atomically and leaves the
+/// other fields unchanged, even if they are updated concurrently. The
+/// orderings are those of the default ordering policy.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic_bitfield;
+///
+/// atomic_bitfield! {
+///     pub struct AtomicSlot(Slot): u64 {
+///         pub generation: 16,
+///         pub index: 32,
+///     }
+/// }
+///
+/// let slot = AtomicSlot::new(Slot { generation: 1, index: 7 });
+/// slot.generation().fetch_add(1);
+/// assert_eq!(slot.generation().load(), 2);
+/// assert_eq!(slot.index().load(), 7);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct BitField<'a, R: BitFieldRepr> {
+    atomic: &'a R::Atomic,
+    layout: BitFieldLayout,
+}
+
+impl<'a, R: BitFieldRepr> BitField<'a, R> {
+    /// Creates a field of an atomic packed integer.
+    ///
+    /// # Parameters
+    ///
+    /// * `atomic` - The atomic packed integer.
+    /// * `layout` - The layout of the field.
+    #[doc(hidden)]
+    #[inline]
+    pub fn new(atomic: &'a R::Atomic, layout: BitFieldLayout) -> Self {
+        Self { atomic, layout }
+    }
+
+    /// Returns the layout of the field.
+    ///
+    /// # Returns
+    ///
+    /// The layout of the field.
+    #[inline]
+    pub fn layout(&self) -> BitFieldLayout {
+        self.layout
+    }
+
+    /// Gets the current value of the field.
+    ///
+    /// # Returns
+    ///
+    /// The current value of the field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic_bitfield;
+    ///
+    /// atomic_bitfield! {
+    ///     pub struct AtomicFlags(Flags): u32 {
+    ///         pub ready: 1,
+    ///         pub count: 8,
+    ///     }
+    /// }
+    ///
+    /// let flags = AtomicFlags::new(Flags { ready: 1, count: 3 });
+    /// assert_eq!(flags.count().load(), 3);
+    /// ```
+    #[inline]
+    pub fn load(&self) -> R {
+        self.layout.get(self.atomic.load())
+    }
+
+    /// Sets the value of the field.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new value of the field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` does not fit in the field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic_bitfield;
+    ///
+    /// atomic_bitfield! {
+    ///     pub struct AtomicFlags(Flags): u32 {
+    ///         pub ready: 1,
+    ///         pub count: 8,
+    ///     }
+    /// }
+    ///
+    /// let flags = AtomicFlags::new(Flags { ready: 0, count: 3 });
+    /// flags.ready().set(1);
+    /// assert_eq!(flags.load(), Flags { ready: 1, count: 3 });
+    /// ```
+    #[inline]
+    pub fn set(&self, value: R) {
+        // Check eagerly, so that the panic does not happen inside the loop
+        let layout = self.layout;
+        layout.replace(R::from_u64(0), value);
+        self.atomic.fetch_update(|bits| layout.replace(bits, value));
+    }
+
+    /// Adds to the value of the field, returning the old value.
+    ///
+    /// The addition wraps around within the field and never carries into
+    /// the other fields.
+    ///
+    /// # Parameters
+    ///
+    /// * `delta` - The value to add.
+    ///
+    /// # Returns
+    ///
+    /// The old value of the field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic_bitfield;
+    ///
+    /// atomic_bitfield! {
+    ///     pub struct AtomicFlags(Flags): u32 {
+    ///         pub ready: 1,
+    ///         pub count: 8,
+    ///     }
+    /// }
+    ///
+    /// let flags = AtomicFlags::new(Flags { ready: 1, count: 255 });
+    /// assert_eq!(flags.count().fetch_add(1), 255);
+    /// // Wrapped around without touching `ready`
+    /// assert_eq!(flags.load(), Flags { ready: 1, count: 0 });
+    /// ```
+    #[inline]
+    pub fn fetch_add(&self, delta: R) -> R {
+        let layout = self.layout;
+        let old = self.atomic.fetch_update(|bits| {
+            let value = layout.get(bits).to_u64().wrapping_add(delta.to_u64());
+            layout.replace(bits, R::from_u64(value & layout.max()))
+        });
+        layout.get(old)
+    }
+
+    /// Compares and sets the value of the field atomically.
+    ///
+    /// Succeeds if the field equals `current`, whatever the values of the
+    /// other fields.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value of the field.
+    /// * `new` - The new value to set if the field matches.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` with the actual value of the
+    /// field on failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new` does not fit in the field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic_bitfield;
+    ///
+    /// atomic_bitfield! {
+    ///     pub struct AtomicFlags(Flags): u32 {
+    ///         pub ready: 1,
+    ///         pub count: 8,
+    ///     }
+    /// }
+    ///
+    /// let flags = AtomicFlags::new(Flags { ready: 0, count: 3 });
+    /// assert!(flags.ready().compare_set(0, 1).is_ok());
+    /// assert_eq!(flags.ready().compare_set(0, 1), Err(1));
+    /// ```
+    #[inline]
+    pub fn compare_set(&self, current: R, new: R) -> Result<(), R> {
+        let layout = self.layout;
+        layout.replace(R::from_u64(0), new);
+        self.atomic
+            .try_update(|bits| {
+                if layout.get(bits) == current {
+                    Some(layout.replace(bits, new))
+                } else {
+                    None
+                }
+            })
+            .map(|_| ())
+            .map_err(|bits| layout.get(bits))
+    }
+}
+
+impl<R: BitFieldRepr> fmt::Debug for BitField<'_, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BitField")
+            .field("name", &self.layout.name)
+            .field("value", &self.load())
+            .finish()
+    }
+}
+
+/// Generates an atomic struct of bit fields packed into an `AtomicU32` or
+/// an `AtomicU64`.
+///
+/// The macro takes the name of the atomic type, the name of its value type
+/// in parentheses, the packed integer type (`u32` or `u64`) and the fields
+/// with their widths in bits. The first field occupies the lowest bits. The
+/// total width must not exceed the width of the packed integer, which is
+/// checked at compile time.
+///
+/// The value type is a plain struct with one field of the packed integer
+/// type per bit field, and `from_bits`/`to_bits` conversions. The atomic
+/// type provides:
+///
+/// - `new`, `load`, `store`, `swap` and `compare_set` on whole values;
+/// - `fetch_update` and `try_update`, which update several fields at once
+///   with a closure on the value type;
+/// - one accessor per field, named after the field, returning a
+///   [`BitField`] with `load`, `set`, `fetch_add` and `compare_set`.
+///
+/// All operations use the default ordering policy. Operations storing a
+/// field value that does not fit in its field panic.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic_bitfield;
+///
+/// atomic_bitfield! {
+///     /// A slot of a handle table.
+///     pub struct AtomicSlot(Slot): u64 {
+///         /// Incremented whenever the slot is reused.
+///         pub generation: 16,
+///         pub index: 32,
+///         pub state: 8,
+///     }
+/// }
+///
+/// let slot = AtomicSlot::new(Slot { generation: 0, index: 42, state: 1 });
+///
+/// // Per-field operations
+/// slot.generation().fetch_add(1);
+/// assert!(slot.state().compare_set(1, 2).is_ok());
+///
+/// // Multi-field update
+/// slot.fetch_update(|s| Slot { index: s.index + 1, state: 0, ..s });
+/// assert_eq!(slot.load(), Slot { generation: 1, index: 43, state: 0 });
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+#[macro_export]
+macro_rules! atomic_bitfield {
+    (@layout $value:ident, $shift:expr; ) => {};
+
+    (
+        @layout $value:ident, $shift:expr;
+        $field:ident : $width:literal $(, $rest:ident : $rest_width:literal)*
+    ) => {
+        impl $value {
+            #[allow(non_upper_case_globals)]
+            const $field: $crate::atomic::BitFieldLayout =
+                $crate::atomic::BitFieldLayout::new(
+                    stringify!($field),
+                    $shift,
+                    $width,
+                );
+        }
+
+        $crate::atomic_bitfield!(
+            @layout $value, $shift + $width; $($rest: $rest_width),*
+        );
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($value:ident) : $repr:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $width:literal
+            ),+ $(,)?
+        }
+    ) => {
+        #[doc = concat!("The value of [`", stringify!($name), "`].")]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        $vis struct $value {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $repr,
+            )+
+        }
+
+        const _: () = {
+            $(
+                assert!($width > 0, "bit fields must not be empty");
+            )+
+            assert!(
+                0 $(+ $width)+ <= <$repr as $crate::atomic::BitFieldRepr>::BITS,
+                "bit fields do not fit in the packed integer"
+            );
+        };
+
+        $crate::atomic_bitfield!(@layout $value, 0; $($field: $width),+);
+
+        impl $value {
+            /// Unpacks a value from an integer.
+            ///
+            /// Bits outside of the fields are ignored.
+            ///
+            /// # Parameters
+            ///
+            /// * `bits` - The packed integer.
+            ///
+            /// # Returns
+            ///
+            /// The unpacked value.
+            #[inline]
+            pub fn from_bits(bits: $repr) -> Self {
+                Self {
+                    $($field: Self::$field.get(bits),)+
+                }
+            }
+
+            /// Packs the value into an integer.
+            ///
+            /// # Returns
+            ///
+            /// The packed integer.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a field value does not fit in its field.
+            #[inline]
+            pub fn to_bits(self) -> $repr {
+                let bits: $repr = 0;
+                $(let bits = Self::$field.replace(bits, self.$field);)+
+                bits
+            }
+        }
+
+        $(#[$meta])*
+        $vis struct $name {
+            inner: <$repr as $crate::atomic::BitFieldRepr>::Atomic,
+        }
+
+        impl $name {
+            /// Creates a new atomic bit-field struct.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The initial value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a field value does not fit in its field.
+            #[inline]
+            pub fn new(value: $value) -> Self {
+                Self {
+                    inner: <$repr as $crate::atomic::BitFieldRepr>::new_atomic(
+                        value.to_bits(),
+                    ),
+                }
+            }
+
+            /// Gets the current value.
+            ///
+            /// # Returns
+            ///
+            /// The current value.
+            #[inline]
+            pub fn load(&self) -> $value {
+                $value::from_bits($crate::atomic::Atomic::load(&self.inner))
+            }
+
+            /// Sets a new value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a field value does not fit in its field.
+            #[inline]
+            pub fn store(&self, value: $value) {
+                $crate::atomic::Atomic::store(&self.inner, value.to_bits());
+            }
+
+            /// Swaps the current value with a new value, returning the old
+            /// value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a field value does not fit in its field.
+            #[inline]
+            pub fn swap(&self, value: $value) -> $value {
+                $value::from_bits($crate::atomic::Atomic::swap(
+                    &self.inner,
+                    value.to_bits(),
+                ))
+            }
+
+            /// Compares and sets the whole value atomically.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a field value does not fit in its field.
+            #[inline]
+            pub fn compare_set(
+                &self,
+                current: $value,
+                new: $value,
+            ) -> ::std::result::Result<(), $value> {
+                $crate::atomic::Atomic::compare_set(
+                    &self.inner,
+                    current.to_bits(),
+                    new.to_bits(),
+                )
+                .map_err($value::from_bits)
+            }
+
+            /// Updates several fields at once using a function, returning
+            /// the old value.
+            ///
+            /// Internally uses a CAS loop until the update succeeds; the
+            /// function may be called several times.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns
+            ///   the new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a field value returned by `f` does not fit in its
+            /// field.
+            #[inline]
+            pub fn fetch_update<F>(&self, mut f: F) -> $value
+            where
+                F: FnMut($value) -> $value,
+            {
+                $value::from_bits($crate::atomic::Atomic::fetch_update(
+                    &self.inner,
+                    |bits| f($value::from_bits(bits)).to_bits(),
+                ))
+            }
+
+            /// Updates several fields at once using a function that may
+            /// decline, returning the old value.
+            ///
+            /// Internally uses a CAS loop that stops as soon as `f` returns
+            /// `None`, leaving the value unchanged.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns
+            ///   the new value, or `None` to abort the update.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old)` if the value was updated, or `Err(current)` if `f`
+            /// returned `None`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a field value returned by `f` does not fit in its
+            /// field.
+            #[inline]
+            pub fn try_update<F>(
+                &self,
+                mut f: F,
+            ) -> ::std::result::Result<$value, $value>
+            where
+                F: FnMut($value) -> ::std::option::Option<$value>,
+            {
+                $crate::atomic::Atomic::try_update(&self.inner, |bits| {
+                    f($value::from_bits(bits)).map($value::to_bits)
+                })
+                .map($value::from_bits)
+                .map_err($value::from_bits)
+            }
+
+            $(
+                #[doc = concat!("Returns the `", stringify!($field), "` field.")]
+                #[inline]
+                $field_vis fn $field(
+                    &self,
+                ) -> $crate::atomic::BitField<'_, $repr> {
+                    $crate::atomic::BitField::new(&self.inner, $value::$field)
+                }
+            )+
+        }
+
+        impl ::std::default::Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::new($value::default())
+            }
+        }
+
+        impl ::std::convert::From<$value> for $name {
+            #[inline]
+            fn from(value: $value) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(
+                &self,
+                f: &mut ::std::fmt::Formatter<'_>,
+            ) -> ::std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("value", &self.load())
+                    .finish()
+            }
+        }
+    };
+}
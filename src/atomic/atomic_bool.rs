@@ -17,9 +17,16 @@
 //! Haixing Hu
 
 use std::fmt;
+use std::marker::PhantomData;
 use std::sync::atomic::AtomicBool as StdAtomicBool;
 use std::sync::atomic::Ordering;
 
+use crate::atomic::backoff::Backoff;
+use crate::atomic::ordering::debug_validate_cas;
+use crate::atomic::ordering::debug_validate_load;
+use crate::atomic::ordering::debug_validate_store;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
 use crate::atomic::traits::Atomic;
 
 /// Atomic boolean type.
@@ -49,11 +56,18 @@ use crate::atomic::traits::Atomic;
 /// These orderings provide a balance between performance and correctness
 /// for typical concurrent programming patterns.
 ///
+/// These are the orderings of the default policy
+/// [`DefaultOrdering`](crate::atomic::DefaultOrdering). Another policy can be
+/// selected with the type parameter `P`, e.g. `AtomicBool<SeqCst>`; such
+/// instances are created with [`with_policy`](Self::with_policy).
+///
 /// # Features
 ///
 /// - Automatic memory ordering selection
+/// - Type-level ordering policies
 /// - Rich set of boolean-specific operations
 /// - Zero-cost abstraction with inline methods
+/// - Explicit memory orderings via the `*_with_ordering` methods
 /// - Access to underlying type via `inner()` for advanced use cases
 ///
 /// # Example
@@ -78,8 +92,9 @@ use crate::atomic::traits::Atomic;
 ///
 /// Haixing Hu
 #[repr(transparent)]
-pub struct AtomicBool {
+pub struct AtomicBool<P = DefaultOrdering> {
     inner: StdAtomicBool,
+    _policy: PhantomData<fn() -> P>,
 }
 
 impl AtomicBool {
@@ -99,8 +114,30 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub const fn new(value: bool) -> Self {
+        Self::with_policy(value)
+    }
+}
+
+impl<P: OrderingPolicy> AtomicBool<P> {
+    /// Creates a new atomic boolean using the ordering policy `P`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicBool, SeqCst};
+    ///
+    /// let atomic = AtomicBool::<SeqCst>::with_policy(true);
+    /// assert_eq!(atomic.load(), true);
+    /// ```
+    #[inline]
+    pub const fn with_policy(value: bool) -> Self {
         Self {
             inner: StdAtomicBool::new(value),
+            _policy: PhantomData,
         }
     }
 
@@ -131,7 +168,38 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn load(&self) -> bool {
-        self.inner.load(Ordering::Acquire)
+        self.load_with_ordering(P::LOAD)
+    }
+
+    /// Gets the current value with an explicit memory ordering.
+    ///
+    /// # Parameters
+    ///
+    /// * `ordering` - The memory ordering to use. Must be `Relaxed`, `Acquire`
+    ///   or `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// The current value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is `Release` or `AcqRel`. In debug builds, the
+    /// ordering is validated before the access with a descriptive message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(true);
+    /// assert_eq!(flag.load_with_ordering(Ordering::Relaxed), true);
+    /// ```
+    #[inline]
+    pub fn load_with_ordering(&self, ordering: Ordering) -> bool {
+        debug_validate_load(ordering);
+        self.inner.load(ordering)
     }
 
     /// Sets a new value.
@@ -161,7 +229,36 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn store(&self, value: bool) {
-        self.inner.store(value, Ordering::Release);
+        self.store_with_ordering(value, P::STORE);
+    }
+
+    /// Sets a new value with an explicit memory ordering.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new value to set.
+    /// * `ordering` - The memory ordering to use. Must be `Relaxed`, `Release`
+    ///   or `SeqCst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is `Acquire` or `AcqRel`. In debug builds, the
+    /// ordering is validated before the access with a descriptive message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(false);
+    /// flag.store_with_ordering(true, Ordering::Relaxed);
+    /// assert_eq!(flag.load(), true);
+    /// ```
+    #[inline]
+    pub fn store_with_ordering(&self, value: bool, ordering: Ordering) {
+        debug_validate_store(ordering);
+        self.inner.store(value, ordering);
     }
 
     /// Swaps the current value with a new value, returning the old value.
@@ -196,7 +293,34 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn swap(&self, value: bool) -> bool {
-        self.inner.swap(value, Ordering::AcqRel)
+        self.swap_with_ordering(value, P::RMW)
+    }
+
+    /// Swaps the current value with a new value using an explicit memory
+    /// ordering, returning the old value.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new value to swap in.
+    /// * `ordering` - The memory ordering to use. Any ordering is valid.
+    ///
+    /// # Returns
+    ///
+    /// The old value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(false);
+    /// assert_eq!(flag.swap_with_ordering(true, Ordering::SeqCst), false);
+    /// assert_eq!(flag.load(), true);
+    /// ```
+    #[inline]
+    pub fn swap_with_ordering(&self, value: bool, ordering: Ordering) -> bool {
+        self.inner.swap(value, ordering)
     }
 
     /// Compares and sets the value atomically.
@@ -238,8 +362,57 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn compare_set(&self, current: bool, new: bool) -> Result<(), bool> {
+        self.compare_set_with_ordering(current, new, P::CAS_SUCCESS, P::CAS_FAILURE)
+    }
+
+    /// Compares and sets the value atomically with explicit memory orderings.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    /// * `success` - The ordering of the read-modify-write if the comparison
+    ///   succeeds.
+    /// * `failure` - The ordering of the load if the comparison fails. Must be
+    ///   `Relaxed`, `Acquire` or `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `failure` is `Release` or `AcqRel`. In debug builds, the
+    /// orderings are validated before the access with a descriptive message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(false);
+    /// assert!(flag
+    ///     .compare_set_with_ordering(
+    ///         false,
+    ///         true,
+    ///         Ordering::SeqCst,
+    ///         Ordering::Relaxed,
+    ///     )
+    ///     .is_ok());
+    /// assert_eq!(flag.load(), true);
+    /// ```
+    #[inline]
+    pub fn compare_set_with_ordering(
+        &self,
+        current: bool,
+        new: bool,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<(), bool> {
+        debug_validate_cas(success, failure);
         self.inner
-            .compare_exchange(current, new, Ordering::AcqRel, Ordering::Acquire)
+            .compare_exchange(current, new, success, failure)
             .map(|_| ())
     }
 
@@ -276,8 +449,60 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn compare_set_weak(&self, current: bool, new: bool) -> Result<(), bool> {
+        self.compare_set_weak_with_ordering(current, new, P::CAS_SUCCESS, P::CAS_FAILURE)
+    }
+
+    /// Weak version of compare-and-set with explicit memory orderings.
+    ///
+    /// May spuriously fail even when the comparison succeeds. Should be used in
+    /// a loop.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    /// * `success` - The ordering of the read-modify-write if the comparison
+    ///   succeeds.
+    /// * `failure` - The ordering of the load if the comparison fails. Must be
+    ///   `Relaxed`, `Acquire` or `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `failure` is `Release` or `AcqRel`. In debug builds, the
+    /// orderings are validated before the access with a descriptive message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(false);
+    /// assert!(flag
+    ///     .compare_set_weak_with_ordering(
+    ///         false,
+    ///         true,
+    ///         Ordering::SeqCst,
+    ///         Ordering::Relaxed,
+    ///     )
+    ///     .is_ok());
+    /// assert_eq!(flag.load(), true);
+    /// ```
+    #[inline]
+    pub fn compare_set_weak_with_ordering(
+        &self,
+        current: bool,
+        new: bool,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<(), bool> {
+        debug_validate_cas(success, failure);
         self.inner
-            .compare_exchange_weak(current, new, Ordering::AcqRel, Ordering::Acquire)
+            .compare_exchange_weak(current, new, success, failure)
             .map(|_| ())
     }
 
@@ -310,10 +535,56 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn compare_and_exchange(&self, current: bool, new: bool) -> bool {
-        match self
-            .inner
-            .compare_exchange(current, new, Ordering::AcqRel, Ordering::Acquire)
-        {
+        self.compare_and_exchange_with_ordering(current, new, P::CAS_SUCCESS, P::CAS_FAILURE)
+    }
+
+    /// Compares and exchanges the value atomically with explicit memory
+    /// orderings, returning the previous value.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    /// * `success` - The ordering of the read-modify-write if the comparison
+    ///   succeeds.
+    /// * `failure` - The ordering of the load if the comparison fails. Must be
+    ///   `Relaxed`, `Acquire` or `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// The value before the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `failure` is `Release` or `AcqRel`. In debug builds, the
+    /// orderings are validated before the access with a descriptive message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(false);
+    /// let prev = flag.compare_and_exchange_with_ordering(
+    ///     false,
+    ///     true,
+    ///     Ordering::SeqCst,
+    ///     Ordering::Relaxed,
+    /// );
+    /// assert_eq!(prev, false);
+    /// assert_eq!(flag.load(), true);
+    /// ```
+    #[inline]
+    pub fn compare_and_exchange_with_ordering(
+        &self,
+        current: bool,
+        new: bool,
+        success: Ordering,
+        failure: Ordering,
+    ) -> bool {
+        debug_validate_cas(success, failure);
+        match self.inner.compare_exchange(current, new, success, failure) {
             Ok(prev) => prev,
             Err(actual) => actual,
         }
@@ -353,9 +624,60 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn compare_and_exchange_weak(&self, current: bool, new: bool) -> bool {
+        self.compare_and_exchange_weak_with_ordering(current, new, P::CAS_SUCCESS, P::CAS_FAILURE)
+    }
+
+    /// Weak version of compare-and-exchange with explicit memory orderings.
+    ///
+    /// May spuriously fail even when the comparison succeeds. Should be used in
+    /// a loop.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    /// * `success` - The ordering of the read-modify-write if the comparison
+    ///   succeeds.
+    /// * `failure` - The ordering of the load if the comparison fails. Must be
+    ///   `Relaxed`, `Acquire` or `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// The value before the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `failure` is `Release` or `AcqRel`. In debug builds, the
+    /// orderings are validated before the access with a descriptive message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(false);
+    /// let prev = flag.compare_and_exchange_weak_with_ordering(
+    ///     false,
+    ///     true,
+    ///     Ordering::SeqCst,
+    ///     Ordering::Relaxed,
+    /// );
+    /// assert_eq!(prev, false);
+    /// assert_eq!(flag.load(), true);
+    /// ```
+    #[inline]
+    pub fn compare_and_exchange_weak_with_ordering(
+        &self,
+        current: bool,
+        new: bool,
+        success: Ordering,
+        failure: Ordering,
+    ) -> bool {
+        debug_validate_cas(success, failure);
         match self
             .inner
-            .compare_exchange_weak(current, new, Ordering::AcqRel, Ordering::Acquire)
+            .compare_exchange_weak(current, new, success, failure)
         {
             Ok(prev) => prev,
             Err(actual) => actual,
@@ -386,7 +708,33 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn fetch_set(&self) -> bool {
-        self.swap(true)
+        self.fetch_set_with_ordering(P::RMW)
+    }
+
+    /// Atomically sets the value to `true` with an explicit memory ordering,
+    /// returning the old value.
+    ///
+    /// # Parameters
+    ///
+    /// * `ordering` - The memory ordering to use. Any ordering is valid.
+    ///
+    /// # Returns
+    ///
+    /// The old value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(false);
+    /// assert_eq!(flag.fetch_set_with_ordering(Ordering::Relaxed), false);
+    /// assert_eq!(flag.load(), true);
+    /// ```
+    #[inline]
+    pub fn fetch_set_with_ordering(&self, ordering: Ordering) -> bool {
+        self.swap_with_ordering(true, ordering)
     }
 
     /// Atomically sets the value to `false`, returning the old value.
@@ -413,7 +761,33 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn fetch_clear(&self) -> bool {
-        self.swap(false)
+        self.fetch_clear_with_ordering(P::RMW)
+    }
+
+    /// Atomically sets the value to `false` with an explicit memory ordering,
+    /// returning the old value.
+    ///
+    /// # Parameters
+    ///
+    /// * `ordering` - The memory ordering to use. Any ordering is valid.
+    ///
+    /// # Returns
+    ///
+    /// The old value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(true);
+    /// assert_eq!(flag.fetch_clear_with_ordering(Ordering::Relaxed), true);
+    /// assert_eq!(flag.load(), false);
+    /// ```
+    #[inline]
+    pub fn fetch_clear_with_ordering(&self, ordering: Ordering) -> bool {
+        self.swap_with_ordering(false, ordering)
     }
 
     /// Atomically negates the value, returning the old value.
@@ -440,7 +814,33 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn fetch_not(&self) -> bool {
-        self.inner.fetch_xor(true, Ordering::AcqRel)
+        self.fetch_not_with_ordering(P::BIT_OP)
+    }
+
+    /// Atomically negates the value with an explicit memory ordering, returning
+    /// the old value.
+    ///
+    /// # Parameters
+    ///
+    /// * `ordering` - The memory ordering to use. Any ordering is valid.
+    ///
+    /// # Returns
+    ///
+    /// The old value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(false);
+    /// assert_eq!(flag.fetch_not_with_ordering(Ordering::Relaxed), false);
+    /// assert_eq!(flag.load(), true);
+    /// ```
+    #[inline]
+    pub fn fetch_not_with_ordering(&self, ordering: Ordering) -> bool {
+        self.inner.fetch_xor(true, ordering)
     }
 
     /// Atomically performs logical AND, returning the old value.
@@ -470,7 +870,35 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn fetch_and(&self, value: bool) -> bool {
-        self.inner.fetch_and(value, Ordering::AcqRel)
+        self.fetch_and_with_ordering(value, P::BIT_OP)
+    }
+
+    /// Atomically performs logical AND with an explicit memory ordering,
+    /// returning the old value.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to AND with.
+    /// * `ordering` - The memory ordering to use. Any ordering is valid.
+    ///
+    /// # Returns
+    ///
+    /// The old value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(true);
+    /// let old = flag.fetch_and_with_ordering(false, Ordering::Relaxed);
+    /// assert_eq!(old, true);
+    /// assert_eq!(flag.load(), false);
+    /// ```
+    #[inline]
+    pub fn fetch_and_with_ordering(&self, value: bool, ordering: Ordering) -> bool {
+        self.inner.fetch_and(value, ordering)
     }
 
     /// Atomically performs logical OR, returning the old value.
@@ -500,7 +928,35 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn fetch_or(&self, value: bool) -> bool {
-        self.inner.fetch_or(value, Ordering::AcqRel)
+        self.fetch_or_with_ordering(value, P::BIT_OP)
+    }
+
+    /// Atomically performs logical OR with an explicit memory ordering,
+    /// returning the old value.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to OR with.
+    /// * `ordering` - The memory ordering to use. Any ordering is valid.
+    ///
+    /// # Returns
+    ///
+    /// The old value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(true);
+    /// let old = flag.fetch_or_with_ordering(true, Ordering::Relaxed);
+    /// assert_eq!(old, true);
+    /// assert_eq!(flag.load(), true);
+    /// ```
+    #[inline]
+    pub fn fetch_or_with_ordering(&self, value: bool, ordering: Ordering) -> bool {
+        self.inner.fetch_or(value, ordering)
     }
 
     /// Atomically performs logical XOR, returning the old value.
@@ -530,7 +986,35 @@ impl AtomicBool {
     /// ```
     #[inline]
     pub fn fetch_xor(&self, value: bool) -> bool {
-        self.inner.fetch_xor(value, Ordering::AcqRel)
+        self.fetch_xor_with_ordering(value, P::BIT_OP)
+    }
+
+    /// Atomically performs logical XOR with an explicit memory ordering,
+    /// returning the old value.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to XOR with.
+    /// * `ordering` - The memory ordering to use. Any ordering is valid.
+    ///
+    /// # Returns
+    ///
+    /// The old value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBool;
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// let flag = AtomicBool::new(true);
+    /// let old = flag.fetch_xor_with_ordering(true, Ordering::Relaxed);
+    /// assert_eq!(old, true);
+    /// assert_eq!(flag.load(), false);
+    /// ```
+    #[inline]
+    pub fn fetch_xor_with_ordering(&self, value: bool, ordering: Ordering) -> bool {
+        self.inner.fetch_xor(value, ordering)
     }
 
     /// Conditionally sets the value if it is currently `false`.
@@ -625,7 +1109,7 @@ impl AtomicBool {
     }
 }
 
-impl Atomic for AtomicBool {
+impl<P: OrderingPolicy> Atomic for AtomicBool<P> {
     type Value = bool;
 
     #[inline]
@@ -664,23 +1148,73 @@ impl Atomic for AtomicBool {
     }
 
     #[inline]
-    fn fetch_update<F>(&self, f: F) -> bool
+    fn fetch_update<F>(&self, mut f: F) -> bool
     where
-        F: Fn(bool) -> bool,
+        F: FnMut(bool) -> bool,
     {
         let mut current = self.load();
+        let mut backoff = P::BACKOFF;
         loop {
             let new = f(current);
             match self.compare_set_weak(current, new) {
                 Ok(_) => return current,
-                Err(actual) => current = actual,
+                Err(actual) => {
+                    current = actual;
+                    backoff.backoff();
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn update_and_get<F>(&self, mut f: F) -> bool
+    where
+        F: FnMut(bool) -> bool,
+    {
+        let mut current = self.load();
+        let mut backoff = P::BACKOFF;
+        loop {
+            let new = f(current);
+            match self.compare_set_weak(current, new) {
+                Ok(_) => return new,
+                Err(actual) => {
+                    current = actual;
+                    backoff.backoff();
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn try_update<F>(&self, mut f: F) -> Result<bool, bool>
+    where
+        F: FnMut(bool) -> Option<bool>,
+    {
+        Atomic::try_update_result(self, |current| f(current).ok_or(current))
+    }
+
+    #[inline]
+    fn try_update_result<F, E>(&self, mut f: F) -> Result<bool, E>
+    where
+        F: FnMut(bool) -> Result<bool, E>,
+    {
+        let mut current = self.load();
+        let mut backoff = P::BACKOFF;
+        loop {
+            let new = f(current)?;
+            match self.compare_set_weak(current, new) {
+                Ok(_) => return Ok(current),
+                Err(actual) => {
+                    current = actual;
+                    backoff.backoff();
+                }
             }
         }
     }
 }
 
-unsafe impl Send for AtomicBool {}
-unsafe impl Sync for AtomicBool {}
+unsafe impl<P> Send for AtomicBool<P> {}
+unsafe impl<P> Sync for AtomicBool<P> {}
 
 impl Default for AtomicBool {
     #[inline]
@@ -696,7 +1230,7 @@ impl From<bool> for AtomicBool {
     }
 }
 
-impl fmt::Debug for AtomicBool {
+impl<P: OrderingPolicy> fmt::Debug for AtomicBool<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("AtomicBool")
             .field("value", &self.load())
@@ -704,7 +1238,7 @@ impl fmt::Debug for AtomicBool {
     }
 }
 
-impl fmt::Display for AtomicBool {
+impl<P: OrderingPolicy> fmt::Display for AtomicBool<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.load())
     }
@@ -0,0 +1,378 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Box
+//!
+//! Provides an atomic slot owning an optional `Box<T>`, for handing uniquely
+//! owned values between threads.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::AtomicPtr;
+
+use crate::atomic::ordering::assert_acq_rel;
+use crate::atomic::ordering::assert_acquire;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::PublishingPolicy;
+
+/// Atomic slot owning an optional `Box<T>`.
+///
+/// Unlike [`AtomicRef`](crate::atomic::AtomicRef), the slot does not share
+/// its value: a value moves into the slot with [`swap`](Self::swap) or
+/// [`compare_set_null`](Self::compare_set_null) and moves out with
+/// [`swap`](Self::swap) or [`take`](Self::take), so no reference counting
+/// or reclamation is needed. Typical uses are double-buffered frames and
+/// per-worker mailboxes.
+///
+/// # Memory Ordering Strategy
+///
+/// Every operation that moves a value in or out is a read-modify-write
+/// operation using the `RMW` ordering (`AcqRel` with the default policy),
+/// so the thread taking a value observes all writes made to it before it
+/// was put in. Only policies implementing [`PublishingPolicy`] are
+/// accepted, and an operation panics if the ordering it uses is weaker
+/// than required, also in release builds.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicBox;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let mailbox = Arc::new(AtomicBox::default());
+/// let sender = {
+///     let mailbox = mailbox.clone();
+///     thread::spawn(move || {
+///         mailbox.compare_set_null(Box::new(vec![1, 2, 3])).unwrap();
+///     })
+/// };
+/// sender.join().unwrap();
+/// assert_eq!(mailbox.take().as_deref(), Some(&vec![1, 2, 3]));
+/// assert!(mailbox.is_empty());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct AtomicBox<T, P = DefaultOrdering> {
+    inner: AtomicPtr<T>,
+    _owned: PhantomData<Box<T>>,
+    _policy: PhantomData<fn() -> P>,
+}
+
+impl<T> AtomicBox<T> {
+    /// Creates a new atomic box.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial value, or `None` for an empty slot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBox;
+    ///
+    /// let slot = AtomicBox::new(Some(Box::new(42)));
+    /// assert_eq!(slot.into_inner().as_deref(), Some(&42));
+    /// ```
+    #[inline]
+    pub fn new(value: Option<Box<T>>) -> Self {
+        Self::with_policy(value)
+    }
+}
+
+impl<T, P: PublishingPolicy> AtomicBox<T, P> {
+    /// Creates a new atomic box using the ordering policy `P`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial value, or `None` for an empty slot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicBox, SeqCst};
+    ///
+    /// let slot = AtomicBox::<i32, SeqCst>::with_policy(None);
+    /// assert!(slot.is_empty());
+    /// ```
+    #[inline]
+    pub fn with_policy(value: Option<Box<T>>) -> Self {
+        Self {
+            inner: AtomicPtr::new(Self::into_raw(value)),
+            _owned: PhantomData,
+            _policy: PhantomData,
+        }
+    }
+
+    /// Tests whether the slot is empty.
+    ///
+    /// The result may be outdated as soon as it is returned, if other
+    /// threads use the slot.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the slot holds no value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `P::LOAD` is neither `Acquire` nor `SeqCst`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBox;
+    ///
+    /// let slot = AtomicBox::new(None);
+    /// assert!(slot.is_empty());
+    /// slot.swap(Box::new(1));
+    /// assert!(!slot.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        assert_acquire(P::LOAD);
+        self.inner.load(P::LOAD).is_null()
+    }
+
+    /// Puts a value in the slot, returning the previous one.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new value.
+    ///
+    /// # Returns
+    ///
+    /// The previous value, or `None` if the slot was empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBox;
+    ///
+    /// // Double buffering: publish the back buffer, reuse the front one
+    /// let front = AtomicBox::new(Some(Box::new([0u8; 4])));
+    /// let back = Box::new([1u8; 4]);
+    /// let old = front.swap(back).unwrap();
+    /// assert_eq!(*old, [0; 4]);
+    /// ```
+    #[inline]
+    pub fn swap(&self, value: Box<T>) -> Option<Box<T>> {
+        self.replace(Some(value))
+    }
+
+    /// Replaces the value of the slot, returning the previous one.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new value, or `None` to empty the slot.
+    ///
+    /// # Returns
+    ///
+    /// The previous value, or `None` if the slot was empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `P::RMW` is neither `AcqRel` nor `SeqCst`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBox;
+    ///
+    /// let slot = AtomicBox::new(Some(Box::new(1)));
+    /// assert_eq!(slot.replace(None).as_deref(), Some(&1));
+    /// assert!(slot.replace(Some(Box::new(2))).is_none());
+    /// ```
+    #[inline]
+    pub fn replace(&self, value: Option<Box<T>>) -> Option<Box<T>> {
+        assert_acq_rel(P::RMW);
+        let old_ptr = self.inner.swap(Self::into_raw(value), P::RMW);
+        // SAFETY: the pointer has been unlinked from the slot, so its
+        // ownership moves to the caller.
+        unsafe { Self::from_raw(old_ptr) }
+    }
+
+    /// Takes the value out of the slot, leaving it empty.
+    ///
+    /// # Returns
+    ///
+    /// The value, or `None` if the slot was empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBox;
+    ///
+    /// let slot = AtomicBox::new(Some(Box::new("frame")));
+    /// assert_eq!(slot.take().as_deref(), Some(&"frame"));
+    /// assert!(slot.take().is_none());
+    /// ```
+    #[inline]
+    pub fn take(&self) -> Option<Box<T>> {
+        self.replace(None)
+    }
+
+    /// Puts a value in the slot if it is empty.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new value.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the value was put in the slot, or `Err(value)` giving the
+    /// value back if the slot was not empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `P::CAS_SUCCESS` is neither `AcqRel` nor `SeqCst`, or if
+    /// `P::CAS_FAILURE` is neither `Acquire` nor `SeqCst`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBox;
+    ///
+    /// let mailbox = AtomicBox::new(None);
+    /// assert!(mailbox.compare_set_null(Box::new(1)).is_ok());
+    /// let rejected = mailbox.compare_set_null(Box::new(2)).unwrap_err();
+    /// assert_eq!(*rejected, 2);
+    /// ```
+    #[inline]
+    pub fn compare_set_null(&self, value: Box<T>) -> Result<(), Box<T>> {
+        assert_acq_rel(P::CAS_SUCCESS);
+        assert_acquire(P::CAS_FAILURE);
+        let new_ptr = Box::into_raw(value);
+        match self
+            .inner
+            .compare_exchange(ptr::null_mut(), new_ptr, P::CAS_SUCCESS, P::CAS_FAILURE)
+        {
+            Ok(_) => Ok(()),
+            // SAFETY: `new_ptr` was never published, so we still own it.
+            Err(_) => Err(unsafe { Box::from_raw(new_ptr) }),
+        }
+    }
+
+    /// Returns a mutable reference to the value of the slot.
+    ///
+    /// No atomic operation is needed, since the mutable borrow guarantees
+    /// exclusive access.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the value, or `None` if the slot is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBox;
+    ///
+    /// let mut slot = AtomicBox::new(Some(Box::new(1)));
+    /// *slot.get_mut().unwrap() += 1;
+    /// assert_eq!(slot.take().as_deref(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        let ptr = *self.inner.get_mut();
+        // SAFETY: a non-null pointer came from `Box::into_raw` and is owned
+        // by this slot, which is exclusively borrowed.
+        unsafe { ptr.as_mut() }
+    }
+
+    /// Consumes the slot, returning its value.
+    ///
+    /// # Returns
+    ///
+    /// The value, or `None` if the slot is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicBox;
+    ///
+    /// let slot = AtomicBox::new(Some(Box::new(42)));
+    /// assert_eq!(slot.into_inner().as_deref(), Some(&42));
+    /// ```
+    #[inline]
+    pub fn into_inner(mut self) -> Option<Box<T>> {
+        let ptr = std::mem::replace(self.inner.get_mut(), ptr::null_mut());
+        // SAFETY: the pointer is owned by this slot, which is consumed; the
+        // slot is left empty so that its destructor does not free it.
+        unsafe { Self::from_raw(ptr) }
+    }
+
+    /// Converts an optional box into a possibly null raw pointer.
+    #[inline]
+    fn into_raw(value: Option<Box<T>>) -> *mut T {
+        value.map_or(ptr::null_mut(), Box::into_raw)
+    }
+
+    /// Converts a possibly null raw pointer back into an optional box.
+    ///
+    /// # Safety
+    ///
+    /// A non-null `ptr` must come from `Box::into_raw`, and the caller must
+    /// own it.
+    #[inline]
+    unsafe fn from_raw(ptr: *mut T) -> Option<Box<T>> {
+        // SAFETY: guaranteed by the caller.
+        (!ptr.is_null()).then(|| unsafe { Box::from_raw(ptr) })
+    }
+}
+
+impl<T> Default for AtomicBox<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<T> From<Box<T>> for AtomicBox<T> {
+    #[inline]
+    fn from(value: Box<T>) -> Self {
+        Self::new(Some(value))
+    }
+}
+
+impl<T> From<Option<Box<T>>> for AtomicBox<T> {
+    #[inline]
+    fn from(value: Option<Box<T>>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, P> Drop for AtomicBox<T, P> {
+    fn drop(&mut self) {
+        let ptr = *self.inner.get_mut();
+        if !ptr.is_null() {
+            // SAFETY: the pointer came from `Box::into_raw` and is owned by
+            // this slot.
+            unsafe { drop(Box::from_raw(ptr)) };
+        }
+    }
+}
+
+// The slot never hands out shared references to its value, only moves it
+// between threads, so `T: Send` is enough for both.
+unsafe impl<T: Send, P> Send for AtomicBox<T, P> {}
+unsafe impl<T: Send, P> Sync for AtomicBox<T, P> {}
+
+impl<T, P: PublishingPolicy> fmt::Debug for AtomicBox<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicBox")
+            .field("is_empty", &self.is_empty())
+            .finish()
+    }
+}
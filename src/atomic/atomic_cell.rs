@@ -0,0 +1,991 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Cell
+//!
+//! Provides an atomic cell holding any `Copy` value without uninitialized
+//! bytes, such as a small struct, a tuple or a `char`, without hand-written
+//! bit packing, and the `no_uninit!` macro declaring such structs.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::mem;
+use std::num::NonZeroI128;
+use std::num::NonZeroI16;
+use std::num::NonZeroI32;
+use std::num::NonZeroI64;
+use std::num::NonZeroI8;
+use std::num::NonZeroIsize;
+use std::num::NonZeroU128;
+use std::num::NonZeroU16;
+use std::num::NonZeroU32;
+use std::num::NonZeroU64;
+use std::num::NonZeroU8;
+use std::num::NonZeroUsize;
+use std::ptr;
+use std::slice;
+
+use crate::atomic::atomic_u16::AtomicU16;
+use crate::atomic::atomic_u32::AtomicU32;
+use crate::atomic::atomic_u64::AtomicU64;
+use crate::atomic::atomic_u8::AtomicU8;
+use crate::atomic::backoff::Backoff;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+use crate::atomic::striped::StripedLockGuard;
+use crate::atomic::traits::Atomic;
+
+/// Values without uninitialized bytes, which [`AtomicCell`] can hold.
+///
+/// `AtomicCell` copies and compares values by their bytes, which is only
+/// defined if every byte of the value is initialized. Padding bytes, e.g.
+/// the byte between the fields of `(u8, u16)`, are uninitialized, so types
+/// with padding must not implement this trait.
+///
+/// The trait is implemented for the primitive integers, floats, `bool`,
+/// `char` and `()`, the `NonZero` integers and their `Option`s, arrays of
+/// such values and tuples of up to four values of the same such type. Since
+/// the layout of tuples is unspecified, creating an [`AtomicCell`] of a
+/// tuple also checks at compile time that it has no padding. The
+/// [`no_uninit!`](crate::no_uninit) macro implements it for `#[repr(C)]`
+/// structs, checking that they have no padding.
+///
+/// # Safety
+///
+/// Every byte of every value of the type must be initialized: the type
+/// must not contain padding, unions or `MaybeUninit`. Any bytes copied
+/// from a value must also form a valid value.
+///
+/// # Author
+///
+/// Haixing Hu
+pub unsafe trait NoUninit: Copy {
+    /// Compile-time check of a layout that the language does not specify,
+    /// evaluated when an [`AtomicCell`] of the type is created.
+    #[doc(hidden)]
+    const LAYOUT_CHECK: () = ();
+}
+
+/// Implements [`NoUninit`] for types without padding.
+macro_rules! impl_no_uninit {
+    ($($type:ty),* $(,)?) => {
+        $(
+            // SAFETY: the type has no padding, and every byte of a value is
+            // initialized.
+            unsafe impl NoUninit for $type {}
+        )*
+    };
+}
+
+impl_no_uninit!(
+    (),
+    bool,
+    char,
+    f32,
+    f64,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
+    Option<NonZeroI8>,
+    Option<NonZeroI16>,
+    Option<NonZeroI32>,
+    Option<NonZeroI64>,
+    Option<NonZeroI128>,
+    Option<NonZeroIsize>,
+    Option<NonZeroU8>,
+    Option<NonZeroU16>,
+    Option<NonZeroU32>,
+    Option<NonZeroU64>,
+    Option<NonZeroU128>,
+    Option<NonZeroUsize>,
+);
+
+// SAFETY: the elements of an array are laid out without gaps.
+unsafe impl<T: NoUninit, const N: usize> NoUninit for [T; N] {
+    const LAYOUT_CHECK: () = T::LAYOUT_CHECK;
+}
+
+/// Implements [`NoUninit`] for tuples of values of the same type.
+macro_rules! impl_no_uninit_tuple {
+    ($(($($field:ident),+) = $len:literal),+ $(,)?) => {
+        $(
+            // SAFETY: the tuple layout is unspecified, so `LAYOUT_CHECK`
+            // rejects any tuple larger than its fields, i.e. with padding.
+            unsafe impl<T: NoUninit> NoUninit for ($($field,)+) {
+                const LAYOUT_CHECK: () = {
+                    let () = T::LAYOUT_CHECK;
+                    assert!(
+                        mem::size_of::<Self>() == $len * mem::size_of::<T>(),
+                        "tuple contains padding",
+                    );
+                };
+            }
+        )+
+    };
+}
+
+impl_no_uninit_tuple!((T) = 1, (T, T) = 2, (T, T, T) = 3, (T, T, T, T) = 4);
+
+/// Implements [`NoUninit`] for a struct without padding.
+///
+/// Wraps the definition of a struct with named or unnamed fields, which is
+/// emitted with the `#[repr(C)]` attribute. The macro fails to compile if
+/// a field does not implement `NoUninit`, or if the struct contains
+/// padding, i.e. if its size is not the sum of the sizes of its fields;
+/// reordering the fields by decreasing alignment, or adding explicit
+/// filler fields, removes the padding.
+///
+/// The struct must implement `Copy`, usually by deriving it, and must not
+/// be generic.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicCell;
+/// use prism3_rust_concurrent::no_uninit;
+///
+/// no_uninit! {
+///     #[derive(Clone, Copy, Debug, PartialEq)]
+///     pub struct Slot {
+///         pub generation: u32,
+///         pub index: u16,
+///         pub flags: u16,
+///     }
+/// }
+///
+/// let slot = AtomicCell::new(Slot { generation: 0, index: 3, flags: 0 });
+/// slot.fetch_update(|s| Slot { generation: s.generation + 1, ..s });
+/// assert_eq!(slot.load().generation, 1);
+/// ```
+///
+/// A struct with padding is rejected:
+///
+/// ```rust,compile_fail
+/// use prism3_rust_concurrent::no_uninit;
+///
+/// no_uninit! {
+///     #[derive(Clone, Copy)]
+///     struct Padded {
+///         tag: u8,
+///         value: u16,
+///     }
+/// }
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+#[macro_export]
+macro_rules! no_uninit {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident : $type:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(C)]
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                $field_vis $field: $type,
+            )*
+        }
+
+        $crate::no_uninit!(@impl $name, $($type),*);
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident (
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $type:ty
+            ),* $(,)?
+        );
+    ) => {
+        $(#[$meta])*
+        #[repr(C)]
+        $vis struct $name (
+            $(
+                $(#[$field_meta])*
+                $field_vis $type,
+            )*
+        );
+
+        $crate::no_uninit!(@impl $name, $($type),*);
+    };
+    (@impl $name:ident, $($type:ty),*) => {
+        const _: () = {
+            fn assert_no_uninit<T: $crate::atomic::NoUninit>() {}
+            #[allow(dead_code)]
+            fn assert_fields() {
+                $(assert_no_uninit::<$type>();)*
+            }
+            $(let () = <$type as $crate::atomic::NoUninit>::LAYOUT_CHECK;)*
+            assert!(
+                ::std::mem::size_of::<$name>() == 0 $(+ ::std::mem::size_of::<$type>())*,
+                concat!("`", stringify!($name), "` contains padding"),
+            );
+        };
+
+        // SAFETY: the fields have no uninitialized bytes, and the struct has
+        // no padding since its size is the sum of the sizes of its fields.
+        unsafe impl $crate::atomic::NoUninit for $name {}
+    };
+}
+
+/// The storage of an [`AtomicCell`].
+///
+/// Values of up to 8 bytes are stored as the bits of the smallest atomic
+/// integer they fit in, larger values in a cell guarded by a striped lock.
+enum Storage<T, P> {
+    U8(AtomicU8<P>),
+    U16(AtomicU16<P>),
+    U32(AtomicU32<P>),
+    U64(AtomicU64<P>),
+    Locked(UnsafeCell<T>),
+}
+
+/// Applies the same expression to the atomic integer of a lock-free
+/// storage, or another expression to the cell of a locked storage.
+macro_rules! with_storage {
+    ($storage:expr, $atomic:ident => $lock_free:expr, $cell:ident => $locked:expr) => {
+        match $storage {
+            Storage::U8($atomic) => $lock_free,
+            Storage::U16($atomic) => $lock_free,
+            Storage::U32($atomic) => $lock_free,
+            Storage::U64($atomic) => $lock_free,
+            Storage::Locked($cell) => $locked,
+        }
+    };
+}
+
+/// Converts a value to the bits of an integer at least as large.
+///
+/// The bytes of the value are copied to the first bytes of the integer and
+/// the remaining bytes are zero, so equal values always give equal bits.
+#[inline]
+fn to_bits<T: NoUninit, B: Copy + Default>(value: T) -> B {
+    debug_assert!(mem::size_of::<T>() <= mem::size_of::<B>());
+    let mut bits = B::default();
+    // SAFETY: `T` is not larger than `B`, both pointers are valid for the
+    // copied bytes, which are initialized since `T: NoUninit`, and any bytes
+    // are valid for the integer `B`.
+    unsafe {
+        ptr::copy_nonoverlapping(
+            &value as *const T as *const u8,
+            &mut bits as *mut B as *mut u8,
+            mem::size_of::<T>(),
+        );
+    }
+    bits
+}
+
+/// Converts bits produced by `to_bits` back to the value.
+#[inline]
+fn from_bits<T: NoUninit, B: Copy>(bits: B) -> T {
+    debug_assert!(mem::size_of::<T>() <= mem::size_of::<B>());
+    // SAFETY: the storage only ever holds bits produced by `to_bits`, whose
+    // first bytes are those of a valid `T`.
+    unsafe { ptr::read_unaligned(&bits as *const B as *const T) }
+}
+
+/// Returns whether two values have the same bytes.
+#[inline]
+fn bytes_eq<T: NoUninit>(a: &T, b: &T) -> bool {
+    let size = mem::size_of::<T>();
+    // SAFETY: both references are valid for `size` bytes, which are
+    // initialized since `T: NoUninit`.
+    unsafe {
+        slice::from_raw_parts(a as *const T as *const u8, size)
+            == slice::from_raw_parts(b as *const T as *const u8, size)
+    }
+}
+
+/// Atomic cell holding any `Copy` value without uninitialized bytes.
+///
+/// Provides atomic `load`, `store`, `swap`, compare-and-set and functional
+/// updates on values of any type implementing [`NoUninit`], e.g. `char`,
+/// `(u32, u32)` or a small struct declared with
+/// [`no_uninit!`](crate::no_uninit), and implements the [`Atomic`] trait
+/// so that it can be used by generic code.
+///
+/// # Implementation
+///
+/// Values of up to 8 bytes are stored as bits in the smallest of
+/// [`AtomicU8`](crate::atomic::AtomicU8),
+/// [`AtomicU16`](crate::atomic::AtomicU16),
+/// [`AtomicU32`](crate::atomic::AtomicU32) and
+/// [`AtomicU64`](crate::atomic::AtomicU64) they fit in, whatever their
+/// alignment, and every operation is lock-free and uses the orderings of
+/// the policy `P`. Larger values are stored in place and every operation
+/// holds one of a fixed set of global spin locks, selected by the address
+/// of the cell, while it reads or writes the value; these operations behave
+/// as `SeqCst` operations whatever the policy.
+/// [`is_lock_free`](Self::is_lock_free) tells which implementation a type
+/// uses.
+///
+/// The closures of `fetch_update` and the other functional updates never
+/// run while a lock is held.
+///
+/// # Comparisons
+///
+/// The CAS operations compare values by their bytes, so `T` does not need
+/// to implement `PartialEq`, and values which are equal but have different
+/// representations, such as `0.0` and `-0.0`, are different. Reading the
+/// bytes of padding, e.g. in `(u8, u16)`, would be undefined behavior, so
+/// `T` must implement `NoUninit`, which excludes types with padding.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicCell;
+/// use prism3_rust_concurrent::no_uninit;
+///
+/// no_uninit! {
+///     #[derive(Clone, Copy, Debug, PartialEq)]
+///     struct Point {
+///         x: i16,
+///         y: i16,
+///     }
+/// }
+///
+/// let point = AtomicCell::new(Point { x: 0, y: 0 });
+/// assert!(AtomicCell::<Point>::is_lock_free());
+///
+/// point.fetch_update(|p| Point { x: p.x + 1, ..p });
+/// assert_eq!(point.load(), Point { x: 1, y: 0 });
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct AtomicCell<T: NoUninit, P = DefaultOrdering> {
+    storage: Storage<T, P>,
+}
+
+impl<T: NoUninit> AtomicCell<T> {
+    /// Creates a new atomic cell.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new('a');
+    /// assert_eq!(cell.load(), 'a');
+    /// ```
+    #[inline]
+    pub fn new(value: T) -> Self {
+        Self::with_policy(value)
+    }
+}
+
+impl<T: NoUninit, P: OrderingPolicy> AtomicCell<T, P> {
+    /// Creates a new atomic cell using the ordering policy `P`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicCell, SeqCst};
+    ///
+    /// let cell = AtomicCell::<(u32, u32), SeqCst>::with_policy((1, 2));
+    /// assert_eq!(cell.load(), (1, 2));
+    /// ```
+    #[inline]
+    pub fn with_policy(value: T) -> Self {
+        let () = T::LAYOUT_CHECK;
+        let storage = match mem::size_of::<T>() {
+            0..=1 => Storage::U8(AtomicU8::with_policy(to_bits(value))),
+            2 => Storage::U16(AtomicU16::with_policy(to_bits(value))),
+            3..=4 => Storage::U32(AtomicU32::with_policy(to_bits(value))),
+            5..=8 => Storage::U64(AtomicU64::with_policy(to_bits(value))),
+            _ => Storage::Locked(UnsafeCell::new(value)),
+        };
+        Self { storage }
+    }
+
+    /// Returns whether the operations on values of type `T` are lock-free.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `T` is at most 8 bytes large, `false` if the operations
+    /// use the lock-based implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// assert!(AtomicCell::<(u32, u32)>::is_lock_free());
+    /// assert!(!AtomicCell::<[u64; 4]>::is_lock_free());
+    /// ```
+    #[inline]
+    pub fn is_lock_free() -> bool {
+        mem::size_of::<T>() <= 8
+    }
+
+    /// Acquires the lock guarding a locked storage.
+    #[inline]
+    fn lock(cell: &UnsafeCell<T>) -> StripedLockGuard {
+        StripedLockGuard::acquire(cell.get() as usize)
+    }
+
+    /// Gets the current value.
+    ///
+    /// # Returns
+    ///
+    /// The current value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new((1u32, 2u32));
+    /// assert_eq!(cell.load(), (1, 2));
+    /// ```
+    #[inline]
+    pub fn load(&self) -> T {
+        with_storage!(&self.storage,
+            atomic => from_bits(atomic.load()),
+            cell => {
+                let _guard = Self::lock(cell);
+                // SAFETY: the lock serializes all accesses to the cell.
+                unsafe { *cell.get() }
+            }
+        )
+    }
+
+    /// Sets a new value.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new([0u64; 4]);
+    /// cell.store([1, 2, 3, 4]);
+    /// assert_eq!(cell.load(), [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn store(&self, value: T) {
+        with_storage!(&self.storage,
+            atomic => atomic.store(to_bits(value)),
+            cell => {
+                let _guard = Self::lock(cell);
+                // SAFETY: the lock serializes all accesses to the cell.
+                unsafe { *cell.get() = value }
+            }
+        )
+    }
+
+    /// Swaps the current value with a new value, returning the old value.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new value.
+    ///
+    /// # Returns
+    ///
+    /// The old value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new('a');
+    /// assert_eq!(cell.swap('b'), 'a');
+    /// assert_eq!(cell.load(), 'b');
+    /// ```
+    #[inline]
+    pub fn swap(&self, value: T) -> T {
+        with_storage!(&self.storage,
+            atomic => from_bits(atomic.swap(to_bits(value))),
+            cell => {
+                let _guard = Self::lock(cell);
+                // SAFETY: the lock serializes all accesses to the cell.
+                unsafe { mem::replace(&mut *cell.get(), value) }
+            }
+        )
+    }
+
+    /// Compares and sets the value atomically.
+    ///
+    /// The values are compared by their bytes, see the
+    /// [type-level documentation](Self#comparisons).
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new((1u32, 2u32));
+    /// assert!(cell.compare_set((1, 2), (3, 4)).is_ok());
+    /// assert_eq!(cell.compare_set((1, 2), (5, 6)), Err((3, 4)));
+    /// ```
+    #[inline]
+    pub fn compare_set(&self, current: T, new: T) -> Result<(), T> {
+        with_storage!(&self.storage,
+            atomic => atomic
+                .compare_set(to_bits(current), to_bits(new))
+                .map_err(from_bits),
+            cell => {
+                let _guard = Self::lock(cell);
+                // SAFETY: the lock serializes all accesses to the cell.
+                let value = unsafe { &mut *cell.get() };
+                if bytes_eq(value, &current) {
+                    *value = new;
+                    Ok(())
+                } else {
+                    Err(*value)
+                }
+            }
+        )
+    }
+
+    /// Weak version of compare-and-set.
+    ///
+    /// May spuriously fail even when the comparison succeeds. Should be used
+    /// in a loop.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new('a');
+    /// let mut current = cell.load();
+    /// loop {
+    ///     match cell.compare_set_weak(current, 'z') {
+    ///         Ok(_) => break,
+    ///         Err(actual) => current = actual,
+    ///     }
+    /// }
+    /// assert_eq!(cell.load(), 'z');
+    /// ```
+    #[inline]
+    pub fn compare_set_weak(&self, current: T, new: T) -> Result<(), T> {
+        with_storage!(&self.storage,
+            atomic => atomic
+                .compare_set_weak(to_bits(current), to_bits(new))
+                .map_err(from_bits),
+            _cell => self.compare_set(current, new)
+        )
+    }
+
+    /// Compares and exchanges the value atomically, returning the previous
+    /// value.
+    ///
+    /// If the current value equals `current`, sets it to `new` and returns
+    /// `current`. Otherwise, returns the actual current value. The values
+    /// are compared by their bytes.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    ///
+    /// # Returns
+    ///
+    /// The value before the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new('a');
+    /// assert_eq!(cell.compare_and_exchange('a', 'b'), 'a');
+    /// assert_eq!(cell.compare_and_exchange('a', 'c'), 'b');
+    /// ```
+    #[inline]
+    pub fn compare_and_exchange(&self, current: T, new: T) -> T {
+        match self.compare_set(current, new) {
+            Ok(()) => current,
+            Err(actual) => actual,
+        }
+    }
+
+    /// Weak version of compare-and-exchange.
+    ///
+    /// May spuriously fail even when the comparison succeeds. Should be used
+    /// in a loop.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    ///
+    /// # Returns
+    ///
+    /// The value before the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new(1u8);
+    /// let mut current = cell.load();
+    /// loop {
+    ///     let prev = cell.compare_and_exchange_weak(current, 2);
+    ///     if prev == current {
+    ///         break;
+    ///     }
+    ///     current = prev;
+    /// }
+    /// assert_eq!(cell.load(), 2);
+    /// ```
+    #[inline]
+    pub fn compare_and_exchange_weak(&self, current: T, new: T) -> T {
+        match self.compare_set_weak(current, new) {
+            Ok(()) => current,
+            Err(actual) => actual,
+        }
+    }
+
+    /// Updates the value using a function, returning the old value.
+    ///
+    /// Internally uses a CAS loop until the update succeeds; the function
+    /// may be called several times.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current value and returns the new
+    ///   value.
+    ///
+    /// # Returns
+    ///
+    /// The old value before the update.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new((1u32, 10u32));
+    /// let old = cell.fetch_update(|(a, b)| (a + 1, b - 1));
+    /// assert_eq!(old, (1, 10));
+    /// assert_eq!(cell.load(), (2, 9));
+    /// ```
+    #[inline]
+    pub fn fetch_update<F>(&self, mut f: F) -> T
+    where
+        F: FnMut(T) -> T,
+    {
+        match self.try_update(|current| Some(f(current))) {
+            Ok(old) | Err(old) => old,
+        }
+    }
+
+    /// Updates the value using a function, returning the new value.
+    ///
+    /// Internally uses a CAS loop until the update succeeds. The returned
+    /// value is exactly the one that was stored.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current value and returns the new
+    ///   value.
+    ///
+    /// # Returns
+    ///
+    /// The new value after the update.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new('a');
+    /// let new = cell.update_and_get(|c| c.to_ascii_uppercase());
+    /// assert_eq!(new, 'A');
+    /// ```
+    #[inline]
+    pub fn update_and_get<F>(&self, mut f: F) -> T
+    where
+        F: FnMut(T) -> T,
+    {
+        let mut new = None;
+        self.fetch_update(|current| {
+            let value = f(current);
+            new = Some(value);
+            value
+        });
+        match new {
+            Some(value) => value,
+            None => unreachable!("fetch_update always calls the function"),
+        }
+    }
+
+    /// Updates the value using a function that may decline, returning the
+    /// old value.
+    ///
+    /// Internally uses a CAS loop that stops as soon as `f` returns `None`,
+    /// leaving the value unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current value and returns the new
+    ///   value, or `None` to abort the update.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(old_value)` if the value was updated, or `Err(current_value)` if
+    /// `f` returned `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new((3u16, 4u16));
+    /// let shrink = |(w, h): (u16, u16)| {
+    ///     Some((w.checked_sub(1)?, h.checked_sub(1)?))
+    /// };
+    /// assert_eq!(cell.try_update(shrink), Ok((3, 4)));
+    /// cell.store((0, 4));
+    /// assert_eq!(cell.try_update(shrink), Err((0, 4)));
+    /// ```
+    #[inline]
+    pub fn try_update<F>(&self, mut f: F) -> Result<T, T>
+    where
+        F: FnMut(T) -> Option<T>,
+    {
+        self.try_update_result(|current| f(current).ok_or(current))
+    }
+
+    /// Updates the value using a fallible function, returning the old value.
+    ///
+    /// Internally uses a CAS loop that stops as soon as `f` returns an
+    /// error, leaving the value unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current value and returns the new
+    ///   value, or an error to abort the update.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(old_value)` if the value was updated, or the error returned by
+    /// `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new('y');
+    /// let next = |c: char| {
+    ///     char::from_u32(c as u32 + 1)
+    ///         .filter(char::is_ascii_lowercase)
+    ///         .ok_or("no next letter")
+    /// };
+    /// assert_eq!(cell.try_update_result(next), Ok('y'));
+    /// assert_eq!(cell.try_update_result(next), Err("no next letter"));
+    /// ```
+    #[inline]
+    pub fn try_update_result<F, E>(&self, mut f: F) -> Result<T, E>
+    where
+        F: FnMut(T) -> Result<T, E>,
+    {
+        let mut backoff = P::BACKOFF;
+        let mut current = self.load();
+        loop {
+            let new = f(current)?;
+            match self.compare_set_weak(current, new) {
+                Ok(()) => return Ok(current),
+                Err(actual) => {
+                    current = actual;
+                    backoff.backoff();
+                }
+            }
+        }
+    }
+
+    /// Consumes the cell, returning the value.
+    ///
+    /// # Returns
+    ///
+    /// The value held by the cell.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCell;
+    ///
+    /// let cell = AtomicCell::new([7u64; 3]);
+    /// assert_eq!(cell.into_inner(), [7, 7, 7]);
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> T {
+        with_storage!(self.storage,
+            atomic => from_bits(atomic.load()),
+            cell => cell.into_inner()
+        )
+    }
+}
+
+impl<T: NoUninit, P: OrderingPolicy> Atomic for AtomicCell<T, P> {
+    type Value = T;
+
+    #[inline]
+    fn load(&self) -> T {
+        self.load()
+    }
+
+    #[inline]
+    fn store(&self, value: T) {
+        self.store(value);
+    }
+
+    #[inline]
+    fn swap(&self, value: T) -> T {
+        self.swap(value)
+    }
+
+    #[inline]
+    fn compare_set(&self, current: T, new: T) -> Result<(), T> {
+        self.compare_set(current, new)
+    }
+
+    #[inline]
+    fn compare_set_weak(&self, current: T, new: T) -> Result<(), T> {
+        self.compare_set_weak(current, new)
+    }
+
+    #[inline]
+    fn compare_exchange(&self, current: T, new: T) -> T {
+        self.compare_and_exchange(current, new)
+    }
+
+    #[inline]
+    fn compare_exchange_weak(&self, current: T, new: T) -> T {
+        self.compare_and_exchange_weak(current, new)
+    }
+
+    #[inline]
+    fn fetch_update<F>(&self, f: F) -> T
+    where
+        F: FnMut(T) -> T,
+    {
+        self.fetch_update(f)
+    }
+
+    #[inline]
+    fn update_and_get<F>(&self, f: F) -> T
+    where
+        F: FnMut(T) -> T,
+    {
+        self.update_and_get(f)
+    }
+
+    #[inline]
+    fn try_update<F>(&self, f: F) -> Result<T, T>
+    where
+        F: FnMut(T) -> Option<T>,
+    {
+        self.try_update(f)
+    }
+
+    #[inline]
+    fn try_update_result<F, E>(&self, f: F) -> Result<T, E>
+    where
+        F: FnMut(T) -> Result<T, E>,
+    {
+        self.try_update_result(f)
+    }
+}
+
+// SAFETY: the value of a locked storage is only accessed while holding its
+// lock, and values are only moved between threads by copy, which `T: Send`
+// allows.
+unsafe impl<T: NoUninit + Send, P> Sync for AtomicCell<T, P> {}
+
+impl<T: NoUninit + Default> Default for AtomicCell<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: NoUninit> From<T> for AtomicCell<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: NoUninit + fmt::Debug, P: OrderingPolicy> fmt::Debug for AtomicCell<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicCell")
+            .field("value", &self.load())
+            .finish()
+    }
+}
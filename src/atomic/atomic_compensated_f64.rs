@@ -0,0 +1,291 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Compensated 64-bit Floating Point Accumulator
+//!
+//! Provides an accumulator of 64-bit floating point values that uses
+//! Neumaier's compensated summation, so that long sums of small values keep
+//! full precision.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::sync::atomic::fence;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use crate::atomic::backoff::Backoff;
+use crate::atomic::backoff::ExponentialBackoff;
+use crate::atomic::AtomicF64;
+
+/// Atomic accumulator of 64-bit floating point values with compensated
+/// summation.
+///
+/// Adding many values with `AtomicF64::fetch_add` accumulates a rounding
+/// error on every addition: a thousand additions of `0.1` do not reach
+/// `100.0`. This type keeps, next to the running sum, a compensation term
+/// holding the low-order bits lost by each addition (Neumaier's variant of
+/// Kahan summation). The error of `sum` is then about one rounding of the
+/// exact result, independently of the number of additions, unless the
+/// values cancel almost completely.
+///
+/// # Implementation Details
+///
+/// The sum and the compensation must be updated together, which would need
+/// a 128-bit compare-and-exchange that the standard library does not offer
+/// on stable Rust. The pair is therefore stored in two [`AtomicF64`] words
+/// protected by a sequence lock:
+///
+/// - Writers (`add`, `reset`, `sum_then_reset`) make the sequence number
+///   odd, update both words and make it even again. Writers exclude each
+///   other, so a write never fails, but it may wait for a concurrent write
+///   to finish.
+/// - Readers (`sum`) read both words between two loads of the sequence
+///   number and retry if a write happened in between. Readers never block
+///   writers.
+///
+/// The orderings are dictated by the sequence lock, so unlike the other
+/// atomic types this type has no ordering policy. A completed `add`
+/// happens-before every `sum` that observes it.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicCompensatedF64;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// let total = Arc::new(AtomicCompensatedF64::new());
+/// let mut handles = vec![];
+///
+/// for _ in 0..10 {
+///     let total = total.clone();
+///     let handle = thread::spawn(move || {
+///         for _ in 0..100 {
+///             total.add(0.1);
+///         }
+///     });
+///     handles.push(handle);
+/// }
+///
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// assert_eq!(total.sum(), 100.0);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct AtomicCompensatedF64 {
+    seq: AtomicUsize,
+    sum: AtomicF64,
+    compensation: AtomicF64,
+}
+
+impl AtomicCompensatedF64 {
+    /// Creates a new accumulator with a sum of zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCompensatedF64;
+    ///
+    /// let total = AtomicCompensatedF64::new();
+    /// assert_eq!(total.sum(), 0.0);
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            seq: AtomicUsize::new(0),
+            sum: AtomicF64::new(0.0),
+            compensation: AtomicF64::new(0.0),
+        }
+    }
+
+    /// Adds a value to the sum.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - The value to add.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCompensatedF64;
+    ///
+    /// let total = AtomicCompensatedF64::new();
+    /// total.add(1e16);
+    /// total.add(1.0);
+    /// total.add(-1e16);
+    /// assert_eq!(total.sum(), 1.0);
+    /// ```
+    #[inline]
+    pub fn add(&self, x: f64) {
+        let seq = self.lock();
+        let sum = self.sum.load_with_ordering(Ordering::Relaxed);
+        let compensation = self.compensation.load_with_ordering(Ordering::Relaxed);
+        let t = sum + x;
+        // Recover the low-order bits of the smaller operand lost in `t`
+        let lost = if sum.abs() >= x.abs() {
+            (sum - t) + x
+        } else {
+            (x - t) + sum
+        };
+        self.sum.store_with_ordering(t, Ordering::Relaxed);
+        self.compensation
+            .store_with_ordering(compensation + lost, Ordering::Relaxed);
+        self.unlock(seq);
+    }
+
+    /// Returns the current compensated sum.
+    ///
+    /// The sum and the compensation are read as a consistent pair, so the
+    /// result reflects exactly the additions completed before it.
+    ///
+    /// # Returns
+    ///
+    /// The compensated sum.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCompensatedF64;
+    ///
+    /// let total = AtomicCompensatedF64::new();
+    /// for _ in 0..1000 {
+    ///     total.add(0.1);
+    /// }
+    /// assert_eq!(total.sum(), 100.0);
+    /// ```
+    #[inline]
+    pub fn sum(&self) -> f64 {
+        let mut backoff = ExponentialBackoff::new();
+        loop {
+            let seq = self.seq.load(Ordering::Acquire);
+            if seq & 1 == 0 {
+                let sum = self.sum.load_with_ordering(Ordering::Relaxed);
+                let compensation = self.compensation.load_with_ordering(Ordering::Relaxed);
+                fence(Ordering::Acquire);
+                if self.seq.load(Ordering::Relaxed) == seq {
+                    return sum + compensation;
+                }
+            }
+            backoff.backoff();
+        }
+    }
+
+    /// Resets the sum to zero.
+    ///
+    /// Additions completed before the reset are discarded, additions
+    /// starting after it are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCompensatedF64;
+    ///
+    /// let total = AtomicCompensatedF64::new();
+    /// total.add(3.0);
+    /// total.reset();
+    /// assert_eq!(total.sum(), 0.0);
+    /// ```
+    #[inline]
+    pub fn reset(&self) {
+        self.sum_then_reset();
+    }
+
+    /// Returns the current compensated sum and resets it to zero.
+    ///
+    /// Both steps happen atomically with respect to `add`, so every addition
+    /// is counted exactly once across successive calls.
+    ///
+    /// # Returns
+    ///
+    /// The compensated sum before the reset.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicCompensatedF64;
+    ///
+    /// let total = AtomicCompensatedF64::new();
+    /// total.add(3.0);
+    /// assert_eq!(total.sum_then_reset(), 3.0);
+    /// assert_eq!(total.sum(), 0.0);
+    /// ```
+    #[inline]
+    pub fn sum_then_reset(&self) -> f64 {
+        let seq = self.lock();
+        let sum = self.sum.load_with_ordering(Ordering::Relaxed);
+        let compensation = self.compensation.load_with_ordering(Ordering::Relaxed);
+        self.sum.store_with_ordering(0.0, Ordering::Relaxed);
+        self.compensation
+            .store_with_ordering(0.0, Ordering::Relaxed);
+        self.unlock(seq);
+        sum + compensation
+    }
+
+    /// Makes the sequence number odd, waiting for a concurrent writer to
+    /// finish, and returns its previous even value.
+    #[inline]
+    fn lock(&self) -> usize {
+        let mut backoff = ExponentialBackoff::new();
+        loop {
+            let seq = self.seq.load(Ordering::Relaxed);
+            if seq & 1 == 0
+                && self
+                    .seq
+                    .compare_exchange_weak(
+                        seq,
+                        seq.wrapping_add(1),
+                        Ordering::Acquire,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+            {
+                // Keeps the writes of the protected words after the odd
+                // sequence number for readers
+                fence(Ordering::Release);
+                return seq;
+            }
+            backoff.backoff();
+        }
+    }
+
+    /// Publishes the writes made since `lock` returned `seq`.
+    #[inline]
+    fn unlock(&self, seq: usize) {
+        self.seq.store(seq.wrapping_add(2), Ordering::Release);
+    }
+}
+
+impl Default for AtomicCompensatedF64 {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for AtomicCompensatedF64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicCompensatedF64")
+            .field("sum", &self.sum())
+            .finish()
+    }
+}
+
+impl fmt::Display for AtomicCompensatedF64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.sum())
+    }
+}
@@ -0,0 +1,742 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Enum
+//!
+//! Provides an atomic wrapper of fieldless enums, such as the states of a
+//! state machine, stored by their discriminant in an atomic integer.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::atomic::atomic_u16::AtomicU16;
+use crate::atomic::atomic_u32::AtomicU32;
+use crate::atomic::atomic_u8::AtomicU8;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+use crate::atomic::traits::Atomic;
+
+/// Primitive representation of the enums stored in [`AtomicEnum`].
+///
+/// Implemented for `u8`, `u16` and `u32`, whose values are stored in
+/// [`AtomicU8`](crate::atomic::AtomicU8),
+/// [`AtomicU16`](crate::atomic::AtomicU16) and
+/// [`AtomicU32`](crate::atomic::AtomicU32) respectively.
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait EnumRepr: Copy + Eq + fmt::Debug {
+    /// The atomic integer type storing values of this type under the
+    /// ordering policy `P`.
+    type Atomic<P: OrderingPolicy>: Atomic<Value = Self>;
+
+    /// Creates an atomic integer holding `value`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial value.
+    ///
+    /// # Returns
+    ///
+    /// The new atomic integer.
+    fn new_atomic<P: OrderingPolicy>(value: Self) -> Self::Atomic<P>;
+}
+
+impl EnumRepr for u8 {
+    type Atomic<P: OrderingPolicy> = AtomicU8<P>;
+
+    #[inline]
+    fn new_atomic<P: OrderingPolicy>(value: Self) -> Self::Atomic<P> {
+        AtomicU8::with_policy(value)
+    }
+}
+
+impl EnumRepr for u16 {
+    type Atomic<P: OrderingPolicy> = AtomicU16<P>;
+
+    #[inline]
+    fn new_atomic<P: OrderingPolicy>(value: Self) -> Self::Atomic<P> {
+        AtomicU16::with_policy(value)
+    }
+}
+
+impl EnumRepr for u32 {
+    type Atomic<P: OrderingPolicy> = AtomicU32<P>;
+
+    #[inline]
+    fn new_atomic<P: OrderingPolicy>(value: Self) -> Self::Atomic<P> {
+        AtomicU32::with_policy(value)
+    }
+}
+
+/// Trait for the values stored in [`AtomicEnum`].
+///
+/// Maps the values to and from a primitive representation. For fieldless
+/// enums, the trait is usually implemented by the [`atomic_enum!`] macro,
+/// which uses the discriminants as representation.
+///
+/// Implementations must round-trip: `from_repr(value.to_repr())` must be
+/// `Some(value)` for every value.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicEnumValue;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// enum Direction {
+///     Up,
+///     Down,
+/// }
+///
+/// impl AtomicEnumValue for Direction {
+///     type Repr = u8;
+///
+///     fn to_repr(self) -> u8 {
+///         self as u8
+///     }
+///
+///     fn from_repr(repr: u8) -> Option<Self> {
+///         match repr {
+///             0 => Some(Direction::Up),
+///             1 => Some(Direction::Down),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Direction::from_repr(Direction::Down.to_repr()),
+///            Some(Direction::Down));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait AtomicEnumValue: Copy {
+    /// The primitive representation of the values.
+    type Repr: EnumRepr;
+
+    /// Converts the value to its representation.
+    ///
+    /// # Returns
+    ///
+    /// The representation of the value.
+    fn to_repr(self) -> Self::Repr;
+
+    /// Converts a representation back to the value.
+    ///
+    /// # Parameters
+    ///
+    /// * `repr` - The representation.
+    ///
+    /// # Returns
+    ///
+    /// The value represented by `repr`, or `None` if `repr` does not
+    /// represent any value.
+    fn from_repr(repr: Self::Repr) -> Option<Self>;
+}
+
+/// Implements [`AtomicEnumValue`] for a fieldless enum.
+///
+/// Wraps the definition of the enum, whose representation is given after
+/// its name (`u8`, `u16` or `u32`). The enum is emitted with the matching
+/// `#[repr]` attribute, and its discriminants are used as representation.
+/// Explicit discriminants are supported.
+///
+/// The enum must implement `Copy`, usually by deriving it.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicEnum;
+/// use prism3_rust_concurrent::atomic_enum;
+///
+/// atomic_enum! {
+///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///     pub enum State: u8 {
+///         Idle,
+///         Running,
+///         Stopping,
+///         Stopped = 10,
+///     }
+/// }
+///
+/// let state = AtomicEnum::new(State::Idle);
+/// assert!(state.transition(State::Idle, State::Running).is_ok());
+/// assert_eq!(state.load(), State::Running);
+/// assert_eq!(State::Stopped as u8, 10);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+#[macro_export]
+macro_rules! atomic_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident : $repr:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident $(= $discriminant:expr)?
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr($repr)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant $(= $discriminant)?,
+            )*
+        }
+
+        impl $crate::atomic::AtomicEnumValue for $name {
+            type Repr = $repr;
+
+            #[inline]
+            fn to_repr(self) -> $repr {
+                self as $repr
+            }
+
+            #[inline]
+            fn from_repr(repr: $repr) -> ::std::option::Option<Self> {
+                $(
+                    if repr == $name::$variant as $repr {
+                        return ::std::option::Option::Some($name::$variant);
+                    }
+                )*
+                ::std::option::Option::None
+            }
+        }
+    };
+}
+
+/// Atomic enum.
+///
+/// Holds a value of a fieldless enum, e.g. the state of a state machine, in
+/// an atomic integer of its representation. The enum implements
+/// [`AtomicEnumValue`], usually via the [`atomic_enum!`] macro, so no
+/// manual transmutation is needed.
+///
+/// The orderings of the operations are those of the atomic integer under
+/// the policy `P`: `Acquire` loads, `Release` stores and `AcqRel`
+/// read-modify-write operations with the default policy, so that a thread
+/// observing a state also observes the writes made before entering it.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicEnum;
+/// use prism3_rust_concurrent::atomic_enum;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// atomic_enum! {
+///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///     enum State: u8 {
+///         Idle,
+///         Running,
+///         Stopped,
+///     }
+/// }
+///
+/// let state = Arc::new(AtomicEnum::new(State::Idle));
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let state = state.clone();
+///         thread::spawn(move || {
+///             state.transition(State::Idle, State::Running).is_ok()
+///         })
+///     })
+///     .collect();
+/// let started = handles
+///     .into_iter()
+///     .map(|h| h.join().unwrap())
+///     .filter(|started| *started)
+///     .count();
+/// // Only one thread starts the machine
+/// assert_eq!(started, 1);
+/// assert_eq!(state.load(), State::Running);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct AtomicEnum<E: AtomicEnumValue, P: OrderingPolicy = DefaultOrdering> {
+    inner: <E::Repr as EnumRepr>::Atomic<P>,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<E: AtomicEnumValue> AtomicEnum<E> {
+    /// Creates a new atomic enum.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicEnum;
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum Mode: u16 {
+    ///         Read,
+    ///         Write,
+    ///     }
+    /// }
+    ///
+    /// let mode = AtomicEnum::new(Mode::Read);
+    /// assert_eq!(mode.load(), Mode::Read);
+    /// ```
+    #[inline]
+    pub fn new(value: E) -> Self {
+        Self::with_policy(value)
+    }
+}
+
+impl<E: AtomicEnumValue, P: OrderingPolicy> AtomicEnum<E, P> {
+    /// Creates a new atomic enum using the ordering policy `P`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicEnum, SeqCst};
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum Mode: u8 {
+    ///         Read,
+    ///         Write,
+    ///     }
+    /// }
+    ///
+    /// let mode = AtomicEnum::<Mode, SeqCst>::with_policy(Mode::Write);
+    /// assert_eq!(mode.load(), Mode::Write);
+    /// ```
+    #[inline]
+    pub fn with_policy(value: E) -> Self {
+        Self {
+            inner: E::Repr::new_atomic(value.to_repr()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Converts a representation read from the atomic integer back to the
+    /// value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`AtomicEnumValue`] implementation of `E` does not
+    /// round-trip.
+    #[inline]
+    fn decode(repr: E::Repr) -> E {
+        match E::from_repr(repr) {
+            Some(value) => value,
+            None => panic!(
+                "AtomicEnum holds {:?}, which does not represent any value",
+                repr
+            ),
+        }
+    }
+
+    /// Gets the current value.
+    ///
+    /// # Returns
+    ///
+    /// The current value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicEnum;
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum Mode: u8 {
+    ///         Read,
+    ///         Write,
+    ///     }
+    /// }
+    ///
+    /// let mode = AtomicEnum::new(Mode::Read);
+    /// assert_eq!(mode.load(), Mode::Read);
+    /// ```
+    #[inline]
+    pub fn load(&self) -> E {
+        Self::decode(self.inner.load())
+    }
+
+    /// Sets a new value.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicEnum;
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum Mode: u8 {
+    ///         Read,
+    ///         Write,
+    ///     }
+    /// }
+    ///
+    /// let mode = AtomicEnum::new(Mode::Read);
+    /// mode.store(Mode::Write);
+    /// assert_eq!(mode.load(), Mode::Write);
+    /// ```
+    #[inline]
+    pub fn store(&self, value: E) {
+        self.inner.store(value.to_repr());
+    }
+
+    /// Swaps the current value with a new value, returning the old value.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new value.
+    ///
+    /// # Returns
+    ///
+    /// The old value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicEnum;
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum Mode: u8 {
+    ///         Read,
+    ///         Write,
+    ///     }
+    /// }
+    ///
+    /// let mode = AtomicEnum::new(Mode::Read);
+    /// assert_eq!(mode.swap(Mode::Write), Mode::Read);
+    /// assert_eq!(mode.load(), Mode::Write);
+    /// ```
+    #[inline]
+    pub fn swap(&self, value: E) -> E {
+        Self::decode(self.inner.swap(value.to_repr()))
+    }
+
+    /// Compares and sets the value atomically.
+    ///
+    /// Values are compared by their representation.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicEnum;
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum Mode: u8 {
+    ///         Read,
+    ///         Write,
+    ///     }
+    /// }
+    ///
+    /// let mode = AtomicEnum::new(Mode::Read);
+    /// assert!(mode.compare_set(Mode::Read, Mode::Write).is_ok());
+    /// assert_eq!(mode.compare_set(Mode::Read, Mode::Write), Err(Mode::Write));
+    /// ```
+    #[inline]
+    pub fn compare_set(&self, current: E, new: E) -> Result<(), E> {
+        self.inner
+            .compare_set(current.to_repr(), new.to_repr())
+            .map_err(Self::decode)
+    }
+
+    /// Weak version of compare-and-set.
+    ///
+    /// May spuriously fail even when the comparison succeeds. Should be used
+    /// in a loop.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicEnum;
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum Mode: u8 {
+    ///         Read,
+    ///         Write,
+    ///     }
+    /// }
+    ///
+    /// let mode = AtomicEnum::new(Mode::Read);
+    /// let mut current = mode.load();
+    /// loop {
+    ///     match mode.compare_set_weak(current, Mode::Write) {
+    ///         Ok(_) => break,
+    ///         Err(actual) => current = actual,
+    ///     }
+    /// }
+    /// assert_eq!(mode.load(), Mode::Write);
+    /// ```
+    #[inline]
+    pub fn compare_set_weak(&self, current: E, new: E) -> Result<(), E> {
+        self.inner
+            .compare_set_weak(current.to_repr(), new.to_repr())
+            .map_err(Self::decode)
+    }
+
+    /// Compares and exchanges the value atomically, returning the previous
+    /// value.
+    ///
+    /// If the current value equals `current`, sets it to `new` and returns
+    /// `current`. Otherwise, returns the actual current value.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new value to set if current matches.
+    ///
+    /// # Returns
+    ///
+    /// The value before the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicEnum;
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum Mode: u8 {
+    ///         Read,
+    ///         Write,
+    ///     }
+    /// }
+    ///
+    /// let mode = AtomicEnum::new(Mode::Read);
+    /// assert_eq!(mode.compare_and_exchange(Mode::Read, Mode::Write),
+    ///            Mode::Read);
+    /// assert_eq!(mode.compare_and_exchange(Mode::Read, Mode::Write),
+    ///            Mode::Write);
+    /// ```
+    #[inline]
+    pub fn compare_and_exchange(&self, current: E, new: E) -> E {
+        Self::decode(
+            self.inner
+                .compare_exchange(current.to_repr(), new.to_repr()),
+        )
+    }
+
+    /// Transitions from one state to another.
+    ///
+    /// Sets the value to `to` if and only if it is currently `from`. This
+    /// is [`compare_set`](Self::compare_set) named for state machines.
+    ///
+    /// # Parameters
+    ///
+    /// * `from` - The state the transition starts from.
+    /// * `to` - The state the transition leads to.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the transition was made, or `Err(actual)` with the actual
+    /// state if it was not `from`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicEnum;
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum State: u8 {
+    ///         Idle,
+    ///         Running,
+    ///         Stopping,
+    ///         Stopped,
+    ///     }
+    /// }
+    ///
+    /// let state = AtomicEnum::new(State::Running);
+    /// assert!(state.transition(State::Running, State::Stopping).is_ok());
+    /// // Cannot stop twice
+    /// assert_eq!(state.transition(State::Running, State::Stopping),
+    ///            Err(State::Stopping));
+    /// ```
+    #[inline]
+    pub fn transition(&self, from: E, to: E) -> Result<(), E> {
+        self.compare_set(from, to)
+    }
+
+    /// Updates the value using a function, returning the old value.
+    ///
+    /// Internally uses a CAS loop until the update succeeds; the function
+    /// may be called several times.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current value and returns the new
+    ///   value.
+    ///
+    /// # Returns
+    ///
+    /// The old value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicEnum;
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum Light: u8 {
+    ///         Red,
+    ///         Green,
+    ///     }
+    /// }
+    ///
+    /// let light = AtomicEnum::new(Light::Red);
+    /// let toggle = |light| match light {
+    ///     Light::Red => Light::Green,
+    ///     Light::Green => Light::Red,
+    /// };
+    /// assert_eq!(light.fetch_update(toggle), Light::Red);
+    /// assert_eq!(light.load(), Light::Green);
+    /// ```
+    #[inline]
+    pub fn fetch_update<F>(&self, mut f: F) -> E
+    where
+        F: FnMut(E) -> E,
+    {
+        Self::decode(
+            self.inner
+                .fetch_update(|current| f(Self::decode(current)).to_repr()),
+        )
+    }
+
+    /// Updates the value using a function that may decline, returning the
+    /// old value.
+    ///
+    /// Internally uses a CAS loop that stops as soon as `f` returns `None`,
+    /// leaving the value unchanged. Useful for transitions allowed from
+    /// several states.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current value and returns the new
+    ///   value, or `None` to abort the update.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(old)` if the value was updated, or `Err(current)` if `f` returned
+    /// `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicEnum;
+    /// use prism3_rust_concurrent::atomic_enum;
+    ///
+    /// atomic_enum! {
+    ///     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    ///     enum State: u8 {
+    ///         Idle,
+    ///         Running,
+    ///         Stopped,
+    ///     }
+    /// }
+    ///
+    /// let state = AtomicEnum::new(State::Idle);
+    /// let stop = |state| match state {
+    ///     State::Idle | State::Running => Some(State::Stopped),
+    ///     State::Stopped => None,
+    /// };
+    /// assert_eq!(state.try_update(stop), Ok(State::Idle));
+    /// assert_eq!(state.try_update(stop), Err(State::Stopped));
+    /// ```
+    #[inline]
+    pub fn try_update<F>(&self, mut f: F) -> Result<E, E>
+    where
+        F: FnMut(E) -> Option<E>,
+    {
+        self.inner
+            .try_update(|current| f(Self::decode(current)).map(E::to_repr))
+            .map(Self::decode)
+            .map_err(Self::decode)
+    }
+}
+
+impl<E: AtomicEnumValue + Default> Default for AtomicEnum<E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(E::default())
+    }
+}
+
+impl<E: AtomicEnumValue> From<E> for AtomicEnum<E> {
+    #[inline]
+    fn from(value: E) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<E, P> fmt::Debug for AtomicEnum<E, P>
+where
+    E: AtomicEnumValue + fmt::Debug,
+    P: OrderingPolicy,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicEnum")
+            .field("value", &self.load())
+            .finish()
+    }
+}
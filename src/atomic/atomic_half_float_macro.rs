@@ -0,0 +1,2324 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Half-Precision Float Macro
+//!
+//! Provides a macro to generate the 16-bit atomic floating point types with
+//! consistent implementations.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+/// Macro to generate 16-bit atomic floating point types.
+///
+/// The generated type stores the bits of the 16-bit value in an `AtomicU16`
+/// and exposes the value as `f32`.
+///
+/// # Parameters
+///
+/// * `$name` - The name of the atomic type (e.g., `AtomicF16`)
+/// * `$doc_type` - The format description for documentation (e.g.,
+///   "bfloat16")
+/// * `$to_bits` - The function rounding an `f32` to the bits of the format
+/// * `$from_bits` - The function converting the bits of the format to `f32`
+macro_rules! impl_atomic_half_float {
+    ($name:ident, $doc_type:expr, $to_bits:path, $from_bits:path) => {
+        #[doc = concat!("Atomic ", $doc_type, " floating point number.")]
+        ///
+        /// Stores a 16-bit floating point value in an `AtomicU16` and
+        /// exposes it as `f32`, the type in which 16-bit values are usually
+        /// computed. The API mirrors
+        /// [`AtomicF32`](crate::atomic::AtomicF32): `load`, `store`, CAS,
+        /// arithmetic and functional updates via CAS loops, max/min with a
+        /// NaN policy, and the `Atomic` and `AtomicNumber` traits.
+        ///
+        /// # Rounding
+        ///
+        /// Every `f32` argument that is stored or compared as a bit pattern
+        /// is first rounded to the nearest value of this type, ties to
+        /// even, and every returned value is exactly representable in this
+        /// type. Arithmetic such as `fetch_add` is computed in `f32` and
+        /// rounded once, which gives the correctly rounded 16-bit result.
+        /// Values too large for the format become infinities.
+        ///
+        /// # Memory Ordering Strategy
+        ///
+        /// This type uses the same memory ordering strategy as `AtomicF32`:
+        /// `Acquire` loads, `Release` stores, `AcqRel` read-modify-write
+        /// operations, and `AcqRel`/`Acquire` in CAS loops. These are the
+        /// orderings of the default policy
+        /// [`DefaultOrdering`](crate::atomic::DefaultOrdering). Another
+        /// policy can be selected with the type parameter `P`, e.g.
+        #[doc = concat!("`", stringify!($name), "<SeqCst>`; such instances are created")]
+        /// with [`with_policy`](Self::with_policy).
+        ///
+        /// # Implementation Details
+        ///
+        /// The conversions between `f32` and the 16-bit format are
+        /// implemented in this crate, see [`to_bits`](Self::to_bits) and
+        /// [`from_bits`](Self::from_bits). NaN payloads survive the round
+        /// trip through `f32`, so CAS loops terminate for every stored
+        /// value.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+        /// use std::sync::Arc;
+        /// use std::thread;
+        ///
+        #[doc = concat!("let sum = Arc::new(", stringify!($name), "::new(0.0));")]
+        /// let mut handles = vec![];
+        ///
+        /// for _ in 0..4 {
+        ///     let sum = sum.clone();
+        ///     let handle = thread::spawn(move || {
+        ///         for _ in 0..16 {
+        ///             sum.fetch_add(0.5);
+        ///         }
+        ///     });
+        ///     handles.push(handle);
+        /// }
+        ///
+        /// for handle in handles {
+        ///     handle.join().unwrap();
+        /// }
+        ///
+        /// assert_eq!(sum.load(), 32.0);
+        /// ```
+        ///
+        /// # Author
+        ///
+        /// Haixing Hu
+        #[repr(transparent)]
+        pub struct $name<P = DefaultOrdering> {
+            inner: AtomicU16,
+            _policy: PhantomData<fn() -> P>,
+        }
+
+        impl $name {
+            /// Creates a new atomic floating point number.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The initial value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(2.5);")]
+            /// assert_eq!(atomic.load(), 2.5);
+            /// ```
+            #[inline]
+            pub fn new(value: f32) -> Self {
+                Self::with_policy(value)
+            }
+
+            /// Rounds an `f32` to the nearest value of this type, ties to even,
+            /// and returns its bit pattern.
+            ///
+            /// This is the conversion applied to every value stored in the
+            /// atomic. Values too large for the format become infinities, and
+            /// NaN payloads are kept as far as they fit.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to convert.
+            ///
+            /// # Returns
+            ///
+            /// The bit pattern of the rounded value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let bits = ", stringify!($name), "::to_bits(1.0);")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::from_bits(bits), 1.0);")]
+            /// ```
+            #[inline]
+            pub fn to_bits(value: f32) -> u16 {
+                $to_bits(value)
+            }
+
+            /// Converts a bit pattern of this type to `f32`.
+            ///
+            /// The conversion is exact, and converting the result back with
+            /// `to_bits` yields the same bit pattern.
+            ///
+            /// # Parameters
+            ///
+            /// * `bits` - The bit pattern to convert.
+            ///
+            /// # Returns
+            ///
+            /// The value represented by `bits`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let bits = ", stringify!($name), "::to_bits(-2.5);")]
+            #[doc = concat!("assert_eq!(", stringify!($name), "::from_bits(bits), -2.5);")]
+            /// ```
+            #[inline]
+            pub fn from_bits(bits: u16) -> f32 {
+                $from_bits(bits)
+            }
+        }
+
+        impl<P: OrderingPolicy> $name<P> {
+            /// Creates a new atomic floating point number using the ordering
+            /// policy `P`.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The initial value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::{", stringify!($name), ", SeqCst};")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::<SeqCst>::with_policy(1.5);")]
+            /// assert_eq!(atomic.load(), 1.5);
+            /// ```
+            #[inline]
+            pub fn with_policy(value: f32) -> Self {
+                Self {
+                    inner: AtomicU16::new($to_bits(value)),
+                    _policy: PhantomData,
+                }
+            }
+
+            /// Gets the current value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// Uses `Acquire` ordering on the underlying `AtomicU16`. This
+            /// ensures that all writes from other threads that happened before
+            /// a `Release` store are visible after this load.
+            ///
+            /// # Returns
+            ///
+            /// The current value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(2.5);")]
+            /// assert_eq!(atomic.load(), 2.5);
+            /// ```
+            #[inline]
+            pub fn load(&self) -> f32 {
+                self.load_with_ordering(P::LOAD)
+            }
+
+            /// Gets the current value with an explicit memory ordering.
+            ///
+            /// # Parameters
+            ///
+            /// * `ordering` - The memory ordering to use. Must be `Relaxed`,
+            ///   `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The current value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `ordering` is `Release` or `AcqRel`. In debug builds,
+            /// the ordering is validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.5);")]
+            /// assert_eq!(atomic.load_with_ordering(Ordering::Relaxed), 1.5);
+            /// ```
+            #[inline]
+            pub fn load_with_ordering(&self, ordering: Ordering) -> f32 {
+                crate::atomic::ordering::debug_validate_load(ordering);
+                $from_bits(self.inner.load(ordering))
+            }
+
+            /// Sets a new value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// Uses `Release` ordering on the underlying `AtomicU16`. This
+            /// ensures that all prior writes in this thread are visible to
+            /// other threads that perform an `Acquire` load.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value to set.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0.0);")]
+            /// atomic.store(2.5);
+            /// assert_eq!(atomic.load(), 2.5);
+            /// ```
+            #[inline]
+            pub fn store(&self, value: f32) {
+                self.store_with_ordering(value, P::STORE);
+            }
+
+            /// Sets a new value with an explicit memory ordering.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value to set.
+            /// * `ordering` - The memory ordering to use. Must be `Relaxed`,
+            ///   `Release` or `SeqCst`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `ordering` is `Acquire` or `AcqRel`. In debug builds,
+            /// the ordering is validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0.0);")]
+            /// atomic.store_with_ordering(1.5, Ordering::SeqCst);
+            /// assert_eq!(atomic.load(), 1.5);
+            /// ```
+            #[inline]
+            pub fn store_with_ordering(&self, value: f32, ordering: Ordering) {
+                crate::atomic::ordering::debug_validate_store(ordering);
+                self.inner.store($to_bits(value), ordering);
+            }
+
+            /// Swaps the current value with a new value, returning the old
+            /// value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// Uses `AcqRel` ordering on the underlying `AtomicU16`. This
+            /// provides full synchronization for this read-modify-write
+            /// operation.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value to swap in.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.0);")]
+            /// let old = atomic.swap(2.0);
+            /// assert_eq!(old, 1.0);
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn swap(&self, value: f32) -> f32 {
+                self.swap_with_ordering(value, P::RMW)
+            }
+
+            /// Swaps the current value with a new value using an explicit
+            /// memory ordering, returning the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value to swap in.
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.0);")]
+            /// let old = atomic.swap_with_ordering(2.0, Ordering::SeqCst);
+            /// assert_eq!(old, 1.0);
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn swap_with_ordering(
+                &self,
+                value: f32,
+                ordering: Ordering,
+            ) -> f32 {
+                $from_bits(self.inner.swap($to_bits(value), ordering))
+            }
+
+            /// Compares and sets the value atomically.
+            ///
+            /// If the current value equals `current`, sets it to `new` and
+            /// returns `Ok(())`. Otherwise, returns `Err(actual)` where
+            /// `actual` is the current value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// - **Success**: Uses `AcqRel` ordering on the underlying
+            ///   `AtomicU16` to ensure full synchronization when the exchange
+            ///   succeeds.
+            /// - **Failure**: Uses `Acquire` ordering to observe the actual
+            ///   value written by another thread.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Warning
+            ///
+            /// Due to NaN != NaN, CAS operations with NaN values may behave
+            /// unexpectedly. Avoid using NaN in atomic floating point
+            /// operations.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.0);")]
+            /// assert!(atomic.compare_set(1.0, 2.0).is_ok());
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn compare_set(
+                &self,
+                current: f32,
+                new: f32,
+            ) -> Result<(), f32> {
+                self.compare_set_with_ordering(
+                    current,
+                    new,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Compares and sets the value atomically with explicit memory
+            /// orderings.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            /// * `success` - The ordering of the read-modify-write if the
+            ///   comparison succeeds.
+            /// * `failure` - The ordering of the load if the comparison fails.
+            ///   Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is `Release` or `AcqRel`. In debug builds,
+            /// the orderings are validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.0);")]
+            /// assert!(atomic
+            ///     .compare_set_with_ordering(
+            ///         1.0,
+            ///         2.0,
+            ///         Ordering::SeqCst,
+            ///         Ordering::Relaxed,
+            ///     )
+            ///     .is_ok());
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn compare_set_with_ordering(
+                &self,
+                current: f32,
+                new: f32,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<(), f32> {
+                crate::atomic::ordering::debug_validate_cas(success, failure);
+                self.inner
+                    .compare_exchange(
+                        $to_bits(current),
+                        $to_bits(new),
+                        success,
+                        failure,
+                    )
+                    .map(|_| ())
+                    .map_err($from_bits)
+            }
+
+            /// Weak version of compare-and-set.
+            ///
+            /// May spuriously fail even when the comparison succeeds. Should be
+            /// used in a loop.
+            ///
+            /// Uses `AcqRel` ordering on success and `Acquire` ordering on
+            /// failure.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.0);")]
+            /// let mut current = atomic.load();
+            /// loop {
+            ///     match atomic.compare_set_weak(current, current + 1.0) {
+            ///         Ok(_) => break,
+            ///         Err(actual) => current = actual,
+            ///     }
+            /// }
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn compare_set_weak(
+                &self,
+                current: f32,
+                new: f32,
+            ) -> Result<(), f32> {
+                self.compare_set_weak_with_ordering(
+                    current,
+                    new,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Weak version of compare-and-set with explicit memory orderings.
+            ///
+            /// May spuriously fail even when the comparison succeeds. Should be
+            /// used in a loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            /// * `success` - The ordering of the read-modify-write if the
+            ///   comparison succeeds.
+            /// * `failure` - The ordering of the load if the comparison fails.
+            ///   Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is `Release` or `AcqRel`. In debug builds,
+            /// the orderings are validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.0);")]
+            /// assert!(atomic
+            ///     .compare_set_weak_with_ordering(
+            ///         1.0,
+            ///         2.0,
+            ///         Ordering::SeqCst,
+            ///         Ordering::Relaxed,
+            ///     )
+            ///     .is_ok());
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn compare_set_weak_with_ordering(
+                &self,
+                current: f32,
+                new: f32,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<(), f32> {
+                crate::atomic::ordering::debug_validate_cas(success, failure);
+                self.inner
+                    .compare_exchange_weak(
+                        $to_bits(current),
+                        $to_bits(new),
+                        success,
+                        failure,
+                    )
+                    .map(|_| ())
+                    .map_err($from_bits)
+            }
+
+            /// Compares and exchanges the value atomically, returning the
+            /// previous value.
+            ///
+            /// If the current value equals `current`, sets it to `new` and
+            /// returns the old value. Otherwise, returns the actual current
+            /// value.
+            ///
+            /// Uses `AcqRel` ordering on success and `Acquire` ordering on
+            /// failure.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.0);")]
+            /// let prev = atomic.compare_and_exchange(1.0, 2.0);
+            /// assert_eq!(prev, 1.0);
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange(&self, current: f32, new: f32) -> f32 {
+                self.compare_and_exchange_with_ordering(
+                    current,
+                    new,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Compares and exchanges the value atomically with explicit memory
+            /// orderings, returning the previous value.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            /// * `success` - The ordering of the read-modify-write if the
+            ///   comparison succeeds.
+            /// * `failure` - The ordering of the load if the comparison fails.
+            ///   Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is `Release` or `AcqRel`. In debug builds,
+            /// the orderings are validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.0);")]
+            /// let prev = atomic.compare_and_exchange_with_ordering(
+            ///     1.0,
+            ///     2.0,
+            ///     Ordering::SeqCst,
+            ///     Ordering::Relaxed,
+            /// );
+            /// assert_eq!(prev, 1.0);
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange_with_ordering(
+                &self,
+                current: f32,
+                new: f32,
+                success: Ordering,
+                failure: Ordering,
+            ) -> f32 {
+                crate::atomic::ordering::debug_validate_cas(success, failure);
+                match self.inner.compare_exchange(
+                    $to_bits(current),
+                    $to_bits(new),
+                    success,
+                    failure,
+                ) {
+                    Ok(prev_bits) => $from_bits(prev_bits),
+                    Err(actual_bits) => $from_bits(actual_bits),
+                }
+            }
+
+            /// Weak version of compare-and-exchange.
+            ///
+            /// May spuriously fail even when the comparison succeeds. Should be
+            /// used in a loop.
+            ///
+            /// Uses `AcqRel` ordering on success and `Acquire` ordering on
+            /// failure.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.0);")]
+            /// let mut current = atomic.load();
+            /// loop {
+            ///     let new = current + 1.0;
+            ///     let prev = atomic.compare_and_exchange_weak(current, new);
+            ///     if prev == current {
+            ///         break;
+            ///     }
+            ///     current = prev;
+            /// }
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange_weak(
+                &self,
+                current: f32,
+                new: f32,
+            ) -> f32 {
+                self.compare_and_exchange_weak_with_ordering(
+                    current,
+                    new,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Weak version of compare-and-exchange with explicit memory
+            /// orderings.
+            ///
+            /// May spuriously fail even when the comparison succeeds. Should be
+            /// used in a loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            /// * `success` - The ordering of the read-modify-write if the
+            ///   comparison succeeds.
+            /// * `failure` - The ordering of the load if the comparison fails.
+            ///   Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is `Release` or `AcqRel`. In debug builds,
+            /// the orderings are validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(1.0);")]
+            /// let prev = atomic.compare_and_exchange_weak_with_ordering(
+            ///     1.0,
+            ///     2.0,
+            ///     Ordering::SeqCst,
+            ///     Ordering::Relaxed,
+            /// );
+            /// assert_eq!(prev, 1.0);
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange_weak_with_ordering(
+                &self,
+                current: f32,
+                new: f32,
+                success: Ordering,
+                failure: Ordering,
+            ) -> f32 {
+                crate::atomic::ordering::debug_validate_cas(success, failure);
+                match self.inner.compare_exchange_weak(
+                    $to_bits(current),
+                    $to_bits(new),
+                    success,
+                    failure,
+                ) {
+                    Ok(prev_bits) => $from_bits(prev_bits),
+                    Err(actual_bits) => $from_bits(actual_bits),
+                }
+            }
+
+            /// Compares and sets the value atomically, comparing floating-point
+            /// values instead of bit patterns.
+            ///
+            /// `compare_set` compares the raw bits of the values, so it fails
+            /// for `current = 0.0` when the stored value is `-0.0`, and for
+            /// `current = NaN` unless the NaN payloads match. This method
+            /// instead considers `0.0` and `-0.0` equal, and any two NaNs
+            /// equal, so it can be used with values computed independently of
+            /// the stored one.
+            ///
+            /// Uses the `CAS_SUCCESS` and `CAS_FAILURE` orderings of the
+            /// policy. The stored bits may change between the comparison and
+            /// the exchange without changing the value, e.g. from `0.0` to
+            /// `-0.0`, so the exchange is retried in a loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if the current value equals
+            ///   `current`.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(-0.0);")]
+            /// assert!(atomic.compare_set(0.0, 1.0).is_err());
+            /// assert!(atomic.compare_set_value(0.0, 1.0).is_ok());
+            /// assert_eq!(atomic.load(), 1.0);
+            ///
+            /// atomic.store(f32::NAN);
+            /// assert!(atomic.compare_set_value(-f32::NAN, 2.0).is_ok());
+            /// assert_eq!(atomic.load(), 2.0);
+            /// ```
+            #[inline]
+            pub fn compare_set_value(
+                &self,
+                current: f32,
+                new: f32,
+            ) -> Result<(), f32> {
+                self.compare_exchange_matching(
+                    |actual| value_eq(actual, current),
+                    new,
+                )
+                .map(|_| ())
+            }
+
+            /// Compares and exchanges the value atomically, comparing
+            /// floating-point values instead of bit patterns, and returns the
+            /// previous value.
+            ///
+            /// Uses the same comparison and orderings as `compare_set_value`.
+            /// Note that on success the returned value may differ in bits from
+            /// `current`, e.g. `-0.0` when `current` is `0.0`.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if the current value equals
+            ///   `current`.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(f32::NAN);")]
+            /// let prev = atomic.compare_and_exchange_value(f32::NAN, 1.0);
+            /// assert!(prev.is_nan());
+            /// assert_eq!(atomic.load(), 1.0);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange_value(
+                &self,
+                current: f32,
+                new: f32,
+            ) -> f32 {
+                match self.compare_exchange_matching(
+                    |actual| value_eq(actual, current),
+                    new,
+                ) {
+                    Ok(prev) | Err(prev) => prev,
+                }
+            }
+
+            /// Compares and sets the value atomically if it is within an
+            /// absolute tolerance of the expected value.
+            ///
+            /// The exchange succeeds if `|actual - current| <= tolerance`, or
+            /// if `actual == current`, which also covers equal infinities and
+            /// is the only match left if `tolerance` is negative or NaN. A NaN
+            /// never matches. This is useful when `current` was computed
+            /// independently of the stored value, e.g. a gain recalculated from
+            /// the same inputs, so that its bits may differ slightly.
+            ///
+            /// Uses the `CAS_SUCCESS` and `CAS_FAILURE` orderings of the
+            /// policy. The exchange is retried while the stored value changes
+            /// but stays within the tolerance.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if the current value is close
+            ///   enough.
+            /// * `tolerance` - The maximum absolute difference from `current`.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` if the current value has
+            /// drifted beyond the tolerance.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let gain = ", stringify!($name), "::new(0.7);")]
+            /// assert!(gain.compare_set(0.71, 0.5).is_err());
+            /// assert!(gain.compare_set_approx(0.71, 0.5, 0.05).is_ok());
+            /// assert_eq!(gain.load(), 0.5);
+            ///
+            /// assert_eq!(gain.compare_set_approx(0.6, 0.7, 0.05), Err(0.5));
+            /// ```
+            #[inline]
+            pub fn compare_set_approx(
+                &self,
+                current: f32,
+                new: f32,
+                tolerance: f32,
+            ) -> Result<(), f32> {
+                self.compare_exchange_matching(
+                    |actual| approx_eq(actual, current, tolerance),
+                    new,
+                )
+                .map(|_| ())
+            }
+
+            /// Compares and sets the value atomically if it is within a number
+            /// of units in the last place (ULPs) of the expected value.
+            ///
+            /// The distance between two values is the number of representable
+            /// values of this type between them, so the tolerance scales with
+            /// the magnitude of the values, unlike the absolute tolerance of
+            /// `compare_set_approx`. `0.0` and `-0.0` are 0 ULPs apart, the
+            /// largest finite value is 1 ULP from infinity, and a NaN never
+            /// matches.
+            ///
+            /// Uses the `CAS_SUCCESS` and `CAS_FAILURE` orderings of the
+            /// policy. The exchange is retried while the stored value changes
+            /// but stays within the tolerance.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if the current value is close
+            ///   enough.
+            /// * `max_ulps` - The maximum distance from `current` in ULPs.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` if the current value has
+            /// drifted beyond the tolerance.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let next = ", stringify!($name), "::from_bits(", stringify!($name), "::to_bits(0.7) + 1);")]
+            #[doc = concat!("let gain = ", stringify!($name), "::new(next);")]
+            /// assert!(gain.compare_set_ulps(0.7, 0.5, 1).is_ok());
+            /// assert_eq!(gain.load(), 0.5);
+            ///
+            /// assert_eq!(gain.compare_set_ulps(0.6, 0.7, 4), Err(0.5));
+            /// ```
+            #[inline]
+            pub fn compare_set_ulps(
+                &self,
+                current: f32,
+                new: f32,
+                max_ulps: u32,
+            ) -> Result<(), f32> {
+                self.compare_exchange_matching(
+                    |actual| ulps_eq(actual, current, max_ulps),
+                    new,
+                )
+                .map(|_| ())
+            }
+
+            /// Replaces the value with `new` if `matches` accepts the current
+            /// value, retrying the exchange with the witnessed bits while they
+            /// still match.
+            ///
+            /// Returns `Ok(previous_value)`, or `Err(current_value)` if
+            /// `matches` rejected the current value.
+            #[inline]
+            fn compare_exchange_matching<F>(
+                &self,
+                mut matches: F,
+                new: f32,
+            ) -> Result<f32, f32>
+            where
+                F: FnMut(f32) -> bool,
+            {
+                self.try_update_result(|actual| {
+                    if matches(actual) {
+                        Ok(new)
+                    } else {
+                        Err(actual)
+                    }
+                })
+            }
+
+            /// Atomically adds a value, returning the old value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// Internally uses a CAS loop with `compare_set_weak`, which uses
+            /// `AcqRel` on success and `Acquire` on failure. The loop ensures
+            /// eventual consistency even under high contention.
+            ///
+            /// # Performance
+            ///
+            /// May be slow in high-contention scenarios due to the CAS loop.
+            /// Consider using atomic integers if performance is critical.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to add.
+            ///
+            /// # Returns
+            ///
+            /// The old value before adding.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.fetch_add(5.5);
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 15.5);
+            /// ```
+            #[inline]
+            pub fn fetch_add(&self, delta: f32) -> f32 {
+                self.fetch_add_with_ordering(
+                    delta,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Atomically adds a value with explicit memory orderings,
+            /// returning the old value.
+            ///
+            /// Internally uses a CAS loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to add.
+            /// * `set_order` - The ordering of a successful CAS.
+            /// * `fetch_order` - The ordering of the initial load and of a
+            ///   failed CAS. Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The old value before adding.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is `Release` or `AcqRel`. In debug
+            /// builds, the orderings are validated before the loop starts with
+            /// a descriptive message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.fetch_add_with_ordering(
+            ///     5.5,
+            ///     Ordering::SeqCst,
+            ///     Ordering::Relaxed,
+            /// );
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 15.5);
+            /// ```
+            #[inline]
+            pub fn fetch_add_with_ordering(
+                &self,
+                delta: f32,
+                set_order: Ordering,
+                fetch_order: Ordering,
+            ) -> f32 {
+                self.fetch_update_with_ordering(
+                    set_order,
+                    fetch_order,
+                    |current| current + delta,
+                )
+            }
+
+            /// Adds a delta to the value, returning the new value.
+            ///
+            /// Uses the same orderings as `fetch_add`. The returned value is
+            /// exactly the one that was stored.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to add.
+            ///
+            /// # Returns
+            ///
+            /// The new value after adding.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// assert_eq!(atomic.add_and_get(5.5), 15.5);
+            /// assert_eq!(atomic.load(), 15.5);
+            /// ```
+            #[inline]
+            pub fn add_and_get(&self, delta: f32) -> f32 {
+                $from_bits($to_bits(self.fetch_add(delta) + delta))
+            }
+
+            /// Atomically subtracts a value, returning the old value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// Internally uses a CAS loop with `compare_set_weak`, which uses
+            /// `AcqRel` on success and `Acquire` on failure. The loop ensures
+            /// eventual consistency even under high contention.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to subtract.
+            ///
+            /// # Returns
+            ///
+            /// The old value before subtracting.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.fetch_sub(3.5);
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 6.5);
+            /// ```
+            #[inline]
+            pub fn fetch_sub(&self, delta: f32) -> f32 {
+                self.fetch_sub_with_ordering(
+                    delta,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Atomically subtracts a value with explicit memory orderings,
+            /// returning the old value.
+            ///
+            /// Internally uses a CAS loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to subtract.
+            /// * `set_order` - The ordering of a successful CAS.
+            /// * `fetch_order` - The ordering of the initial load and of a
+            ///   failed CAS. Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The old value before subtracting.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is `Release` or `AcqRel`. In debug
+            /// builds, the orderings are validated before the loop starts with
+            /// a descriptive message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.fetch_sub_with_ordering(
+            ///     3.5,
+            ///     Ordering::SeqCst,
+            ///     Ordering::Relaxed,
+            /// );
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 6.5);
+            /// ```
+            #[inline]
+            pub fn fetch_sub_with_ordering(
+                &self,
+                delta: f32,
+                set_order: Ordering,
+                fetch_order: Ordering,
+            ) -> f32 {
+                self.fetch_update_with_ordering(
+                    set_order,
+                    fetch_order,
+                    |current| current - delta,
+                )
+            }
+
+            /// Subtracts a delta from the value, returning the new value.
+            ///
+            /// Uses the same orderings as `fetch_sub`. The returned value is
+            /// exactly the one that was stored.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to subtract.
+            ///
+            /// # Returns
+            ///
+            /// The new value after subtracting.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// assert_eq!(atomic.sub_and_get(3.5), 6.5);
+            /// assert_eq!(atomic.load(), 6.5);
+            /// ```
+            #[inline]
+            pub fn sub_and_get(&self, delta: f32) -> f32 {
+                $from_bits($to_bits(self.fetch_sub(delta) - delta))
+            }
+
+            /// Atomically multiplies by a factor, returning the old value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// Internally uses a CAS loop with `compare_set_weak`, which uses
+            /// `AcqRel` on success and `Acquire` on failure. The loop ensures
+            /// eventual consistency even under high contention.
+            ///
+            /// # Parameters
+            ///
+            /// * `factor` - The factor to multiply by.
+            ///
+            /// # Returns
+            ///
+            /// The old value before multiplying.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.fetch_mul(2.5);
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 25.0);
+            /// ```
+            #[inline]
+            pub fn fetch_mul(&self, factor: f32) -> f32 {
+                self.fetch_mul_with_ordering(
+                    factor,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Atomically multiplies by a factor with explicit memory
+            /// orderings, returning the old value.
+            ///
+            /// Internally uses a CAS loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `factor` - The factor to multiply by.
+            /// * `set_order` - The ordering of a successful CAS.
+            /// * `fetch_order` - The ordering of the initial load and of a
+            ///   failed CAS. Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The old value before multiplying.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is `Release` or `AcqRel`. In debug
+            /// builds, the orderings are validated before the loop starts with
+            /// a descriptive message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.fetch_mul_with_ordering(
+            ///     2.5,
+            ///     Ordering::SeqCst,
+            ///     Ordering::Relaxed,
+            /// );
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 25.0);
+            /// ```
+            #[inline]
+            pub fn fetch_mul_with_ordering(
+                &self,
+                factor: f32,
+                set_order: Ordering,
+                fetch_order: Ordering,
+            ) -> f32 {
+                self.fetch_update_with_ordering(
+                    set_order,
+                    fetch_order,
+                    |current| current * factor,
+                )
+            }
+
+            /// Atomically divides by a divisor, returning the old value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// Internally uses a CAS loop with `compare_set_weak`, which uses
+            /// `AcqRel` on success and `Acquire` on failure. The loop ensures
+            /// eventual consistency even under high contention.
+            ///
+            /// # Parameters
+            ///
+            /// * `divisor` - The divisor to divide by.
+            ///
+            /// # Returns
+            ///
+            /// The old value before dividing.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.fetch_div(2.0);
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 5.0);
+            /// ```
+            #[inline]
+            pub fn fetch_div(&self, divisor: f32) -> f32 {
+                self.fetch_div_with_ordering(
+                    divisor,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Atomically divides by a divisor with explicit memory orderings,
+            /// returning the old value.
+            ///
+            /// Internally uses a CAS loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `divisor` - The divisor to divide by.
+            /// * `set_order` - The ordering of a successful CAS.
+            /// * `fetch_order` - The ordering of the initial load and of a
+            ///   failed CAS. Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The old value before dividing.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is `Release` or `AcqRel`. In debug
+            /// builds, the orderings are validated before the loop starts with
+            /// a descriptive message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.fetch_div_with_ordering(
+            ///     2.0,
+            ///     Ordering::SeqCst,
+            ///     Ordering::Relaxed,
+            /// );
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 5.0);
+            /// ```
+            #[inline]
+            pub fn fetch_div_with_ordering(
+                &self,
+                divisor: f32,
+                set_order: Ordering,
+                fetch_order: Ordering,
+            ) -> f32 {
+                self.fetch_update_with_ordering(
+                    set_order,
+                    fetch_order,
+                    |current| current / divisor,
+                )
+            }
+
+            /// Atomically sets the value to the maximum of the current value
+            /// and the given value, returning the old value.
+            ///
+            /// A NaN operand is ignored, following the IEEE 754 `maxNum`
+            /// operation: a NaN argument leaves the value unchanged and a
+            /// stored NaN is replaced by the argument. `-0.0` is considered
+            /// smaller than `+0.0`. Use `fetch_max_with_nan_policy` for other
+            /// NaN semantics.
+            ///
+            /// Internally uses a CAS loop with the `CAS_SUCCESS` and
+            /// `CAS_FAILURE` orderings of the policy. If the value does not
+            /// change, nothing is stored.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let peak = ", stringify!($name), "::new(10.0);")]
+            /// assert_eq!(peak.fetch_max(20.5), 10.0);
+            /// assert_eq!(peak.fetch_max(15.0), 20.5);
+            /// assert_eq!(peak.load(), 20.5);
+            /// ```
+            #[inline]
+            pub fn fetch_max(&self, value: f32) -> f32 {
+                match self.fetch_max_with_nan_policy(value, NanPolicy::Ignore) {
+                    Ok(old) | Err(old) => old,
+                }
+            }
+
+            /// Atomically sets the value to the maximum of the current value
+            /// and the given value under a NaN policy, returning the old value.
+            ///
+            /// `-0.0` is considered smaller than `+0.0`. Uses the same
+            /// orderings as `fetch_max`, and nothing is stored if the value
+            /// does not change.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            /// * `policy` - How a NaN argument or stored value is treated.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)`, or `Err(current_value)` if `policy` is
+            /// `NanPolicy::Reject` and either operand is NaN. The value is not
+            /// modified in the error case.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::{", stringify!($name), ", NanPolicy};")]
+            ///
+            #[doc = concat!("let peak = ", stringify!($name), "::new(10.0);")]
+            /// let nan = f32::NAN;
+            /// let result =
+            ///     peak.fetch_max_with_nan_policy(nan, NanPolicy::Reject);
+            /// assert_eq!(result, Err(10.0));
+            ///
+            /// let result =
+            ///     peak.fetch_max_with_nan_policy(nan, NanPolicy::Propagate);
+            /// assert_eq!(result, Ok(10.0));
+            /// assert!(peak.load().is_nan());
+            /// ```
+            #[inline]
+            pub fn fetch_max_with_nan_policy(
+                &self,
+                value: f32,
+                policy: NanPolicy,
+            ) -> Result<f32, f32> {
+                self.fetch_select(|current| select_max(current, value, policy))
+            }
+
+            /// Atomically sets the value to the minimum of the current value
+            /// and the given value, returning the old value.
+            ///
+            /// A NaN operand is ignored, following the IEEE 754 `minNum`
+            /// operation: a NaN argument leaves the value unchanged and a
+            /// stored NaN is replaced by the argument. `-0.0` is considered
+            /// smaller than `+0.0`. Use `fetch_min_with_nan_policy` for other
+            /// NaN semantics.
+            ///
+            /// Internally uses a CAS loop with the `CAS_SUCCESS` and
+            /// `CAS_FAILURE` orderings of the policy. If the value does not
+            /// change, nothing is stored.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let low = ", stringify!($name), "::new(10.0);")]
+            /// assert_eq!(low.fetch_min(5.5), 10.0);
+            /// assert_eq!(low.fetch_min(8.0), 5.5);
+            /// assert_eq!(low.load(), 5.5);
+            /// ```
+            #[inline]
+            pub fn fetch_min(&self, value: f32) -> f32 {
+                match self.fetch_min_with_nan_policy(value, NanPolicy::Ignore) {
+                    Ok(old) | Err(old) => old,
+                }
+            }
+
+            /// Atomically sets the value to the minimum of the current value
+            /// and the given value under a NaN policy, returning the old value.
+            ///
+            /// `-0.0` is considered smaller than `+0.0`. Uses the same
+            /// orderings as `fetch_min`, and nothing is stored if the value
+            /// does not change.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            /// * `policy` - How a NaN argument or stored value is treated.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)`, or `Err(current_value)` if `policy` is
+            /// `NanPolicy::Reject` and either operand is NaN. The value is not
+            /// modified in the error case.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::{", stringify!($name), ", NanPolicy};")]
+            ///
+            #[doc = concat!("let low = ", stringify!($name), "::new(f32::NAN);")]
+            /// let result =
+            ///     low.fetch_min_with_nan_policy(1.0, NanPolicy::Reject);
+            /// assert!(result.unwrap_err().is_nan());
+            ///
+            /// let result =
+            ///     low.fetch_min_with_nan_policy(1.0, NanPolicy::Ignore);
+            /// assert!(result.unwrap().is_nan());
+            /// assert_eq!(low.load(), 1.0);
+            /// ```
+            #[inline]
+            pub fn fetch_min_with_nan_policy(
+                &self,
+                value: f32,
+                policy: NanPolicy,
+            ) -> Result<f32, f32> {
+                self.fetch_select(|current| select_min(current, value, policy))
+            }
+
+            /// Atomically sets the value to the maximum of the current value
+            /// and the given value, returning the new value.
+            ///
+            /// Follows the same NaN semantics as `fetch_max`, ignoring a NaN
+            /// operand, and uses the same orderings.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            ///
+            /// # Returns
+            ///
+            /// The new value after the operation, as stored.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let peak = ", stringify!($name), "::new(10.0);")]
+            /// assert_eq!(peak.max_and_get(20.5), 20.5);
+            /// assert_eq!(peak.max_and_get(15.0), 20.5);
+            /// assert_eq!(peak.max_and_get(f32::NAN), 20.5);
+            /// ```
+            #[inline]
+            pub fn max_and_get(&self, value: f32) -> f32 {
+                let old = self.fetch_max(value);
+                // The stored value is the one selected from the old value
+                let new = select_max(old, value, NanPolicy::Ignore).unwrap_or(old);
+                $from_bits($to_bits(new))
+            }
+
+            /// Atomically sets the value to the minimum of the current value
+            /// and the given value, returning the new value.
+            ///
+            /// Follows the same NaN semantics as `fetch_min`, ignoring a NaN
+            /// operand, and uses the same orderings.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            ///
+            /// # Returns
+            ///
+            /// The new value after the operation, as stored.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let low = ", stringify!($name), "::new(f32::NAN);")]
+            /// assert_eq!(low.min_and_get(5.5), 5.5);
+            /// assert_eq!(low.min_and_get(8.0), 5.5);
+            /// assert_eq!(low.min_and_get(f32::NAN), 5.5);
+            /// ```
+            #[inline]
+            pub fn min_and_get(&self, value: f32) -> f32 {
+                let old = self.fetch_min(value);
+                // The stored value is the one selected from the old value
+                let new = select_min(old, value, NanPolicy::Ignore).unwrap_or(old);
+                $from_bits($to_bits(new))
+            }
+
+            /// Replaces the value with the one chosen by `select` in a CAS
+            /// loop, skipping the store if the chosen value has the same bits.
+            ///
+            /// Returns `Ok(old_value)`, or `Err(current_value)` if `select`
+            /// returned `None`.
+            #[inline]
+            fn fetch_select<F>(&self, mut select: F) -> Result<f32, f32>
+            where
+                F: FnMut(f32) -> Option<f32>,
+            {
+                self.try_update_result(|current| match select(current) {
+                    Some(new) if $to_bits(new) != $to_bits(current) => {
+                        Ok(new)
+                    }
+                    Some(_) => Err(Ok(current)),
+                    None => Err(Err(current)),
+                })
+                .or_else(|result| result)
+            }
+
+            /// Updates the value using a function, returning the old value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// Internally uses a CAS loop with `compare_set_weak`, which uses
+            /// `AcqRel` on success and `Acquire` on failure. The loop ensures
+            /// eventual consistency even under high contention.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the update.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.fetch_update(|x| x * 2.0);
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 20.0);
+            /// ```
+            #[inline]
+            pub fn fetch_update<F>(&self, f: F) -> f32
+            where
+                F: FnMut(f32) -> f32,
+            {
+                self.fetch_update_with_ordering(
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                    f,
+                )
+            }
+
+            /// Updates the value using a function with explicit memory
+            /// orderings, returning the old value.
+            ///
+            /// Internally uses a CAS loop until the update succeeds. The
+            /// parameter order follows `std::sync::atomic`'s `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `set_order` - The ordering of a successful CAS.
+            /// * `fetch_order` - The ordering of the initial load and of a
+            ///   failed CAS. Must be `Relaxed`, `Acquire` or `SeqCst`.
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the update.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is `Release` or `AcqRel`. In debug
+            /// builds, the orderings are validated before the loop starts with
+            /// a descriptive message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.fetch_update_with_ordering(
+            ///     Ordering::SeqCst,
+            ///     Ordering::SeqCst,
+            ///     |x| x * 2.0,
+            /// );
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 20.0);
+            /// ```
+            #[inline]
+            pub fn fetch_update_with_ordering<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> f32
+            where
+                F: FnMut(f32) -> f32,
+            {
+                self.try_update_result_with_backoff(
+                    set_order,
+                    fetch_order,
+                    P::BACKOFF,
+                    |current| Ok::<_, std::convert::Infallible>(f(current)),
+                )
+                .unwrap_or_else(|never| match never {})
+            }
+
+            /// Updates the value using a function, returning the old value and
+            /// the number of CAS attempts.
+            ///
+            /// Behaves like `fetch_update`, using the same memory orderings,
+            /// but also reports how many times `f` was called and the result
+            /// offered to a compare-and-exchange. An uncontended update takes
+            /// exactly one attempt, so the count is a cheap measure of
+            /// contention on the value. Spurious failures of the weak CAS are
+            /// counted as well.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value.
+            ///
+            /// # Returns
+            ///
+            /// A tuple of the old value before the update and the number of
+            /// attempts, which is at least 1.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let (old, attempts) = atomic.fetch_update_counted(|x| x * 2.0);
+            /// assert_eq!(old, 10.0);
+            /// assert!(attempts >= 1);
+            /// ```
+            #[inline]
+            pub fn fetch_update_counted<F>(&self, mut f: F) -> (f32, usize)
+            where
+                F: FnMut(f32) -> f32,
+            {
+                let mut attempts = 0;
+                let old = self.fetch_update(|current| {
+                    attempts += 1;
+                    f(current)
+                });
+                (old, attempts)
+            }
+
+            /// Updates the value using a function and a custom backoff
+            /// strategy, returning the old value.
+            ///
+            /// Behaves like `fetch_update`, using the same memory orderings,
+            /// but calls `backoff` after each failed CAS instead of the
+            /// `BACKOFF` strategy of the policy. Pass `&mut backoff` to inspect
+            /// the strategy's state afterwards.
+            ///
+            /// # Parameters
+            ///
+            /// * `backoff` - The strategy applied after each failed attempt.
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the update.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::{", stringify!($name), ", YieldBackoff};")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let backoff = YieldBackoff::new(4);
+            /// let old =
+            ///     atomic.fetch_update_with_backoff(backoff, |x| x * 2.0);
+            /// assert_eq!(old, 10.0);
+            /// assert_eq!(atomic.load(), 20.0);
+            /// ```
+            #[inline]
+            pub fn fetch_update_with_backoff<B, F>(
+                &self,
+                backoff: B,
+                mut f: F,
+            ) -> f32
+            where
+                B: Backoff,
+                F: FnMut(f32) -> f32,
+            {
+                self.try_update_result_with_backoff(
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                    backoff,
+                    |current| Ok::<_, std::convert::Infallible>(f(current)),
+                )
+                .unwrap_or_else(|never| match never {})
+            }
+
+            /// Updates the value using a function, returning the new value.
+            ///
+            /// Internally uses a CAS loop until the update succeeds. The
+            /// returned value is exactly the one that was stored, so `f` is not
+            /// called again to recompute it.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value.
+            ///
+            /// # Returns
+            ///
+            /// The new value after the update.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// assert_eq!(atomic.update_and_get(|x| x * 2.0), 20.0);
+            /// assert_eq!(atomic.load(), 20.0);
+            /// ```
+            #[inline]
+            pub fn update_and_get<F>(&self, mut f: F) -> f32
+            where
+                F: FnMut(f32) -> f32,
+            {
+                let mut new = 0.0;
+                self.fetch_update(|current| {
+                    new = f(current);
+                    new
+                });
+                $from_bits($to_bits(new))
+            }
+
+            /// Accumulates a value using a binary function, returning the new
+            /// value.
+            ///
+            /// Internally uses the same CAS loop as `update_and_get`.
+            ///
+            /// # Parameters
+            ///
+            /// * `x` - The value to accumulate with.
+            /// * `f` - A binary function that takes the current value and `x`,
+            ///   returning the new value.
+            ///
+            /// # Returns
+            ///
+            /// The new value after the accumulation, as stored.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// assert_eq!(atomic.accumulate_and_get(2.5, |a, b| a * b), 25.0);
+            /// assert_eq!(atomic.load(), 25.0);
+            /// ```
+            #[inline]
+            pub fn accumulate_and_get<F>(&self, x: f32, mut f: F) -> f32
+            where
+                F: FnMut(f32, f32) -> f32,
+            {
+                self.update_and_get(|current| f(current, x))
+            }
+
+            /// Updates the value using a function that may decline, returning
+            /// the old value.
+            ///
+            /// Internally uses a CAS loop. Unlike `fetch_update`, the loop
+            /// stops as soon as `f` returns `None`, and the value is left
+            /// unchanged. This matches the semantics of `std`'s `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value, or `None` to abort the update.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if `f` returned `None`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.try_update(|x| (x > 5.0).then(|| x / 2.0));
+            /// assert_eq!(old, Ok(10.0));
+            /// let old = atomic.try_update(|x| (x > 5.0).then(|| x / 2.0));
+            /// assert_eq!(old, Err(5.0));
+            /// ```
+            #[inline]
+            pub fn try_update<F>(&self, mut f: F) -> Result<f32, f32>
+            where
+                F: FnMut(f32) -> Option<f32>,
+            {
+                self.try_update_result(|current| f(current).ok_or(current))
+            }
+
+            /// Updates the value using a fallible function, returning the old
+            /// value.
+            ///
+            /// Internally uses a CAS loop. The loop stops as soon as `f`
+            /// returns an error, which is passed through to the caller, and the
+            /// value is left unchanged.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value, or an error to abort the update.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or the error returned
+            /// by `f`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let old = atomic.try_update_result(|x| Ok::<_, ()>(x + 1.0));
+            /// assert_eq!(old, Ok(10.0));
+            /// let old = atomic.try_update_result(|_| Err("busy"));
+            /// assert_eq!(old, Err("busy"));
+            /// ```
+            #[inline]
+            pub fn try_update_result<F, E>(&self, f: F) -> Result<f32, E>
+            where
+                F: FnMut(f32) -> Result<f32, E>,
+            {
+                self.try_update_result_with_backoff(
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                    P::BACKOFF,
+                    f,
+                )
+            }
+
+            /// Updates the value using a function that may decline and a custom
+            /// backoff strategy, returning the old value.
+            ///
+            /// Behaves like `try_update`, using the same memory orderings, but
+            /// calls `backoff` after each failed CAS instead of the `BACKOFF`
+            /// strategy of the policy.
+            ///
+            /// # Parameters
+            ///
+            /// * `backoff` - The strategy applied after each failed attempt.
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value, or `None` to abort the update.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if `f` returned `None`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::{", stringify!($name), ", YieldBackoff};")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10.0);")]
+            /// let backoff = YieldBackoff::new(4);
+            /// let halve = |x: f32| (x > 5.0).then(|| x / 2.0);
+            /// let old = atomic.try_update_with_backoff(backoff, halve);
+            /// assert_eq!(old, Ok(10.0));
+            /// assert_eq!(atomic.load(), 5.0);
+            /// ```
+            #[inline]
+            pub fn try_update_with_backoff<B, F>(
+                &self,
+                backoff: B,
+                mut f: F,
+            ) -> Result<f32, f32>
+            where
+                B: Backoff,
+                F: FnMut(f32) -> Option<f32>,
+            {
+                self.try_update_result_with_backoff(
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                    backoff,
+                    |current| f(current).ok_or(current),
+                )
+            }
+
+            /// Shared CAS loop of the update methods.
+            ///
+            /// Calls `f` with the current value until the value it returns is
+            /// stored, applying `backoff` after each failed CAS. Returns the
+            /// old value, or the error returned by `f`, which stops the loop.
+            #[inline]
+            fn try_update_result_with_backoff<B, F, E>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut backoff: B,
+                mut f: F,
+            ) -> Result<f32, E>
+            where
+                B: Backoff,
+                F: FnMut(f32) -> Result<f32, E>,
+            {
+                crate::atomic::ordering::debug_validate_cas(
+                    set_order,
+                    fetch_order,
+                );
+                let mut current = self.load_with_ordering(fetch_order);
+                loop {
+                    let new = f(current)?;
+                    match self.compare_set_weak_with_ordering(
+                        current,
+                        new,
+                        set_order,
+                        fetch_order,
+                    ) {
+                        Ok(_) => return Ok(current),
+                        Err(actual) => {
+                            current = actual;
+                            backoff.backoff();
+                        }
+                    }
+                }
+            }
+
+            /// Gets a reference to the underlying standard library atomic type.
+            ///
+            /// This allows direct access to the standard library's atomic
+            /// operations for advanced use cases that require fine-grained
+            /// control over memory ordering.
+            ///
+            /// # Memory Ordering
+            ///
+            /// When using the returned reference, you have full control over
+            #[doc = concat!("memory ordering. Use `", stringify!($name), "::to_bits()` and")]
+            #[doc = concat!("`", stringify!($name), "::from_bits()` for conversions.")]
+            ///
+            /// # Returns
+            ///
+            /// A reference to the underlying `std::sync::atomic::AtomicU16`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0.0);")]
+            #[doc = concat!("atomic.inner().store(", stringify!($name), "::to_bits(2.5), Ordering::Relaxed);")]
+            /// let bits = atomic.inner().load(Ordering::Relaxed);
+            #[doc = concat!("assert_eq!(", stringify!($name), "::from_bits(bits), 2.5);")]
+            /// ```
+            #[inline]
+            pub fn inner(&self) -> &AtomicU16 {
+                &self.inner
+            }
+        }
+
+        impl<P: OrderingPolicy> crate::atomic::traits::Atomic
+            for $name<P>
+        {
+            type Value = f32;
+
+            #[inline]
+            fn load(&self) -> f32 {
+                self.load()
+            }
+
+            #[inline]
+            fn store(&self, value: f32) {
+                self.store(value);
+            }
+
+            #[inline]
+            fn swap(&self, value: f32) -> f32 {
+                self.swap(value)
+            }
+
+            #[inline]
+            fn compare_set(&self, current: f32, new: f32) -> Result<(), f32> {
+                self.compare_set(current, new)
+            }
+
+            #[inline]
+            fn compare_set_weak(
+                &self,
+                current: f32,
+                new: f32,
+            ) -> Result<(), f32> {
+                self.compare_set_weak(current, new)
+            }
+
+            #[inline]
+            fn compare_exchange(&self, current: f32, new: f32) -> f32 {
+                self.compare_and_exchange(current, new)
+            }
+
+            #[inline]
+            fn compare_exchange_weak(&self, current: f32, new: f32) -> f32 {
+                self.compare_and_exchange_weak(current, new)
+            }
+
+            #[inline]
+            fn fetch_update<F>(&self, f: F) -> f32
+            where
+                F: FnMut(f32) -> f32,
+            {
+                self.fetch_update(f)
+            }
+
+            #[inline]
+            fn update_and_get<F>(&self, f: F) -> f32
+            where
+                F: FnMut(f32) -> f32,
+            {
+                self.update_and_get(f)
+            }
+
+            #[inline]
+            fn try_update<F>(&self, f: F) -> Result<f32, f32>
+            where
+                F: FnMut(f32) -> Option<f32>,
+            {
+                self.try_update(f)
+            }
+
+            #[inline]
+            fn try_update_result<F, E>(&self, f: F) -> Result<f32, E>
+            where
+                F: FnMut(f32) -> Result<f32, E>,
+            {
+                self.try_update_result(f)
+            }
+
+            #[inline]
+            fn accumulate_and_get<F>(&self, x: f32, f: F) -> f32
+            where
+                F: FnMut(f32, f32) -> f32,
+            {
+                self.accumulate_and_get(x, f)
+            }
+        }
+
+        impl<P: OrderingPolicy> crate::atomic::traits::AtomicNumber
+            for $name<P>
+        {
+            #[inline]
+            fn fetch_add(&self, delta: f32) -> f32 {
+                self.fetch_add(delta)
+            }
+
+            #[inline]
+            fn fetch_sub(&self, delta: f32) -> f32 {
+                self.fetch_sub(delta)
+            }
+
+            #[inline]
+            fn fetch_mul(&self, factor: f32) -> f32 {
+                self.fetch_mul(factor)
+            }
+
+            #[inline]
+            fn fetch_div(&self, divisor: f32) -> f32 {
+                self.fetch_div(divisor)
+            }
+
+            #[inline]
+            fn add_and_get(&self, delta: f32) -> f32 {
+                self.add_and_get(delta)
+            }
+
+            #[inline]
+            fn sub_and_get(&self, delta: f32) -> f32 {
+                self.sub_and_get(delta)
+            }
+
+            #[inline]
+            fn inc_and_get(&self) -> f32 {
+                self.add_and_get(1.0)
+            }
+
+            #[inline]
+            fn dec_and_get(&self) -> f32 {
+                self.sub_and_get(1.0)
+            }
+
+            #[inline]
+            fn max_and_get(&self, value: f32) -> f32 {
+                self.max_and_get(value)
+            }
+
+            #[inline]
+            fn min_and_get(&self, value: f32) -> f32 {
+                self.min_and_get(value)
+            }
+        }
+
+        unsafe impl<P> Send for $name<P> {}
+        unsafe impl<P> Sync for $name<P> {}
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::new(0.0)
+            }
+        }
+
+        impl From<f32> for $name {
+            #[inline]
+            fn from(value: f32) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<P: OrderingPolicy> fmt::Debug for $name<P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("value", &self.load())
+                    .finish()
+            }
+        }
+
+        impl<P: OrderingPolicy> fmt::Display for $name<P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.load())
+            }
+        }
+
+        /// Returns whether `a` equals `b` rounded to this type as numbers,
+        /// treating all NaNs as equal to each other.
+        #[inline]
+        fn value_eq(a: f32, b: f32) -> bool {
+            crate::atomic::float_cmp::value_eq(a, $from_bits($to_bits(b)))
+        }
+
+        /// Returns whether `a` is within `max_ulps` units in the last place of
+        /// `b`, counted in this type.
+        #[inline]
+        fn ulps_eq(a: f32, b: f32, max_ulps: u32) -> bool {
+            if a.is_nan() || b.is_nan() {
+                return false;
+            }
+            let distance = crate::atomic::float_cmp::ulps_distance(
+                u64::from($to_bits(a)),
+                u64::from($to_bits(b)),
+                16,
+            );
+            distance <= u64::from(max_ulps)
+        }
+    };
+}
@@ -17,8 +17,13 @@
 //! Haixing Hu
 
 use std::fmt;
+use std::marker::PhantomData;
 use std::sync::atomic::Ordering;
 
+use crate::atomic::backoff::Backoff;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
 impl_atomic_number!(
     AtomicI64,
     std::sync::atomic::AtomicI64,
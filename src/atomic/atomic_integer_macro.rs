@@ -55,15 +55,23 @@ macro_rules! impl_atomic_number {
         /// - **Max/Min operations**: Use `AcqRel` ordering as they often
         ///   coordinate with threshold-based logic.
         ///
-        /// For advanced use cases requiring different memory orderings,
-        /// use `inner()` to access the underlying standard library type.
+        /// These are the orderings of the default policy
+        /// [`DefaultOrdering`](crate::atomic::DefaultOrdering). Another
+        /// policy can be selected with the type parameter `P`, e.g.
+        #[doc = concat!("`", stringify!($name), "<SeqCst>`; such instances are created")]
+        /// with [`with_policy`](Self::with_policy). Individual operations can
+        /// use other orderings through the `*_with_ordering` methods, and
+        /// `inner()` gives access to the underlying standard library type.
         ///
         /// # Features
         ///
         /// - Automatic memory ordering selection
+        /// - Type-level ordering policies
         /// - Rich set of integer operations (increment, decrement,
         ///   arithmetic, etc.)
         /// - Zero-cost abstraction with inline methods
+        /// - Explicit memory orderings via the `*_with_ordering`
+        ///   methods
         /// - Access to underlying type via `inner()` for advanced use
         ///   cases
         ///
@@ -98,8 +106,9 @@ macro_rules! impl_atomic_number {
         ///
         /// Haixing Hu
         #[repr(transparent)]
-        pub struct $name {
+        pub struct $name<P = DefaultOrdering> {
             inner: $inner_type,
+            _policy: PhantomData<fn() -> P>,
         }
 
         impl $name {
@@ -119,8 +128,30 @@ macro_rules! impl_atomic_number {
             /// ```
             #[inline]
             pub const fn new(value: $value_type) -> Self {
+                Self::with_policy(value)
+            }
+        }
+
+        impl<P: OrderingPolicy> $name<P> {
+            /// Creates a new atomic integer using the ordering policy `P`.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The initial value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::{", stringify!($name), ", SeqCst};")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::<SeqCst>::with_policy(42);")]
+            /// assert_eq!(atomic.load(), 42);
+            /// ```
+            #[inline]
+            pub const fn with_policy(value: $value_type) -> Self {
                 Self {
                     inner: <$inner_type>::new(value),
+                    _policy: PhantomData,
                 }
             }
 
@@ -151,7 +182,42 @@ macro_rules! impl_atomic_number {
             /// ```
             #[inline]
             pub fn load(&self) -> $value_type {
-                self.inner.load(Ordering::Acquire)
+                self.load_with_ordering(P::LOAD)
+            }
+
+            /// Loads the current value with an explicit memory ordering.
+            ///
+            /// # Parameters
+            ///
+            /// * `ordering` - The memory ordering to use. Must be `Relaxed`,
+            ///   `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The current value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `ordering` is `Release` or `AcqRel`. In debug builds,
+            /// the ordering is validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(42);")]
+            /// assert_eq!(atomic.load_with_ordering(Ordering::Relaxed), 42);
+            /// ```
+            #[inline]
+            pub fn load_with_ordering(
+                &self,
+                ordering: Ordering,
+            ) -> $value_type {
+                crate::atomic::ordering::debug_validate_load(ordering);
+                self.inner.load(ordering)
             }
 
             /// Stores a new value.
@@ -182,7 +248,41 @@ macro_rules! impl_atomic_number {
             /// ```
             #[inline]
             pub fn store(&self, value: $value_type) {
-                self.inner.store(value, Ordering::Release);
+                self.store_with_ordering(value, P::STORE);
+            }
+
+            /// Stores a new value with an explicit memory ordering.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value to store.
+            /// * `ordering` - The memory ordering to use. Must be `Relaxed`,
+            ///   `Release` or `SeqCst`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `ordering` is `Acquire` or `AcqRel`. In debug builds,
+            /// the ordering is validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0);")]
+            /// atomic.store_with_ordering(42, Ordering::SeqCst);
+            /// assert_eq!(atomic.load(), 42);
+            /// ```
+            #[inline]
+            pub fn store_with_ordering(
+                &self,
+                value: $value_type,
+                ordering: Ordering,
+            ) {
+                crate::atomic::ordering::debug_validate_store(ordering);
+                self.inner.store(value, ordering);
             }
 
             /// Swaps the current value with a new value, returning the old
@@ -219,7 +319,39 @@ macro_rules! impl_atomic_number {
             /// ```
             #[inline]
             pub fn swap(&self, value: $value_type) -> $value_type {
-                self.inner.swap(value, Ordering::AcqRel)
+                self.swap_with_ordering(value, P::RMW)
+            }
+
+            /// Swaps the current value with a new value using an explicit
+            /// memory ordering, returning the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value to swap in.
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.swap_with_ordering(20, Ordering::SeqCst), 10);
+            /// assert_eq!(atomic.load(), 20);
+            /// ```
+            #[inline]
+            pub fn swap_with_ordering(
+                &self,
+                value: $value_type,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.swap(value, ordering)
             }
 
             /// Compares and sets the value atomically.
@@ -263,13 +395,64 @@ macro_rules! impl_atomic_number {
                 current: $value_type,
                 new: $value_type,
             ) -> Result<(), $value_type> {
+                self.compare_set_with_ordering(
+                    current,
+                    new,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Compares and sets the value atomically with explicit memory
+            /// orderings.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            /// * `success` - The ordering of the read-modify-write if the
+            ///   comparison succeeds.
+            /// * `failure` - The ordering of the load if the comparison fails.
+            ///   Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is `Release` or `AcqRel`. In debug builds,
+            /// the orderings are validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert!(atomic
+            ///     .compare_set_with_ordering(
+            ///         10,
+            ///         20,
+            ///         Ordering::SeqCst,
+            ///         Ordering::Relaxed,
+            ///     )
+            ///     .is_ok());
+            /// assert_eq!(atomic.load(), 20);
+            /// ```
+            #[inline]
+            pub fn compare_set_with_ordering(
+                &self,
+                current: $value_type,
+                new: $value_type,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<(), $value_type> {
+                crate::atomic::ordering::debug_validate_cas(success, failure);
                 self.inner
-                    .compare_exchange(
-                        current,
-                        new,
-                        Ordering::AcqRel,
-                        Ordering::Acquire,
-                    )
+                    .compare_exchange(current, new, success, failure)
                     .map(|_| ())
             }
 
@@ -311,13 +494,66 @@ macro_rules! impl_atomic_number {
                 current: $value_type,
                 new: $value_type,
             ) -> Result<(), $value_type> {
+                self.compare_set_weak_with_ordering(
+                    current,
+                    new,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Weak version of compare-and-set with explicit memory orderings.
+            ///
+            /// May spuriously fail even when the comparison succeeds. Should be
+            /// used in a loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            /// * `success` - The ordering of the read-modify-write if the
+            ///   comparison succeeds.
+            /// * `failure` - The ordering of the load if the comparison fails.
+            ///   Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is `Release` or `AcqRel`. In debug builds,
+            /// the orderings are validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert!(atomic
+            ///     .compare_set_weak_with_ordering(
+            ///         10,
+            ///         20,
+            ///         Ordering::SeqCst,
+            ///         Ordering::Relaxed,
+            ///     )
+            ///     .is_ok());
+            /// assert_eq!(atomic.load(), 20);
+            /// ```
+            #[inline]
+            pub fn compare_set_weak_with_ordering(
+                &self,
+                current: $value_type,
+                new: $value_type,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<(), $value_type> {
+                crate::atomic::ordering::debug_validate_cas(success, failure);
                 self.inner
-                    .compare_exchange_weak(
-                        current,
-                        new,
-                        Ordering::AcqRel,
-                        Ordering::Acquire,
-                    )
+                    .compare_exchange_weak(current, new, success, failure)
                     .map(|_| ())
             }
 
@@ -356,12 +592,66 @@ macro_rules! impl_atomic_number {
                 current: $value_type,
                 new: $value_type,
             ) -> $value_type {
-                match self.inner.compare_exchange(
+                self.compare_and_exchange_with_ordering(
                     current,
                     new,
-                    Ordering::AcqRel,
-                    Ordering::Acquire,
-                ) {
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Compares and exchanges the value atomically with explicit memory
+            /// orderings, returning the previous value.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            /// * `success` - The ordering of the read-modify-write if the
+            ///   comparison succeeds.
+            /// * `failure` - The ordering of the load if the comparison fails.
+            ///   Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation. If it equals `current`, the
+            /// operation succeeded.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is `Release` or `AcqRel`. In debug builds,
+            /// the orderings are validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let prev = atomic.compare_and_exchange_with_ordering(
+            ///     10,
+            ///     20,
+            ///     Ordering::SeqCst,
+            ///     Ordering::Relaxed,
+            /// );
+            /// assert_eq!(prev, 10);
+            /// assert_eq!(atomic.load(), 20);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange_with_ordering(
+                &self,
+                current: $value_type,
+                new: $value_type,
+                success: Ordering,
+                failure: Ordering,
+            ) -> $value_type {
+                crate::atomic::ordering::debug_validate_cas(success, failure);
+                match self
+                    .inner
+                    .compare_exchange(current, new, success, failure)
+                {
                     Ok(prev) => prev,
                     Err(actual) => actual,
                 }
@@ -407,12 +697,68 @@ macro_rules! impl_atomic_number {
                 current: $value_type,
                 new: $value_type,
             ) -> $value_type {
-                match self.inner.compare_exchange_weak(
+                self.compare_and_exchange_weak_with_ordering(
                     current,
                     new,
-                    Ordering::AcqRel,
-                    Ordering::Acquire,
-                ) {
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Weak version of compare-and-exchange with explicit memory
+            /// orderings.
+            ///
+            /// May spuriously fail even when the comparison succeeds. Should be
+            /// used in a loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            /// * `success` - The ordering of the read-modify-write if the
+            ///   comparison succeeds.
+            /// * `failure` - The ordering of the load if the comparison fails.
+            ///   Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is `Release` or `AcqRel`. In debug builds,
+            /// the orderings are validated before the access with a descriptive
+            /// message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let prev = atomic.compare_and_exchange_weak_with_ordering(
+            ///     10,
+            ///     20,
+            ///     Ordering::SeqCst,
+            ///     Ordering::Relaxed,
+            /// );
+            /// assert_eq!(prev, 10);
+            /// assert_eq!(atomic.load(), 20);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange_weak_with_ordering(
+                &self,
+                current: $value_type,
+                new: $value_type,
+                success: Ordering,
+                failure: Ordering,
+            ) -> $value_type {
+                crate::atomic::ordering::debug_validate_cas(success, failure);
+                match self
+                    .inner
+                    .compare_exchange_weak(current, new, success, failure)
+                {
                     Ok(prev) => prev,
                     Err(actual) => actual,
                 }
@@ -450,48 +796,48 @@ macro_rules! impl_atomic_number {
             /// ```
             #[inline]
             pub fn fetch_inc(&self) -> $value_type {
-                self.inner.fetch_add(1, Ordering::Relaxed)
+                self.fetch_inc_with_ordering(P::ARITHMETIC)
             }
 
-            /// Decrements the value by 1, returning the old value.
+            /// Increments the value by 1 with an explicit memory ordering,
+            /// returning the old value.
             ///
-            /// Uses `Relaxed` ordering.
+            /// # Parameters
+            ///
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
             ///
             /// # Returns
             ///
-            /// The old value before decrementing.
+            /// The old value before incrementing.
             ///
             /// # Example
             ///
             /// ```rust
             #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
             ///
             #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
-            /// let old = atomic.fetch_dec();
+            /// let old = atomic.fetch_inc_with_ordering(Ordering::SeqCst);
             /// assert_eq!(old, 10);
-            /// assert_eq!(atomic.load(), 9);
+            /// assert_eq!(atomic.load(), 11);
             /// ```
             #[inline]
-            pub fn fetch_dec(&self) -> $value_type {
-                self.inner.fetch_sub(1, Ordering::Relaxed)
+            pub fn fetch_inc_with_ordering(
+                &self,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.fetch_add(1, ordering)
             }
 
-            /// Adds a delta to the value, returning the old value.
+            /// Increments the value by 1, returning the new value.
             ///
-            /// # Memory Ordering
-            ///
-            /// Uses `Relaxed` ordering for the same reasons as
-            /// `fetch_inc()`: optimal performance for pure counting
-            /// scenarios. See `fetch_inc()` documentation for detailed
-            /// rationale.
-            ///
-            /// # Parameters
-            ///
-            /// * `delta` - The value to add.
+            /// Uses the same ordering as `fetch_inc` (`Relaxed` by default) and
+            /// wraps around on overflow.
             ///
             /// # Returns
             ///
-            /// The old value before adding.
+            /// The new value after incrementing.
             ///
             /// # Example
             ///
@@ -499,26 +845,21 @@ macro_rules! impl_atomic_number {
             #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
             ///
             #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
-            /// let old = atomic.fetch_add(5);
-            /// assert_eq!(old, 10);
-            /// assert_eq!(atomic.load(), 15);
+            /// assert_eq!(atomic.inc_and_get(), 11);
+            /// assert_eq!(atomic.load(), 11);
             /// ```
             #[inline]
-            pub fn fetch_add(&self, delta: $value_type) -> $value_type {
-                self.inner.fetch_add(delta, Ordering::Relaxed)
+            pub fn inc_and_get(&self) -> $value_type {
+                self.fetch_inc().wrapping_add(1)
             }
 
-            /// Subtracts a delta from the value, returning the old value.
+            /// Decrements the value by 1, returning the old value.
             ///
             /// Uses `Relaxed` ordering.
             ///
-            /// # Parameters
-            ///
-            /// * `delta` - The value to subtract.
-            ///
             /// # Returns
             ///
-            /// The old value before subtracting.
+            /// The old value before decrementing.
             ///
             /// # Example
             ///
@@ -526,18 +867,251 @@ macro_rules! impl_atomic_number {
             #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
             ///
             #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
-            /// let old = atomic.fetch_sub(3);
+            /// let old = atomic.fetch_dec();
             /// assert_eq!(old, 10);
-            /// assert_eq!(atomic.load(), 7);
+            /// assert_eq!(atomic.load(), 9);
             /// ```
             #[inline]
-            pub fn fetch_sub(&self, delta: $value_type) -> $value_type {
-                self.inner.fetch_sub(delta, Ordering::Relaxed)
+            pub fn fetch_dec(&self) -> $value_type {
+                self.fetch_dec_with_ordering(P::ARITHMETIC)
             }
 
-            /// Multiplies the value by a factor, returning the old value.
+            /// Decrements the value by 1 with an explicit memory ordering,
+            /// returning the old value.
             ///
-            /// Uses `Relaxed` ordering for the operation.
+            /// # Parameters
+            ///
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value before decrementing.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_dec_with_ordering(Ordering::SeqCst);
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 9);
+            /// ```
+            #[inline]
+            pub fn fetch_dec_with_ordering(
+                &self,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.fetch_sub(1, ordering)
+            }
+
+            /// Decrements the value by 1, returning the new value.
+            ///
+            /// Uses the same ordering as `fetch_dec` (`Relaxed` by default) and
+            /// wraps around on overflow.
+            ///
+            /// # Returns
+            ///
+            /// The new value after decrementing.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.dec_and_get(), 9);
+            /// assert_eq!(atomic.load(), 9);
+            /// ```
+            #[inline]
+            pub fn dec_and_get(&self) -> $value_type {
+                self.fetch_dec().wrapping_sub(1)
+            }
+
+            /// Adds a delta to the value, returning the old value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// Uses `Relaxed` ordering for the same reasons as
+            /// `fetch_inc()`: optimal performance for pure counting
+            /// scenarios. See `fetch_inc()` documentation for detailed
+            /// rationale.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to add.
+            ///
+            /// # Returns
+            ///
+            /// The old value before adding.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_add(5);
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 15);
+            /// ```
+            #[inline]
+            pub fn fetch_add(&self, delta: $value_type) -> $value_type {
+                self.fetch_add_with_ordering(delta, P::ARITHMETIC)
+            }
+
+            /// Adds a delta to the value with an explicit memory ordering,
+            /// returning the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to add.
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value before adding.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_add_with_ordering(5, Ordering::AcqRel);
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 15);
+            /// ```
+            #[inline]
+            pub fn fetch_add_with_ordering(
+                &self,
+                delta: $value_type,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.fetch_add(delta, ordering)
+            }
+
+            /// Adds a delta to the value, returning the new value.
+            ///
+            /// Uses the same ordering as `fetch_add` (`Relaxed` by default) and
+            /// wraps around on overflow.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to add.
+            ///
+            /// # Returns
+            ///
+            /// The new value after adding.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.add_and_get(5), 15);
+            /// assert_eq!(atomic.load(), 15);
+            /// ```
+            #[inline]
+            pub fn add_and_get(&self, delta: $value_type) -> $value_type {
+                self.fetch_add(delta).wrapping_add(delta)
+            }
+
+            /// Subtracts a delta from the value, returning the old value.
+            ///
+            /// Uses `Relaxed` ordering.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to subtract.
+            ///
+            /// # Returns
+            ///
+            /// The old value before subtracting.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_sub(3);
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 7);
+            /// ```
+            #[inline]
+            pub fn fetch_sub(&self, delta: $value_type) -> $value_type {
+                self.fetch_sub_with_ordering(delta, P::ARITHMETIC)
+            }
+
+            /// Subtracts a delta from the value with an explicit memory
+            /// ordering, returning the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to subtract.
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value before subtracting.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_sub_with_ordering(3, Ordering::AcqRel);
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 7);
+            /// ```
+            #[inline]
+            pub fn fetch_sub_with_ordering(
+                &self,
+                delta: $value_type,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.fetch_sub(delta, ordering)
+            }
+
+            /// Subtracts a delta from the value, returning the new value.
+            ///
+            /// Uses the same ordering as `fetch_sub` (`Relaxed` by default) and
+            /// wraps around on overflow.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to subtract.
+            ///
+            /// # Returns
+            ///
+            /// The new value after subtracting.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.sub_and_get(3), 7);
+            /// assert_eq!(atomic.load(), 7);
+            /// ```
+            #[inline]
+            pub fn sub_and_get(&self, delta: $value_type) -> $value_type {
+                self.fetch_sub(delta).wrapping_sub(delta)
+            }
+
+            /// Multiplies the value by a factor, returning the old value.
+            ///
+            /// Uses `Relaxed` ordering for the operation.
             ///
             /// # Parameters
             ///
@@ -559,14 +1133,60 @@ macro_rules! impl_atomic_number {
             /// ```
             #[inline]
             pub fn fetch_mul(&self, factor: $value_type) -> $value_type {
-                let mut current = self.load();
-                loop {
-                    let new = current.wrapping_mul(factor);
-                    match self.compare_set(current, new) {
-                        Ok(_) => return current,
-                        Err(actual) => current = actual,
-                    }
-                }
+                self.fetch_mul_with_ordering(
+                    factor,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
+            }
+
+            /// Multiplies the value by a factor with explicit memory orderings,
+            /// returning the old value.
+            ///
+            /// Implemented via a CAS loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `factor` - The factor to multiply by.
+            /// * `set_order` - The ordering of a successful CAS.
+            /// * `fetch_order` - The ordering of the initial load and of a
+            ///   failed CAS. Must be `Relaxed`, `Acquire` or `SeqCst`.
+            ///
+            /// # Returns
+            ///
+            /// The old value before multiplication.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is `Release` or `AcqRel`. In debug
+            /// builds, the orderings are validated before the loop starts with
+            /// a descriptive message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_mul_with_ordering(
+            ///     3,
+            ///     Ordering::SeqCst,
+            ///     Ordering::Relaxed,
+            /// );
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 30);
+            /// ```
+            #[inline]
+            pub fn fetch_mul_with_ordering(
+                &self,
+                factor: $value_type,
+                set_order: Ordering,
+                fetch_order: Ordering,
+            ) -> $value_type {
+                self.fetch_update_with_ordering(set_order, fetch_order, |current| {
+                    current.wrapping_mul(factor)
+                })
             }
 
             /// Divides the value by a divisor, returning the old value.
@@ -597,154 +1217,1201 @@ macro_rules! impl_atomic_number {
             /// ```
             #[inline]
             pub fn fetch_div(&self, divisor: $value_type) -> $value_type {
-                assert!(divisor != 0, "division by zero");
-                let mut current = self.load();
-                loop {
-                    let new = current.wrapping_div(divisor);
-                    match self.compare_set(current, new) {
-                        Ok(_) => return current,
-                        Err(actual) => current = actual,
-                    }
-                }
+                self.fetch_div_with_ordering(
+                    divisor,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                )
             }
 
-
-            /// Performs bitwise AND, returning the old value.
+            /// Divides the value by a divisor with explicit memory orderings,
+            /// returning the old value.
             ///
-            /// # Memory Ordering
+            /// Implemented via a CAS loop.
             ///
-            /// Uses `AcqRel` ordering because bit operations typically
-            /// manipulate flag bits that coordinate access to other data.
+            /// # Parameters
             ///
-            /// **Rationale**: Unlike pure counters, bit operations are
-            /// commonly used for:
-            /// - State flags (INITIALIZED, RUNNING, STOPPED, etc.)
-            /// - Feature toggles
-            /// - Permission masks
+            /// * `divisor` - The divisor to divide by.
+            /// * `set_order` - The ordering of a successful CAS.
+            /// * `fetch_order` - The ordering of the initial load and of a
+            ///   failed CAS. Must be `Relaxed`, `Acquire` or `SeqCst`.
             ///
-            /// These scenarios require synchronization with related data,
-            /// so we use the stronger `AcqRel` ordering by default.
+            /// # Returns
             ///
-            /// # Parameters
+            /// The old value before division.
             ///
-            /// * `value` - The value to AND with.
+            /// # Panics
             ///
-            /// # Returns
+            /// Panics if `fetch_order` is `Release` or `AcqRel`. In debug
+            /// builds, the orderings are validated before the loop starts with
+            /// a descriptive message. Also panics if `divisor` is zero.
             ///
-            /// The old value before the operation.
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(30);")]
+            /// let old = atomic.fetch_div_with_ordering(
+            ///     3,
+            ///     Ordering::SeqCst,
+            ///     Ordering::Relaxed,
+            /// );
+            /// assert_eq!(old, 30);
+            /// assert_eq!(atomic.load(), 10);
+            /// ```
+            #[inline]
+            pub fn fetch_div_with_ordering(
+                &self,
+                divisor: $value_type,
+                set_order: Ordering,
+                fetch_order: Ordering,
+            ) -> $value_type {
+                assert!(divisor != 0, "division by zero");
+                self.fetch_update_with_ordering(set_order, fetch_order, |current| {
+                    current.wrapping_div(divisor)
+                })
+            }
+
+            /// Adds a delta to the value, returning the old value, or fails
+            /// without modifying the value if the result would overflow.
+            ///
+            /// Implemented via a CAS loop with the same orderings as
+            /// `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to add.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if the operation would overflow, in which
+            /// case the value is left unchanged.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.fetch_checked_add(5), Ok(10));
+            /// assert_eq!(atomic.load(), 15);
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($value_type), "::MAX);")]
+            /// assert!(atomic.fetch_checked_add(1).is_err());
+            #[doc = concat!("assert_eq!(atomic.load(), ", stringify!($value_type), "::MAX);")]
+            /// ```
+            #[inline]
+            pub fn fetch_checked_add(
+                &self,
+                delta: $value_type,
+            ) -> Result<$value_type, $value_type> {
+                self.try_update(|current| current.checked_add(delta))
+            }
+
+            /// Subtracts a delta from the value, returning the old value, or
+            /// fails without modifying the value if the result would overflow.
+            ///
+            /// Implemented via a CAS loop with the same orderings as
+            /// `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to subtract.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if the operation would overflow, in which
+            /// case the value is left unchanged.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.fetch_checked_sub(3), Ok(10));
+            /// assert_eq!(atomic.load(), 7);
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($value_type), "::MIN);")]
+            /// assert!(atomic.fetch_checked_sub(1).is_err());
+            #[doc = concat!("assert_eq!(atomic.load(), ", stringify!($value_type), "::MIN);")]
+            /// ```
+            #[inline]
+            pub fn fetch_checked_sub(
+                &self,
+                delta: $value_type,
+            ) -> Result<$value_type, $value_type> {
+                self.try_update(|current| current.checked_sub(delta))
+            }
+
+            /// Multiplies the value by a factor, returning the old value, or
+            /// fails without modifying the value if the result would overflow.
+            ///
+            /// Implemented via a CAS loop with the same orderings as
+            /// `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `factor` - The factor to multiply by.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if the operation would overflow, in which
+            /// case the value is left unchanged.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.fetch_checked_mul(3), Ok(10));
+            /// assert_eq!(atomic.load(), 30);
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($value_type), "::MAX);")]
+            /// assert!(atomic.fetch_checked_mul(2).is_err());
+            #[doc = concat!("assert_eq!(atomic.load(), ", stringify!($value_type), "::MAX);")]
+            /// ```
+            #[inline]
+            pub fn fetch_checked_mul(
+                &self,
+                factor: $value_type,
+            ) -> Result<$value_type, $value_type> {
+                self.try_update(|current| current.checked_mul(factor))
+            }
+
+            /// Divides the value by a divisor, returning the old value, or
+            /// fails without modifying the value if the result would overflow
+            /// or divide by zero.
+            ///
+            /// Implemented via a CAS loop with the same orderings as
+            /// `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `divisor` - The divisor to divide by.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if the operation would overflow or divide
+            /// by zero, in which case the value is left unchanged.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(30);")]
+            /// assert_eq!(atomic.fetch_checked_div(3), Ok(30));
+            /// assert_eq!(atomic.load(), 10);
+            ///
+            /// assert!(atomic.fetch_checked_div(0).is_err());
+            /// assert_eq!(atomic.load(), 10);
+            /// ```
+            #[inline]
+            pub fn fetch_checked_div(
+                &self,
+                divisor: $value_type,
+            ) -> Result<$value_type, $value_type> {
+                self.try_update(|current| {
+                    current.checked_div(divisor)
+                })
+            }
+
+            /// Replaces the value with the remainder of dividing it by a
+            /// divisor, returning the old value, or fails without modifying the
+            /// value if the result would overflow or divide by zero.
+            ///
+            /// Implemented via a CAS loop with the same orderings as
+            /// `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `divisor` - The divisor to divide by.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if the operation would overflow or divide
+            /// by zero, in which case the value is left unchanged.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(17);")]
+            /// assert_eq!(atomic.fetch_checked_rem(5), Ok(17));
+            /// assert_eq!(atomic.load(), 2);
+            ///
+            /// assert!(atomic.fetch_checked_rem(0).is_err());
+            /// assert_eq!(atomic.load(), 2);
+            /// ```
+            #[inline]
+            pub fn fetch_checked_rem(
+                &self,
+                divisor: $value_type,
+            ) -> Result<$value_type, $value_type> {
+                self.try_update(|current| {
+                    current.checked_rem(divisor)
+                })
+            }
+
+            /// Adds a delta to the value, saturating at the numeric bounds
+            /// instead of overflowing, returning the old value.
+            ///
+            /// Implemented via a CAS loop with the same orderings as
+            /// `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to add.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.fetch_saturating_add(5), 10);
+            /// assert_eq!(atomic.load(), 15);
+            /// ```
+            #[inline]
+            pub fn fetch_saturating_add(
+                &self,
+                delta: $value_type,
+            ) -> $value_type {
+                self.fetch_update(|current| current.saturating_add(delta))
+            }
+
+            /// Subtracts a delta from the value, saturating at the numeric
+            /// bounds instead of overflowing, returning the old value.
+            ///
+            /// Implemented via a CAS loop with the same orderings as
+            /// `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to subtract.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.fetch_saturating_sub(3), 10);
+            /// assert_eq!(atomic.load(), 7);
+            /// ```
+            #[inline]
+            pub fn fetch_saturating_sub(
+                &self,
+                delta: $value_type,
+            ) -> $value_type {
+                self.fetch_update(|current| current.saturating_sub(delta))
+            }
+
+            /// Multiplies the value by a factor, saturating at the numeric
+            /// bounds instead of overflowing, returning the old value.
+            ///
+            /// Implemented via a CAS loop with the same orderings as
+            /// `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `factor` - The factor to multiply by.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.fetch_saturating_mul(3), 10);
+            /// assert_eq!(atomic.load(), 30);
+            /// ```
+            #[inline]
+            pub fn fetch_saturating_mul(
+                &self,
+                factor: $value_type,
+            ) -> $value_type {
+                self.fetch_update(|current| current.saturating_mul(factor))
+            }
+
+            /// Adds a delta to the value unless the result would exceed an
+            /// upper bound, returning the old value.
+            ///
+            /// The check and the update are performed atomically via a CAS loop
+            /// with the same orderings as `fetch_update`, so concurrent callers
+            /// can never push the value above `max`. This is the release side
+            /// of a counting semaphore or the admission check of a connection
+            /// limit.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to add.
+            /// * `max` - The inclusive upper bound of the new value.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if the new value would exceed `max` or
+            /// overflow, in which case the value is left unchanged.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(8);")]
+            /// assert_eq!(atomic.fetch_add_bounded(2, 10), Ok(8));
+            /// assert_eq!(atomic.fetch_add_bounded(1, 10), Err(10));
+            /// assert_eq!(atomic.load(), 10);
+            /// ```
+            #[inline]
+            pub fn fetch_add_bounded(
+                &self,
+                delta: $value_type,
+                max: $value_type,
+            ) -> Result<$value_type, $value_type> {
+                self.try_update(|current| {
+                    current.checked_add(delta).filter(|new| *new <= max)
+                })
+            }
+
+            /// Subtracts a delta from the value unless the result would fall
+            /// below a lower bound, returning the old value.
+            ///
+            /// The check and the update are performed atomically via a CAS loop
+            /// with the same orderings as `fetch_update`, so concurrent callers
+            /// can never push the value below `min`. With `min` set to `0`,
+            /// this is the try-acquire operation of a counting semaphore: a
+            /// successful call takes `delta` permits, and a failed call takes
+            /// none.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to subtract.
+            /// * `min` - The inclusive lower bound of the new value.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if the new value would fall below `min` or
+            /// overflow, in which case the value is left unchanged.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            /// // A semaphore with two permits.
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(2);")]
+            /// assert!(atomic.fetch_sub_bounded(1, 0).is_ok());
+            /// assert!(atomic.fetch_sub_bounded(1, 0).is_ok());
+            /// assert_eq!(atomic.fetch_sub_bounded(1, 0), Err(0));
+            ///
+            /// // Release a permit.
+            /// assert_eq!(atomic.fetch_add_bounded(1, 2), Ok(0));
+            /// assert_eq!(atomic.load(), 1);
+            /// ```
+            #[inline]
+            pub fn fetch_sub_bounded(
+                &self,
+                delta: $value_type,
+                min: $value_type,
+            ) -> Result<$value_type, $value_type> {
+                self.try_update(|current| {
+                    current.checked_sub(delta).filter(|new| *new >= min)
+                })
+            }
+
+            /// Performs bitwise AND, returning the old value.
+            ///
+            /// # Memory Ordering
+            ///
+            /// Uses `AcqRel` ordering because bit operations typically
+            /// manipulate flag bits that coordinate access to other data.
+            ///
+            /// **Rationale**: Unlike pure counters, bit operations are
+            /// commonly used for:
+            /// - State flags (INITIALIZED, RUNNING, STOPPED, etc.)
+            /// - Feature toggles
+            /// - Permission masks
+            ///
+            /// These scenarios require synchronization with related data,
+            /// so we use the stronger `AcqRel` ordering by default.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to AND with.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1111);")]
+            /// let old = atomic.fetch_and(0b1100);
+            /// assert_eq!(old, 0b1111);
+            /// assert_eq!(atomic.load(), 0b1100);
+            /// ```
+            #[inline]
+            pub fn fetch_and(&self, value: $value_type) -> $value_type {
+                self.fetch_and_with_ordering(value, P::BIT_OP)
+            }
+
+            /// Performs bitwise AND with an explicit memory ordering, returning
+            /// the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to AND with.
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1111);")]
+            /// let old =
+            ///     atomic.fetch_and_with_ordering(0b1100, Ordering::Release);
+            /// assert_eq!(old, 0b1111);
+            /// assert_eq!(atomic.load(), 0b1100);
+            /// ```
+            #[inline]
+            pub fn fetch_and_with_ordering(
+                &self,
+                value: $value_type,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.fetch_and(value, ordering)
+            }
+
+            /// Performs bitwise OR, returning the old value.
+            ///
+            /// Uses `AcqRel` ordering.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to OR with.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1100);")]
+            /// let old = atomic.fetch_or(0b0011);
+            /// assert_eq!(old, 0b1100);
+            /// assert_eq!(atomic.load(), 0b1111);
+            /// ```
+            #[inline]
+            pub fn fetch_or(&self, value: $value_type) -> $value_type {
+                self.fetch_or_with_ordering(value, P::BIT_OP)
+            }
+
+            /// Performs bitwise OR with an explicit memory ordering, returning
+            /// the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to OR with.
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1100);")]
+            /// let old =
+            ///     atomic.fetch_or_with_ordering(0b0011, Ordering::Release);
+            /// assert_eq!(old, 0b1100);
+            /// assert_eq!(atomic.load(), 0b1111);
+            /// ```
+            #[inline]
+            pub fn fetch_or_with_ordering(
+                &self,
+                value: $value_type,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.fetch_or(value, ordering)
+            }
+
+            /// Performs bitwise XOR, returning the old value.
+            ///
+            /// Uses `AcqRel` ordering.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to XOR with.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1100);")]
+            /// let old = atomic.fetch_xor(0b0110);
+            /// assert_eq!(old, 0b1100);
+            /// assert_eq!(atomic.load(), 0b1010);
+            /// ```
+            #[inline]
+            pub fn fetch_xor(&self, value: $value_type) -> $value_type {
+                self.fetch_xor_with_ordering(value, P::BIT_OP)
+            }
+
+            /// Performs bitwise XOR with an explicit memory ordering, returning
+            /// the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to XOR with.
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1100);")]
+            /// let old =
+            ///     atomic.fetch_xor_with_ordering(0b0110, Ordering::Release);
+            /// assert_eq!(old, 0b1100);
+            /// assert_eq!(atomic.load(), 0b1010);
+            /// ```
+            #[inline]
+            pub fn fetch_xor_with_ordering(
+                &self,
+                value: $value_type,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.fetch_xor(value, ordering)
+            }
+
+            /// Performs bitwise NOT, returning the old value.
+            ///
+            /// This is a convenience method equivalent to
+            /// `fetch_xor(-1)`. Uses `AcqRel` ordering.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1010_0101);")]
+            /// let old = atomic.fetch_not();
+            /// assert_eq!(old, 0b1010_0101);
+            #[doc = concat!("assert_eq!(atomic.load(), !0b1010_0101_", stringify!($value_type), ");")]
+            /// ```
+            ///
+            /// # Note
+            ///
+            /// This method is implemented using `fetch_xor(-1)` because
+            /// hardware and LLVM do not provide a native atomic NOT
+            /// instruction. The compiler will optimize this into
+            /// efficient machine code.
+            #[inline]
+            pub fn fetch_not(&self) -> $value_type {
+                self.fetch_not_with_ordering(P::BIT_OP)
+            }
+
+            /// Performs bitwise NOT with an explicit memory ordering, returning
+            /// the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(0);")]
+            /// assert_eq!(atomic.fetch_not_with_ordering(Ordering::SeqCst), 0);
+            /// assert_eq!(atomic.load(), !0);
+            /// ```
+            #[inline]
+            pub fn fetch_not_with_ordering(
+                &self,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.fetch_xor(!0, ordering)
+            }
+
+            /// Updates the value using a function, returning the old value.
+            ///
+            /// Internally uses a CAS loop until the update succeeds.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns
+            ///   the new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the update.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_update(|x| x * 2);
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 20);
+            /// ```
+            #[inline]
+            pub fn fetch_update<F>(&self, f: F) -> $value_type
+            where
+                F: FnMut($value_type) -> $value_type,
+            {
+                self.fetch_update_with_ordering(
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                    f,
+                )
+            }
+
+            /// Updates the value using a function with explicit memory
+            /// orderings, returning the old value.
+            ///
+            /// Internally uses a CAS loop until the update succeeds. The
+            /// parameter order follows `std::sync::atomic`'s `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `set_order` - The ordering of a successful CAS.
+            /// * `fetch_order` - The ordering of the initial load and of a
+            ///   failed CAS. Must be `Relaxed`, `Acquire` or `SeqCst`.
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the update.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is `Release` or `AcqRel`. In debug
+            /// builds, the orderings are validated before the loop starts with
+            /// a descriptive message.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_update_with_ordering(
+            ///     Ordering::SeqCst,
+            ///     Ordering::SeqCst,
+            ///     |x| x * 2,
+            /// );
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 20);
+            /// ```
+            #[inline]
+            pub fn fetch_update_with_ordering<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> $value_type
+            where
+                F: FnMut($value_type) -> $value_type,
+            {
+                self.try_update_result_with_backoff(
+                    set_order,
+                    fetch_order,
+                    P::BACKOFF,
+                    |current| Ok::<_, std::convert::Infallible>(f(current)),
+                )
+                .unwrap_or_else(|never| match never {})
+            }
+
+            /// Updates the value using a function, returning the old value and
+            /// the number of CAS attempts.
+            ///
+            /// Behaves like `fetch_update`, using the same memory orderings,
+            /// but also reports how many times `f` was called and the result
+            /// offered to a compare-and-exchange. An uncontended update takes
+            /// exactly one attempt, so the count is a cheap measure of
+            /// contention on the value. Spurious failures of the weak CAS are
+            /// counted as well.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value.
+            ///
+            /// # Returns
+            ///
+            /// A tuple of the old value before the update and the number of
+            /// attempts, which is at least 1.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let (old, attempts) = atomic.fetch_update_counted(|x| x * 2);
+            /// assert_eq!(old, 10);
+            /// assert!(attempts >= 1);
+            /// ```
+            #[inline]
+            pub fn fetch_update_counted<F>(
+                &self,
+                mut f: F,
+            ) -> ($value_type, usize)
+            where
+                F: FnMut($value_type) -> $value_type,
+            {
+                let mut attempts = 0;
+                let old = self.fetch_update(|current| {
+                    attempts += 1;
+                    f(current)
+                });
+                (old, attempts)
+            }
+
+            /// Updates the value using a function and a custom backoff
+            /// strategy, returning the old value.
+            ///
+            /// Behaves like `fetch_update`, using the same memory orderings,
+            /// but calls `backoff` after each failed CAS instead of the
+            /// `BACKOFF` strategy of the policy. Pass `&mut backoff` to inspect
+            /// the strategy's state afterwards.
+            ///
+            /// # Parameters
+            ///
+            /// * `backoff` - The strategy applied after each failed attempt.
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the update.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use prism3_rust_concurrent::atomic::YieldBackoff;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let backoff = YieldBackoff::new(4);
+            /// let old = atomic.fetch_update_with_backoff(backoff, |x| x * 2);
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 20);
+            /// ```
+            #[inline]
+            pub fn fetch_update_with_backoff<B, F>(
+                &self,
+                backoff: B,
+                mut f: F,
+            ) -> $value_type
+            where
+                B: Backoff,
+                F: FnMut($value_type) -> $value_type,
+            {
+                self.try_update_result_with_backoff(
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                    backoff,
+                    |current| Ok::<_, std::convert::Infallible>(f(current)),
+                )
+                .unwrap_or_else(|never| match never {})
+            }
+
+            /// Updates the value using a function, returning the new value.
+            ///
+            /// Internally uses a CAS loop until the update succeeds. The
+            /// returned value is exactly the one that was stored, so `f` is not
+            /// called again to recompute it.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value.
+            ///
+            /// # Returns
+            ///
+            /// The new value after the update.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.update_and_get(|x| x * 2), 20);
+            /// assert_eq!(atomic.load(), 20);
+            /// ```
+            #[inline]
+            pub fn update_and_get<F>(&self, mut f: F) -> $value_type
+            where
+                F: FnMut($value_type) -> $value_type,
+            {
+                let mut new = 0;
+                self.fetch_update(|current| {
+                    new = f(current);
+                    new
+                });
+                new
+            }
+
+            /// Updates the value using a function that may decline, returning
+            /// the old value.
+            ///
+            /// Internally uses a CAS loop. Unlike `fetch_update`, the loop
+            /// stops as soon as `f` returns `None`, and the value is left
+            /// unchanged. This matches the semantics of `std`'s `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value, or `None` to abort the update.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if `f` returned `None`.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let double = |x| (x < 20).then(|| x * 2);
+            /// assert_eq!(atomic.try_update(double), Ok(10));
+            /// assert_eq!(atomic.try_update(double), Err(20));
+            /// ```
+            #[inline]
+            pub fn try_update<F>(
+                &self,
+                mut f: F,
+            ) -> Result<$value_type, $value_type>
+            where
+                F: FnMut($value_type) -> Option<$value_type>,
+            {
+                self.try_update_result(|current| f(current).ok_or(current))
+            }
+
+            /// Updates the value using a fallible function, returning the old
+            /// value.
+            ///
+            /// Internally uses a CAS loop. The loop stops as soon as `f`
+            /// returns an error, which is passed through to the caller, and the
+            /// value is left unchanged.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value, or an error to abort the update.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old_value)` if the value was updated, or the error returned
+            /// by `f`.
             ///
             /// # Example
             ///
             /// ```rust
             #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
             ///
-            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1111);")]
-            /// let old = atomic.fetch_and(0b1100);
-            /// assert_eq!(old, 0b1111);
-            /// assert_eq!(atomic.load(), 0b1100);
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.try_update_result(|x| Ok::<_, ()>(x + 1));
+            /// assert_eq!(old, Ok(10));
+            /// let result = atomic.try_update_result(|_| Err("busy"));
+            /// assert_eq!(result, Err("busy"));
             /// ```
             #[inline]
-            pub fn fetch_and(&self, value: $value_type) -> $value_type {
-                self.inner.fetch_and(value, Ordering::AcqRel)
+            pub fn try_update_result<F, E>(
+                &self,
+                f: F,
+            ) -> Result<$value_type, E>
+            where
+                F: FnMut($value_type) -> Result<$value_type, E>,
+            {
+                self.try_update_result_with_backoff(
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                    P::BACKOFF,
+                    f,
+                )
             }
 
-            /// Performs bitwise OR, returning the old value.
+            /// Updates the value using a function that may decline and a custom
+            /// backoff strategy, returning the old value.
             ///
-            /// Uses `AcqRel` ordering.
+            /// Behaves like `try_update`, using the same memory orderings, but
+            /// calls `backoff` after each failed CAS instead of the `BACKOFF`
+            /// strategy of the policy.
             ///
             /// # Parameters
             ///
-            /// * `value` - The value to OR with.
+            /// * `backoff` - The strategy applied after each failed attempt.
+            /// * `f` - A function that takes the current value and returns the
+            ///   new value, or `None` to abort the update.
             ///
             /// # Returns
             ///
-            /// The old value before the operation.
+            /// `Ok(old_value)` if the value was updated, or
+            /// `Err(current_value)` if `f` returned `None`.
             ///
             /// # Example
             ///
             /// ```rust
             #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use prism3_rust_concurrent::atomic::YieldBackoff;
             ///
-            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1100);")]
-            /// let old = atomic.fetch_or(0b0011);
-            /// assert_eq!(old, 0b1100);
-            /// assert_eq!(atomic.load(), 0b1111);
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let backoff = YieldBackoff::new(4);
+            /// let double = |x| (x < 20).then(|| x * 2);
+            /// let old = atomic.try_update_with_backoff(backoff, double);
+            /// assert_eq!(old, Ok(10));
+            /// assert_eq!(atomic.load(), 20);
             /// ```
             #[inline]
-            pub fn fetch_or(&self, value: $value_type) -> $value_type {
-                self.inner.fetch_or(value, Ordering::AcqRel)
+            pub fn try_update_with_backoff<B, F>(
+                &self,
+                backoff: B,
+                mut f: F,
+            ) -> Result<$value_type, $value_type>
+            where
+                B: Backoff,
+                F: FnMut($value_type) -> Option<$value_type>,
+            {
+                self.try_update_result_with_backoff(
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                    backoff,
+                    |current| f(current).ok_or(current),
+                )
             }
 
-            /// Performs bitwise XOR, returning the old value.
+            /// Shared CAS loop of the update methods.
             ///
-            /// Uses `AcqRel` ordering.
+            /// Calls `f` with the current value until the value it returns is
+            /// stored, applying `backoff` after each failed CAS. Returns the
+            /// old value, or the error returned by `f`, which stops the loop.
+            #[inline]
+            fn try_update_result_with_backoff<B, F, E>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut backoff: B,
+                mut f: F,
+            ) -> Result<$value_type, E>
+            where
+                B: Backoff,
+                F: FnMut($value_type) -> Result<$value_type, E>,
+            {
+                crate::atomic::ordering::debug_validate_cas(
+                    set_order,
+                    fetch_order,
+                );
+                let mut current = self.inner.load(fetch_order);
+                loop {
+                    let new = f(current)?;
+                    match self.inner.compare_exchange_weak(
+                        current,
+                        new,
+                        set_order,
+                        fetch_order,
+                    ) {
+                        Ok(_) => return Ok(current),
+                        Err(actual) => {
+                            current = actual;
+                            backoff.backoff();
+                        }
+                    }
+                }
+            }
+
+            /// Accumulates a value using a binary function, returning the
+            /// old value.
+            ///
+            /// Internally uses a CAS loop until the update succeeds.
             ///
             /// # Parameters
             ///
-            /// * `value` - The value to XOR with.
+            /// * `x` - The value to accumulate with.
+            /// * `f` - A binary function that takes the current value and
+            ///   `x`, returning the new value.
             ///
             /// # Returns
             ///
-            /// The old value before the operation.
+            /// The old value before the accumulation.
             ///
             /// # Example
             ///
             /// ```rust
             #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
             ///
-            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1100);")]
-            /// let old = atomic.fetch_xor(0b0110);
-            /// assert_eq!(old, 0b1100);
-            /// assert_eq!(atomic.load(), 0b1010);
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_accumulate(5, |a, b| a + b);
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 15);
             /// ```
             #[inline]
-            pub fn fetch_xor(&self, value: $value_type) -> $value_type {
-                self.inner.fetch_xor(value, Ordering::AcqRel)
+            pub fn fetch_accumulate<F>(
+                &self,
+                x: $value_type,
+                f: F,
+            ) -> $value_type
+            where
+                F: FnMut($value_type, $value_type) -> $value_type,
+            {
+                self.fetch_accumulate_with_ordering(
+                    x,
+                    P::CAS_SUCCESS,
+                    P::CAS_FAILURE,
+                    f,
+                )
             }
 
-            /// Performs bitwise NOT, returning the old value.
+            /// Accumulates a value using a binary function with explicit memory
+            /// orderings, returning the old value.
             ///
-            /// This is a convenience method equivalent to
-            /// `fetch_xor(-1)`. Uses `AcqRel` ordering.
+            /// Internally uses a CAS loop until the update succeeds.
+            ///
+            /// # Parameters
+            ///
+            /// * `x` - The value to accumulate with.
+            /// * `set_order` - The ordering of a successful CAS.
+            /// * `fetch_order` - The ordering of the initial load and of a
+            ///   failed CAS. Must be `Relaxed`, `Acquire` or `SeqCst`.
+            /// * `f` - A binary function that takes the current value and `x`,
+            ///   returning the new value.
             ///
             /// # Returns
             ///
-            /// The old value before the operation.
+            /// The old value before the accumulation.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is `Release` or `AcqRel`. In debug
+            /// builds, the orderings are validated before the loop starts with
+            /// a descriptive message.
             ///
             /// # Example
             ///
             /// ```rust
             #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
             ///
-            #[doc = concat!("let atomic = ", stringify!($name), "::new(0b1010_0101);")]
-            /// let old = atomic.fetch_not();
-            /// assert_eq!(old, 0b1010_0101);
-            #[doc = concat!("assert_eq!(atomic.load(), !0b1010_0101_", stringify!($value_type), ");")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_accumulate_with_ordering(
+            ///     5,
+            ///     Ordering::SeqCst,
+            ///     Ordering::SeqCst,
+            ///     |a, b| a + b,
+            /// );
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 15);
             /// ```
-            ///
-            /// # Note
-            ///
-            /// This method is implemented using `fetch_xor(-1)` because
-            /// hardware and LLVM do not provide a native atomic NOT
-            /// instruction. The compiler will optimize this into
-            /// efficient machine code.
             #[inline]
-            pub fn fetch_not(&self) -> $value_type {
-                self.inner.fetch_xor(!0, Ordering::AcqRel)
+            pub fn fetch_accumulate_with_ordering<F>(
+                &self,
+                x: $value_type,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> $value_type
+            where
+                F: FnMut($value_type, $value_type) -> $value_type,
+            {
+                self.fetch_update_with_ordering(
+                    set_order,
+                    fetch_order,
+                    |current| f(current, x),
+                )
             }
 
-            /// Updates the value using a function, returning the old value.
+            /// Accumulates a value using a binary function, returning the old
+            /// value and the number of CAS attempts.
             ///
-            /// Internally uses a CAS loop until the update succeeds.
+            /// Behaves like `fetch_accumulate`, using the same memory
+            /// orderings, but also reports the number of attempts as described
+            /// in `fetch_update_counted`.
             ///
             /// # Parameters
             ///
-            /// * `f` - A function that takes the current value and returns
-            ///   the new value.
+            /// * `x` - The value to accumulate with.
+            /// * `f` - A binary function that takes the current value and `x`,
+            ///   returning the new value.
             ///
             /// # Returns
             ///
-            /// The old value before the update.
+            /// A tuple of the old value before the accumulation and the number
+            /// of attempts, which is at least 1.
             ///
             /// # Example
             ///
@@ -752,39 +2419,38 @@ macro_rules! impl_atomic_number {
             #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
             ///
             #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
-            /// let old = atomic.fetch_update(|x| x * 2);
+            /// let (old, attempts) =
+            ///     atomic.fetch_accumulate_counted(5, |a, b| a + b);
             /// assert_eq!(old, 10);
-            /// assert_eq!(atomic.load(), 20);
+            /// assert!(attempts >= 1);
+            /// assert_eq!(atomic.load(), 15);
             /// ```
             #[inline]
-            pub fn fetch_update<F>(&self, f: F) -> $value_type
+            pub fn fetch_accumulate_counted<F>(
+                &self,
+                x: $value_type,
+                mut f: F,
+            ) -> ($value_type, usize)
             where
-                F: Fn($value_type) -> $value_type,
+                F: FnMut($value_type, $value_type) -> $value_type,
             {
-                let mut current = self.load();
-                loop {
-                    let new = f(current);
-                    match self.compare_set_weak(current, new) {
-                        Ok(_) => return current,
-                        Err(actual) => current = actual,
-                    }
-                }
+                self.fetch_update_counted(|current| f(current, x))
             }
 
-            /// Accumulates a value using a binary function, returning the
-            /// old value.
+            /// Accumulates a value using a binary function, returning the new
+            /// value.
             ///
             /// Internally uses a CAS loop until the update succeeds.
             ///
             /// # Parameters
             ///
             /// * `x` - The value to accumulate with.
-            /// * `f` - A binary function that takes the current value and
-            ///   `x`, returning the new value.
+            /// * `f` - A binary function that takes the current value and `x`,
+            ///   returning the new value.
             ///
             /// # Returns
             ///
-            /// The old value before the accumulation.
+            /// The new value after the accumulation.
             ///
             /// # Example
             ///
@@ -792,27 +2458,19 @@ macro_rules! impl_atomic_number {
             #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
             ///
             #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
-            /// let old = atomic.fetch_accumulate(5, |a, b| a + b);
-            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.accumulate_and_get(5, |a, b| a + b), 15);
             /// assert_eq!(atomic.load(), 15);
             /// ```
             #[inline]
-            pub fn fetch_accumulate<F>(
+            pub fn accumulate_and_get<F>(
                 &self,
                 x: $value_type,
-                f: F,
+                mut f: F,
             ) -> $value_type
             where
-                F: Fn($value_type, $value_type) -> $value_type,
+                F: FnMut($value_type, $value_type) -> $value_type,
             {
-                let mut current = self.load();
-                loop {
-                    let new = f(current, x);
-                    match self.compare_set_weak(current, new) {
-                        Ok(_) => return current,
-                        Err(actual) => current = actual,
-                    }
-                }
+                self.update_and_get(|current| f(current, x))
             }
 
             /// Sets the value to the maximum of the current value and the
@@ -854,7 +2512,67 @@ macro_rules! impl_atomic_number {
             /// ```
             #[inline]
             pub fn fetch_max(&self, value: $value_type) -> $value_type {
-                self.inner.fetch_max(value, Ordering::AcqRel)
+                self.fetch_max_with_ordering(value, P::MAX_MIN)
+            }
+
+            /// Updates the value to the maximum of the current and given value
+            /// with an explicit memory ordering, returning the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_max_with_ordering(20, Ordering::Relaxed);
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 20);
+            /// ```
+            #[inline]
+            pub fn fetch_max_with_ordering(
+                &self,
+                value: $value_type,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.fetch_max(value, ordering)
+            }
+
+            /// Sets the value to the maximum of the current value and the given
+            /// value, returning the new value.
+            ///
+            /// Uses the same ordering as `fetch_max` (`AcqRel` by default).
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            ///
+            /// # Returns
+            ///
+            /// The new value after the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.max_and_get(20), 20);
+            /// assert_eq!(atomic.max_and_get(15), 20);
+            /// ```
+            #[inline]
+            pub fn max_and_get(&self, value: $value_type) -> $value_type {
+                self.fetch_max(value).max(value)
             }
 
             /// Sets the value to the minimum of the current value and the
@@ -884,7 +2602,67 @@ macro_rules! impl_atomic_number {
             /// ```
             #[inline]
             pub fn fetch_min(&self, value: $value_type) -> $value_type {
-                self.inner.fetch_min(value, Ordering::AcqRel)
+                self.fetch_min_with_ordering(value, P::MAX_MIN)
+            }
+
+            /// Updates the value to the minimum of the current and given value
+            /// with an explicit memory ordering, returning the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            /// * `ordering` - The memory ordering to use. Any ordering is
+            ///   valid.
+            ///
+            /// # Returns
+            ///
+            /// The old value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            /// use std::sync::atomic::Ordering;
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// let old = atomic.fetch_min_with_ordering(5, Ordering::Relaxed);
+            /// assert_eq!(old, 10);
+            /// assert_eq!(atomic.load(), 5);
+            /// ```
+            #[inline]
+            pub fn fetch_min_with_ordering(
+                &self,
+                value: $value_type,
+                ordering: Ordering,
+            ) -> $value_type {
+                self.inner.fetch_min(value, ordering)
+            }
+
+            /// Sets the value to the minimum of the current value and the given
+            /// value, returning the new value.
+            ///
+            /// Uses the same ordering as `fetch_min` (`AcqRel` by default).
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            ///
+            /// # Returns
+            ///
+            /// The new value after the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(10);")]
+            /// assert_eq!(atomic.min_and_get(5), 5);
+            /// assert_eq!(atomic.min_and_get(8), 5);
+            /// ```
+            #[inline]
+            pub fn min_and_get(&self, value: $value_type) -> $value_type {
+                self.fetch_min(value).min(value)
             }
 
             /// Gets a reference to the underlying standard library atomic
@@ -896,7 +2674,7 @@ macro_rules! impl_atomic_number {
             ///
             /// # Returns
             ///
-            #[doc = concat!("A reference to the underlying `std::sync::atomic::", stringify!($inner_type), "`.")]
+            #[doc = concat!("A reference to the underlying `", stringify!($inner_type), "`.")]
             ///
             /// # Example
             ///
@@ -919,7 +2697,9 @@ macro_rules! impl_atomic_number {
         // both convenient direct method calls (atomic.load()) and generic
         // trait-based programming (fn foo<T: Atomic>(atomic: &T)).
 
-        impl crate::atomic::traits::Atomic for $name {
+        impl<P: OrderingPolicy> crate::atomic::traits::Atomic
+            for $name<P>
+        {
             type Value = $value_type;
 
             #[inline]
@@ -976,20 +2756,51 @@ macro_rules! impl_atomic_number {
             #[inline]
             fn fetch_update<F>(&self, f: F) -> $value_type
             where
-                F: Fn($value_type) -> $value_type,
+                F: FnMut($value_type) -> $value_type,
             {
-                let mut current = self.load();
-                loop {
-                    let new = f(current);
-                    match self.compare_set_weak(current, new) {
-                        Ok(_) => return current,
-                        Err(actual) => current = actual,
-                    }
-                }
+                self.fetch_update(f)
+            }
+
+            #[inline]
+            fn update_and_get<F>(&self, f: F) -> $value_type
+            where
+                F: FnMut($value_type) -> $value_type,
+            {
+                self.update_and_get(f)
+            }
+
+            #[inline]
+            fn try_update<F>(&self, f: F) -> Result<$value_type, $value_type>
+            where
+                F: FnMut($value_type) -> Option<$value_type>,
+            {
+                self.try_update(f)
+            }
+
+            #[inline]
+            fn try_update_result<F, E>(&self, f: F) -> Result<$value_type, E>
+            where
+                F: FnMut($value_type) -> Result<$value_type, E>,
+            {
+                self.try_update_result(f)
+            }
+
+            #[inline]
+            fn accumulate_and_get<F>(
+                &self,
+                x: $value_type,
+                f: F,
+            ) -> $value_type
+            where
+                F: FnMut($value_type, $value_type) -> $value_type,
+            {
+                self.accumulate_and_get(x, f)
             }
         }
 
-        impl crate::atomic::traits::AtomicNumber for $name {
+        impl<P: OrderingPolicy> crate::atomic::traits::AtomicNumber
+            for $name<P>
+        {
             #[inline]
             fn fetch_add(&self, delta: $value_type) -> $value_type {
                 self.fetch_add(delta)
@@ -1009,10 +2820,40 @@ macro_rules! impl_atomic_number {
             fn fetch_div(&self, divisor: $value_type) -> $value_type {
                 self.fetch_div(divisor)
             }
+
+            #[inline]
+            fn add_and_get(&self, delta: $value_type) -> $value_type {
+                self.add_and_get(delta)
+            }
+
+            #[inline]
+            fn sub_and_get(&self, delta: $value_type) -> $value_type {
+                self.sub_and_get(delta)
+            }
+
+            #[inline]
+            fn inc_and_get(&self) -> $value_type {
+                self.inc_and_get()
+            }
+
+            #[inline]
+            fn dec_and_get(&self) -> $value_type {
+                self.dec_and_get()
+            }
+
+            #[inline]
+            fn max_and_get(&self, value: $value_type) -> $value_type {
+                self.max_and_get(value)
+            }
+
+            #[inline]
+            fn min_and_get(&self, value: $value_type) -> $value_type {
+                self.min_and_get(value)
+            }
         }
 
-        unsafe impl Send for $name {}
-        unsafe impl Sync for $name {}
+        unsafe impl<P> Send for $name<P> {}
+        unsafe impl<P> Sync for $name<P> {}
 
         impl Default for $name {
             #[inline]
@@ -1028,7 +2869,7 @@ macro_rules! impl_atomic_number {
             }
         }
 
-        impl fmt::Debug for $name {
+        impl<P: OrderingPolicy> fmt::Debug for $name<P> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 f.debug_struct(stringify!($name))
                     .field("value", &self.load())
@@ -1036,7 +2877,7 @@ macro_rules! impl_atomic_number {
             }
         }
 
-        impl fmt::Display for $name {
+        impl<P: OrderingPolicy> fmt::Display for $name<P> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 write!(f, "{}", self.load())
             }
@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero 128-bit Signed Integer
+//!
+//! Provides atomic wrappers of `NonZeroI128` and `Option<NonZeroI128>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroI128;
+
+use crate::atomic::atomic_i128::AtomicI128;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroI128,
+    AtomicI128,
+    NonZeroI128,
+    i128,
+    "128-bit signed integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroI128,
+    AtomicI128,
+    NonZeroI128,
+    i128,
+    "128-bit signed integer"
+);
@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero 16-bit Signed Integer
+//!
+//! Provides atomic wrappers of `NonZeroI16` and `Option<NonZeroI16>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroI16;
+
+use crate::atomic::atomic_i16::AtomicI16;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroI16,
+    AtomicI16,
+    NonZeroI16,
+    i16,
+    "16-bit signed integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroI16,
+    AtomicI16,
+    NonZeroI16,
+    i16,
+    "16-bit signed integer"
+);
@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero 32-bit Signed Integer
+//!
+//! Provides atomic wrappers of `NonZeroI32` and `Option<NonZeroI32>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroI32;
+
+use crate::atomic::atomic_i32::AtomicI32;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroI32,
+    AtomicI32,
+    NonZeroI32,
+    i32,
+    "32-bit signed integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroI32,
+    AtomicI32,
+    NonZeroI32,
+    i32,
+    "32-bit signed integer"
+);
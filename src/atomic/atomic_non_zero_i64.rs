@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero 64-bit Signed Integer
+//!
+//! Provides atomic wrappers of `NonZeroI64` and `Option<NonZeroI64>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroI64;
+
+use crate::atomic::atomic_i64::AtomicI64;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroI64,
+    AtomicI64,
+    NonZeroI64,
+    i64,
+    "64-bit signed integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroI64,
+    AtomicI64,
+    NonZeroI64,
+    i64,
+    "64-bit signed integer"
+);
@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero 8-bit Signed Integer
+//!
+//! Provides atomic wrappers of `NonZeroI8` and `Option<NonZeroI8>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroI8;
+
+use crate::atomic::atomic_i8::AtomicI8;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroI8,
+    AtomicI8,
+    NonZeroI8,
+    i8,
+    "8-bit signed integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroI8,
+    AtomicI8,
+    NonZeroI8,
+    i8,
+    "8-bit signed integer"
+);
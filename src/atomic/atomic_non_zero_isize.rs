@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero Pointer-Sized Signed Integer
+//!
+//! Provides atomic wrappers of `NonZeroIsize` and `Option<NonZeroIsize>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroIsize;
+
+use crate::atomic::atomic_isize::AtomicIsize;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroIsize,
+    AtomicIsize,
+    NonZeroIsize,
+    isize,
+    "pointer-sized signed integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroIsize,
+    AtomicIsize,
+    NonZeroIsize,
+    isize,
+    "pointer-sized signed integer"
+);
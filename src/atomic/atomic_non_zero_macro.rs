@@ -0,0 +1,1275 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero Integer Macros
+//!
+//! Provides macros to generate atomic wrappers of the `NonZero` integer
+//! types and of their `Option`s on top of the atomic integer types.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+/// Macro to generate an atomic non-zero integer type.
+///
+/// The generated type wraps an atomic integer type generated by
+/// `impl_atomic_number!` and never lets it hold zero. The integer may be
+/// signed or unsigned.
+///
+/// # Parameters
+///
+/// * `$name` - The name of the atomic type (e.g., `AtomicNonZeroU32`)
+/// * `$inner_type` - The atomic integer type (e.g., `AtomicU32`)
+/// * `$non_zero_type` - The non-zero value type (e.g., `NonZeroU32`)
+/// * `$value_type` - The underlying integer type (e.g., `u32`)
+/// * `$doc_type` - The type description for documentation (e.g., "32-bit
+///   unsigned integer")
+macro_rules! impl_atomic_non_zero {
+    (
+        $name:ident,
+        $inner_type:ident,
+        $non_zero_type:ident,
+        $value_type:ty,
+        $doc_type:expr
+    ) => {
+        #[doc = concat!("Atomic non-zero ", $doc_type, ".")]
+        ///
+        #[doc = concat!("Holds a [`", stringify!($non_zero_type), "`] in an [`", stringify!($inner_type), "`]")]
+        #[doc = concat!("(`crate::atomic::", stringify!($inner_type), "`), for IDs, handles and other")]
+        /// values for which zero is not a valid state. The value can never
+        /// become zero: `store`, `swap` and CAS take non-zero values, and
+        /// the operations that could produce zero (`fetch_add`,
+        /// `fetch_sub`, `fetch_update`, ...) fail instead, returning
+        /// `Err(current)` without modifying the value, like
+        /// `fetch_checked_add` of the integer types.
+        ///
+        /// The orderings of the operations are those of the wrapped
+        #[doc = concat!("integer type under the policy `P`, see [`", stringify!($inner_type), "`].")]
+        /// The underlying integer is not exposed, since storing zero into it
+        /// would break the invariant.
+        ///
+        /// The type also implements [`Atomic`](crate::atomic::Atomic) with
+        #[doc = concat!("`", stringify!($non_zero_type), "` values. The update functions of the trait")]
+        /// return non-zero values, so its `fetch_update`, unlike the inherent
+        /// one, always succeeds.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+        #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+        ///
+        #[doc = concat!("let next_id = ", stringify!($name), "::new(", stringify!($non_zero_type), "::new(1).unwrap());")]
+        /// let id = next_id.fetch_inc().unwrap();
+        /// assert_eq!(id.get(), 1);
+        /// assert_eq!(next_id.load().get(), 2);
+        ///
+        /// // The value cannot drop to zero
+        /// assert_eq!(next_id.fetch_sub(2), Err(next_id.load()));
+        /// ```
+        ///
+        /// # Author
+        ///
+        /// Haixing Hu
+        #[repr(transparent)]
+        pub struct $name<P = DefaultOrdering> {
+            inner: $inner_type<P>,
+        }
+
+        /// Converts a value read from the underlying integer, which is never
+        /// zero, to the non-zero type.
+        #[inline]
+        fn to_non_zero(value: $value_type) -> $non_zero_type {
+            match $non_zero_type::new(value) {
+                Some(value) => value,
+                None => unreachable!(concat!(stringify!($name), " holds zero")),
+            }
+        }
+
+        impl $name {
+            /// Creates a new atomic non-zero integer.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The initial value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let value = ", stringify!($non_zero_type), "::new(42).unwrap();")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(value);")]
+            /// assert_eq!(atomic.load(), value);
+            /// ```
+            #[inline]
+            pub fn new(value: $non_zero_type) -> Self {
+                Self::with_policy(value)
+            }
+        }
+
+        impl<P: OrderingPolicy> $name<P> {
+            /// Creates a new atomic non-zero integer using the ordering
+            /// policy `P`.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The initial value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::{", stringify!($name), ", SeqCst};")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::<SeqCst>::with_policy(", stringify!($non_zero_type), "::new(1).unwrap());")]
+            /// assert_eq!(atomic.load().get(), 1);
+            /// ```
+            #[inline]
+            pub fn with_policy(value: $non_zero_type) -> Self {
+                Self {
+                    inner: $inner_type::with_policy(value.get()),
+                }
+            }
+
+            /// Gets the current value.
+            ///
+            /// # Returns
+            ///
+            /// The current value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($non_zero_type), "::MAX);")]
+            #[doc = concat!("assert_eq!(atomic.load(), ", stringify!($non_zero_type), "::MAX);")]
+            /// ```
+            #[inline]
+            pub fn load(&self) -> $non_zero_type {
+                to_non_zero(self.inner.load())
+            }
+
+            /// Sets a new value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($non_zero_type), "::MIN);")]
+            #[doc = concat!("atomic.store(", stringify!($non_zero_type), "::MAX);")]
+            #[doc = concat!("assert_eq!(atomic.load(), ", stringify!($non_zero_type), "::MAX);")]
+            /// ```
+            #[inline]
+            pub fn store(&self, value: $non_zero_type) {
+                self.inner.store(value.get());
+            }
+
+            /// Swaps the current value with a new value, returning the old
+            /// value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($non_zero_type), "::MIN);")]
+            #[doc = concat!("let old = atomic.swap(", stringify!($non_zero_type), "::MAX);")]
+            #[doc = concat!("assert_eq!(old, ", stringify!($non_zero_type), "::MIN);")]
+            /// ```
+            #[inline]
+            pub fn swap(&self, value: $non_zero_type) -> $non_zero_type {
+                to_non_zero(self.inner.swap(value.get()))
+            }
+
+            /// Compares and sets the value atomically.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($non_zero_type), "::new(1).unwrap();")]
+            #[doc = concat!("let max = ", stringify!($non_zero_type), "::MAX;")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(one);")]
+            /// assert!(atomic.compare_set(one, max).is_ok());
+            /// assert_eq!(atomic.compare_set(one, max), Err(max));
+            /// ```
+            #[inline]
+            pub fn compare_set(
+                &self,
+                current: $non_zero_type,
+                new: $non_zero_type,
+            ) -> Result<(), $non_zero_type> {
+                self.inner
+                    .compare_set(current.get(), new.get())
+                    .map_err(to_non_zero)
+            }
+
+            /// Weak version of compare-and-set.
+            ///
+            /// May spuriously fail even when the comparison succeeds. Should
+            /// be used in a loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($non_zero_type), "::MIN);")]
+            /// let mut current = atomic.load();
+            /// loop {
+            #[doc = concat!("    match atomic.compare_set_weak(current, ", stringify!($non_zero_type), "::MAX) {")]
+            ///         Ok(_) => break,
+            ///         Err(actual) => current = actual,
+            ///     }
+            /// }
+            #[doc = concat!("assert_eq!(atomic.load(), ", stringify!($non_zero_type), "::MAX);")]
+            /// ```
+            #[inline]
+            pub fn compare_set_weak(
+                &self,
+                current: $non_zero_type,
+                new: $non_zero_type,
+            ) -> Result<(), $non_zero_type> {
+                self.inner
+                    .compare_set_weak(current.get(), new.get())
+                    .map_err(to_non_zero)
+            }
+
+            /// Compares and exchanges the value atomically, returning the
+            /// previous value.
+            ///
+            /// If the current value equals `current`, sets it to `new` and
+            /// returns `current`. Otherwise, returns the actual current
+            /// value.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($non_zero_type), "::new(1).unwrap();")]
+            #[doc = concat!("let max = ", stringify!($non_zero_type), "::MAX;")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(one);")]
+            /// assert_eq!(atomic.compare_and_exchange(one, max), one);
+            /// assert_eq!(atomic.compare_and_exchange(one, one), max);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange(
+                &self,
+                current: $non_zero_type,
+                new: $non_zero_type,
+            ) -> $non_zero_type {
+                to_non_zero(
+                    self.inner.compare_and_exchange(current.get(), new.get()),
+                )
+            }
+
+            /// Weak version of compare-and-exchange.
+            ///
+            /// May spuriously fail even when the comparison succeeds. Should
+            /// be used in a loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let max = ", stringify!($non_zero_type), "::MAX;")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($non_zero_type), "::MIN);")]
+            /// let mut current = atomic.load();
+            /// loop {
+            ///     let prev = atomic.compare_and_exchange_weak(current, max);
+            ///     if prev == current {
+            ///         break;
+            ///     }
+            ///     current = prev;
+            /// }
+            /// assert_eq!(atomic.load(), max);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange_weak(
+                &self,
+                current: $non_zero_type,
+                new: $non_zero_type,
+            ) -> $non_zero_type {
+                to_non_zero(
+                    self.inner
+                        .compare_and_exchange_weak(current.get(), new.get()),
+                )
+            }
+
+            /// Increments the value by one, returning the old value, or fails
+            /// without modifying the value if the result would be zero or
+            /// overflow.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old)` on success, or `Err(current)` if the value is
+            #[doc = concat!("already `", stringify!($value_type), "::MAX`, or -1 for a signed type.")]
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let next_id = ", stringify!($name), "::new(", stringify!($non_zero_type), "::new(1).unwrap());")]
+            /// assert_eq!(next_id.fetch_inc().unwrap().get(), 1);
+            /// assert_eq!(next_id.load().get(), 2);
+            ///
+            #[doc = concat!("next_id.store(", stringify!($non_zero_type), "::MAX);")]
+            #[doc = concat!("assert_eq!(next_id.fetch_inc(), Err(", stringify!($non_zero_type), "::MAX));")]
+            /// ```
+            #[inline]
+            pub fn fetch_inc(&self) -> Result<$non_zero_type, $non_zero_type> {
+                self.fetch_add(1)
+            }
+
+            /// Decrements the value by one, returning the old value, or fails
+            /// without modifying the value if the result would be zero or
+            /// overflow.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old)` on success, or `Err(current)` if the value is one
+            #[doc = concat!("or `", stringify!($value_type), "::MIN`.")]
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let two = ", stringify!($non_zero_type), "::new(2).unwrap();")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(two);")]
+            /// assert_eq!(atomic.fetch_dec(), Ok(two));
+            #[doc = concat!("assert_eq!(atomic.fetch_dec(), Err(", stringify!($non_zero_type), "::new(1).unwrap()));")]
+            /// ```
+            #[inline]
+            pub fn fetch_dec(&self) -> Result<$non_zero_type, $non_zero_type> {
+                self.fetch_sub(1)
+            }
+
+            /// Adds a delta to the value, returning the old value, or fails
+            /// without modifying the value if the result would be zero or
+            /// overflow.
+            ///
+            /// Implemented via a CAS loop with the same orderings as
+            /// `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to add.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old)` on success, or `Err(current)` if the result would be
+            /// zero or overflow.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($non_zero_type), "::new(1).unwrap());")]
+            /// assert_eq!(atomic.fetch_add(9).unwrap().get(), 1);
+            /// assert_eq!(atomic.load().get(), 10);
+            #[doc = concat!("assert!(atomic.fetch_add(", stringify!($value_type), "::MAX).is_err());")]
+            /// assert_eq!(atomic.load().get(), 10);
+            /// ```
+            #[inline]
+            pub fn fetch_add(
+                &self,
+                delta: $value_type,
+            ) -> Result<$non_zero_type, $non_zero_type> {
+                self.inner
+                    .try_update(|current| {
+                        current.checked_add(delta).filter(|new| *new != 0)
+                    })
+                    .map(to_non_zero)
+                    .map_err(to_non_zero)
+            }
+
+            /// Subtracts a delta from the value, returning the old value, or
+            /// fails without modifying the value if the result would be zero
+            /// or overflow.
+            ///
+            /// Implemented via a CAS loop with the same orderings as
+            /// `fetch_update`.
+            ///
+            /// # Parameters
+            ///
+            /// * `delta` - The value to subtract.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old)` on success, or `Err(current)` if the result would be
+            /// zero or overflow.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let ten = ", stringify!($non_zero_type), "::new(10).unwrap();")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(ten);")]
+            /// assert_eq!(atomic.fetch_sub(3), Ok(ten));
+            /// assert_eq!(atomic.load().get(), 7);
+            ///
+            /// // Subtracting the whole value would leave zero
+            /// assert_eq!(atomic.fetch_sub(7), Err(atomic.load()));
+            /// assert_eq!(atomic.load().get(), 7);
+            /// ```
+            #[inline]
+            pub fn fetch_sub(
+                &self,
+                delta: $value_type,
+            ) -> Result<$non_zero_type, $non_zero_type> {
+                self.inner
+                    .try_update(|current| {
+                        current.checked_sub(delta).filter(|new| *new != 0)
+                    })
+                    .map(to_non_zero)
+                    .map_err(to_non_zero)
+            }
+
+            /// Updates the value to the maximum of the current and given
+            /// value, returning the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($non_zero_type), "::MIN);")]
+            #[doc = concat!("atomic.fetch_max(", stringify!($non_zero_type), "::MAX);")]
+            #[doc = concat!("assert_eq!(atomic.load(), ", stringify!($non_zero_type), "::MAX);")]
+            /// ```
+            #[inline]
+            pub fn fetch_max(&self, value: $non_zero_type) -> $non_zero_type {
+                to_non_zero(self.inner.fetch_max(value.get()))
+            }
+
+            /// Updates the value to the minimum of the current and given
+            /// value, returning the old value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The value to compare with.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(", stringify!($non_zero_type), "::MAX);")]
+            #[doc = concat!("atomic.fetch_min(", stringify!($non_zero_type), "::MIN);")]
+            #[doc = concat!("assert_eq!(atomic.load(), ", stringify!($non_zero_type), "::MIN);")]
+            /// ```
+            #[inline]
+            pub fn fetch_min(&self, value: $non_zero_type) -> $non_zero_type {
+                to_non_zero(self.inner.fetch_min(value.get()))
+            }
+
+            /// Updates the value using a function, returning the old value,
+            /// or fails without modifying the value if the function returns
+            /// zero.
+            ///
+            /// The function receives the current value and returns the new
+            /// value as a plain integer. Implemented via a CAS loop with the
+            /// `CAS_SUCCESS` and `CAS_FAILURE` orderings of the policy; the
+            /// function may be called several times.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns
+            ///   the new value.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(old)` on success, or `Err(current)` if `f` returned zero.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let eight = ", stringify!($non_zero_type), "::new(8).unwrap();")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(eight);")]
+            /// assert_eq!(atomic.fetch_update(|x| x.get() / 2), Ok(eight));
+            /// assert_eq!(atomic.load().get(), 4);
+            ///
+            /// // Shifting out all bits would leave zero
+            /// assert!(atomic.fetch_update(|x| x.get() >> 3).is_err());
+            /// assert_eq!(atomic.load().get(), 4);
+            /// ```
+            #[inline]
+            pub fn fetch_update<F>(
+                &self,
+                mut f: F,
+            ) -> Result<$non_zero_type, $non_zero_type>
+            where
+                F: FnMut($non_zero_type) -> $value_type,
+            {
+                self.inner
+                    .try_update(|current| {
+                        Some(f(to_non_zero(current))).filter(|new| *new != 0)
+                    })
+                    .map(to_non_zero)
+                    .map_err(to_non_zero)
+            }
+        }
+
+        impl From<$non_zero_type> for $name {
+            #[inline]
+            fn from(value: $non_zero_type) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<P: OrderingPolicy> crate::atomic::traits::Atomic
+            for $name<P>
+        {
+            type Value = $non_zero_type;
+
+            #[inline]
+            fn load(&self) -> $non_zero_type {
+                self.load()
+            }
+
+            #[inline]
+            fn store(&self, value: $non_zero_type) {
+                self.store(value);
+            }
+
+            #[inline]
+            fn swap(&self, value: $non_zero_type) -> $non_zero_type {
+                self.swap(value)
+            }
+
+            #[inline]
+            fn compare_set(
+                &self,
+                current: $non_zero_type,
+                new: $non_zero_type,
+            ) -> Result<(), $non_zero_type> {
+                self.compare_set(current, new)
+            }
+
+            #[inline]
+            fn compare_set_weak(
+                &self,
+                current: $non_zero_type,
+                new: $non_zero_type,
+            ) -> Result<(), $non_zero_type> {
+                self.compare_set_weak(current, new)
+            }
+
+            #[inline]
+            fn compare_exchange(
+                &self,
+                current: $non_zero_type,
+                new: $non_zero_type,
+            ) -> $non_zero_type {
+                self.compare_and_exchange(current, new)
+            }
+
+            #[inline]
+            fn compare_exchange_weak(
+                &self,
+                current: $non_zero_type,
+                new: $non_zero_type,
+            ) -> $non_zero_type {
+                self.compare_and_exchange_weak(current, new)
+            }
+
+            #[inline]
+            fn fetch_update<F>(&self, mut f: F) -> $non_zero_type
+            where
+                F: FnMut($non_zero_type) -> $non_zero_type,
+            {
+                to_non_zero(
+                    self.inner
+                        .fetch_update(|current| f(to_non_zero(current)).get()),
+                )
+            }
+
+            #[inline]
+            fn update_and_get<F>(&self, mut f: F) -> $non_zero_type
+            where
+                F: FnMut($non_zero_type) -> $non_zero_type,
+            {
+                to_non_zero(
+                    self.inner
+                        .update_and_get(|current| f(to_non_zero(current)).get()),
+                )
+            }
+
+            #[inline]
+            fn try_update<F>(
+                &self,
+                mut f: F,
+            ) -> Result<$non_zero_type, $non_zero_type>
+            where
+                F: FnMut($non_zero_type) -> Option<$non_zero_type>,
+            {
+                self.inner
+                    .try_update(|current| {
+                        f(to_non_zero(current)).map($non_zero_type::get)
+                    })
+                    .map(to_non_zero)
+                    .map_err(to_non_zero)
+            }
+
+            #[inline]
+            fn try_update_result<F, E>(
+                &self,
+                mut f: F,
+            ) -> Result<$non_zero_type, E>
+            where
+                F: FnMut($non_zero_type) -> Result<$non_zero_type, E>,
+            {
+                self.inner
+                    .try_update_result(|current| {
+                        f(to_non_zero(current)).map($non_zero_type::get)
+                    })
+                    .map(to_non_zero)
+            }
+        }
+
+        impl<P: OrderingPolicy> fmt::Debug for $name<P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("value", &self.load())
+                    .finish()
+            }
+        }
+
+        impl<P: OrderingPolicy> fmt::Display for $name<P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.load())
+            }
+        }
+    };
+}
+
+/// Macro to generate an atomic optional non-zero integer type.
+///
+/// The generated type wraps an atomic integer type generated by
+/// `impl_atomic_number!`, in which zero represents `None`.
+///
+/// # Parameters
+///
+/// * `$name` - The name of the atomic type (e.g., `AtomicOptionNonZeroU32`)
+/// * `$inner_type` - The atomic integer type (e.g., `AtomicU32`)
+/// * `$non_zero_type` - The non-zero value type (e.g., `NonZeroU32`)
+/// * `$value_type` - The underlying integer type (e.g., `u32`)
+/// * `$doc_type` - The type description for documentation (e.g., "32-bit
+///   unsigned integer")
+macro_rules! impl_atomic_option_non_zero {
+    (
+        $name:ident,
+        $inner_type:ident,
+        $non_zero_type:ident,
+        $value_type:ty,
+        $doc_type:expr
+    ) => {
+        #[doc = concat!("Atomic optional non-zero ", $doc_type, ".")]
+        ///
+        #[doc = concat!("Holds an `Option<", stringify!($non_zero_type), ">` in an [`", stringify!($inner_type), "`]")]
+        #[doc = concat!("(`crate::atomic::", stringify!($inner_type), "`), using the niche of the")]
+        /// non-zero type: zero represents `None`. Useful for optional IDs
+        /// and handles, e.g. the owner of a resource or the head of a free
+        /// list, without a separate flag.
+        ///
+        /// The orderings of the operations are those of the wrapped
+        #[doc = concat!("integer type under the policy `P`, see [`", stringify!($inner_type), "`].")]
+        /// The type also implements [`Atomic`](crate::atomic::Atomic) with
+        #[doc = concat!("`Option<", stringify!($non_zero_type), ">` values.")]
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+        #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+        ///
+        #[doc = concat!("let owner = ", stringify!($name), "::new(None);")]
+        #[doc = concat!("let id = ", stringify!($non_zero_type), "::new(7).unwrap();")]
+        ///
+        /// // Claim the resource if nobody owns it
+        /// assert!(owner.compare_set(None, Some(id)).is_ok());
+        /// assert_eq!(owner.compare_set(None, Some(id)), Err(Some(id)));
+        ///
+        /// // Release it
+        /// assert_eq!(owner.take(), Some(id));
+        /// assert_eq!(owner.load(), None);
+        /// ```
+        ///
+        /// # Author
+        ///
+        /// Haixing Hu
+        #[repr(transparent)]
+        pub struct $name<P = DefaultOrdering> {
+            inner: $inner_type<P>,
+        }
+
+        /// Converts an optional non-zero value to the underlying integer,
+        /// mapping `None` to zero.
+        #[inline]
+        fn from_option(value: Option<$non_zero_type>) -> $value_type {
+            value.map_or(0, $non_zero_type::get)
+        }
+
+        impl $name {
+            /// Creates a new atomic optional non-zero integer.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The initial value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(Some(", stringify!($non_zero_type), "::MIN));")]
+            #[doc = concat!("assert_eq!(atomic.load(), Some(", stringify!($non_zero_type), "::MIN));")]
+            /// ```
+            #[inline]
+            pub fn new(value: Option<$non_zero_type>) -> Self {
+                Self::with_policy(value)
+            }
+        }
+
+        impl<P: OrderingPolicy> $name<P> {
+            /// Creates a new atomic optional non-zero integer using the
+            /// ordering policy `P`.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The initial value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::{", stringify!($name), ", SeqCst};")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::<SeqCst>::with_policy(None);")]
+            /// assert_eq!(atomic.load(), None);
+            /// ```
+            #[inline]
+            pub fn with_policy(value: Option<$non_zero_type>) -> Self {
+                Self {
+                    inner: $inner_type::with_policy(from_option(value)),
+                }
+            }
+
+            /// Gets the current value.
+            ///
+            /// # Returns
+            ///
+            /// The current value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(None);")]
+            /// assert_eq!(atomic.load(), None);
+            /// ```
+            #[inline]
+            pub fn load(&self) -> Option<$non_zero_type> {
+                $non_zero_type::new(self.inner.load())
+            }
+
+            /// Sets a new value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(None);")]
+            #[doc = concat!("atomic.store(Some(", stringify!($non_zero_type), "::MAX));")]
+            #[doc = concat!("assert_eq!(atomic.load(), Some(", stringify!($non_zero_type), "::MAX));")]
+            /// ```
+            #[inline]
+            pub fn store(&self, value: Option<$non_zero_type>) {
+                self.inner.store(from_option(value));
+            }
+
+            /// Swaps the current value with a new value, returning the old
+            /// value.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(None);")]
+            #[doc = concat!("assert_eq!(atomic.swap(Some(", stringify!($non_zero_type), "::MIN)), None);")]
+            #[doc = concat!("assert_eq!(atomic.load(), Some(", stringify!($non_zero_type), "::MIN));")]
+            /// ```
+            #[inline]
+            pub fn swap(
+                &self,
+                value: Option<$non_zero_type>,
+            ) -> Option<$non_zero_type> {
+                $non_zero_type::new(self.inner.swap(from_option(value)))
+            }
+
+            /// Takes the value out, leaving `None` in its place.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(Some(", stringify!($non_zero_type), "::MIN));")]
+            #[doc = concat!("assert_eq!(atomic.take(), Some(", stringify!($non_zero_type), "::MIN));")]
+            /// assert_eq!(atomic.take(), None);
+            /// ```
+            #[inline]
+            pub fn take(&self) -> Option<$non_zero_type> {
+                self.swap(None)
+            }
+
+            /// Compares and sets the value atomically.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($non_zero_type), "::new(1);")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(None);")]
+            /// assert!(atomic.compare_set(None, one).is_ok());
+            /// assert_eq!(atomic.compare_set(None, None), Err(one));
+            /// ```
+            #[inline]
+            pub fn compare_set(
+                &self,
+                current: Option<$non_zero_type>,
+                new: Option<$non_zero_type>,
+            ) -> Result<(), Option<$non_zero_type>> {
+                self.inner
+                    .compare_set(from_option(current), from_option(new))
+                    .map_err($non_zero_type::new)
+            }
+
+            /// Weak version of compare-and-set.
+            ///
+            /// May spuriously fail even when the comparison succeeds. Should
+            /// be used in a loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(())` on success, or `Err(actual)` on failure.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($non_zero_type), "::new(1);")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(None);")]
+            /// let mut current = atomic.load();
+            /// loop {
+            ///     match atomic.compare_set_weak(current, one) {
+            ///         Ok(_) => break,
+            ///         Err(actual) => current = actual,
+            ///     }
+            /// }
+            /// assert_eq!(atomic.load(), one);
+            /// ```
+            #[inline]
+            pub fn compare_set_weak(
+                &self,
+                current: Option<$non_zero_type>,
+                new: Option<$non_zero_type>,
+            ) -> Result<(), Option<$non_zero_type>> {
+                self.inner
+                    .compare_set_weak(from_option(current), from_option(new))
+                    .map_err($non_zero_type::new)
+            }
+
+            /// Compares and exchanges the value atomically, returning the
+            /// previous value.
+            ///
+            /// If the current value equals `current`, sets it to `new` and
+            /// returns `current`. Otherwise, returns the actual current
+            /// value.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($non_zero_type), "::new(1);")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(None);")]
+            /// assert_eq!(atomic.compare_and_exchange(None, one), None);
+            /// assert_eq!(atomic.compare_and_exchange(None, None), one);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange(
+                &self,
+                current: Option<$non_zero_type>,
+                new: Option<$non_zero_type>,
+            ) -> Option<$non_zero_type> {
+                $non_zero_type::new(
+                    self.inner.compare_and_exchange(
+                        from_option(current),
+                        from_option(new),
+                    ),
+                )
+            }
+
+            /// Weak version of compare-and-exchange.
+            ///
+            /// May spuriously fail even when the comparison succeeds. Should
+            /// be used in a loop.
+            ///
+            /// # Parameters
+            ///
+            /// * `current` - The expected current value.
+            /// * `new` - The new value to set if current matches.
+            ///
+            /// # Returns
+            ///
+            /// The value before the operation.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let one = ", stringify!($non_zero_type), "::new(1);")]
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(None);")]
+            /// let mut current = atomic.load();
+            /// loop {
+            ///     let prev = atomic.compare_and_exchange_weak(current, one);
+            ///     if prev == current {
+            ///         break;
+            ///     }
+            ///     current = prev;
+            /// }
+            /// assert_eq!(atomic.load(), one);
+            /// ```
+            #[inline]
+            pub fn compare_and_exchange_weak(
+                &self,
+                current: Option<$non_zero_type>,
+                new: Option<$non_zero_type>,
+            ) -> Option<$non_zero_type> {
+                $non_zero_type::new(
+                    self.inner.compare_and_exchange_weak(
+                        from_option(current),
+                        from_option(new),
+                    ),
+                )
+            }
+
+            /// Updates the value using a function, returning the old value.
+            ///
+            /// Implemented via a CAS loop with the `CAS_SUCCESS` and
+            /// `CAS_FAILURE` orderings of the policy; the function may be
+            /// called several times.
+            ///
+            /// # Parameters
+            ///
+            /// * `f` - A function that takes the current value and returns
+            ///   the new value.
+            ///
+            /// # Returns
+            ///
+            /// The old value.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+            #[doc = concat!("use std::num::", stringify!($non_zero_type), ";")]
+            ///
+            #[doc = concat!("let atomic = ", stringify!($name), "::new(None);")]
+            #[doc = concat!("let init = || Some(", stringify!($non_zero_type), "::MIN);")]
+            /// assert_eq!(atomic.fetch_update(|x| x.or_else(init)), None);
+            /// assert_eq!(atomic.load(), init());
+            /// ```
+            #[inline]
+            pub fn fetch_update<F>(&self, mut f: F) -> Option<$non_zero_type>
+            where
+                F: FnMut(Option<$non_zero_type>) -> Option<$non_zero_type>,
+            {
+                $non_zero_type::new(self.inner.fetch_update(|current| {
+                    from_option(f($non_zero_type::new(current)))
+                }))
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::new(None)
+            }
+        }
+
+        impl From<Option<$non_zero_type>> for $name {
+            #[inline]
+            fn from(value: Option<$non_zero_type>) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<P: OrderingPolicy> crate::atomic::traits::Atomic
+            for $name<P>
+        {
+            type Value = Option<$non_zero_type>;
+
+            #[inline]
+            fn load(&self) -> Option<$non_zero_type> {
+                self.load()
+            }
+
+            #[inline]
+            fn store(&self, value: Option<$non_zero_type>) {
+                self.store(value);
+            }
+
+            #[inline]
+            fn swap(
+                &self,
+                value: Option<$non_zero_type>,
+            ) -> Option<$non_zero_type> {
+                self.swap(value)
+            }
+
+            #[inline]
+            fn compare_set(
+                &self,
+                current: Option<$non_zero_type>,
+                new: Option<$non_zero_type>,
+            ) -> Result<(), Option<$non_zero_type>> {
+                self.compare_set(current, new)
+            }
+
+            #[inline]
+            fn compare_set_weak(
+                &self,
+                current: Option<$non_zero_type>,
+                new: Option<$non_zero_type>,
+            ) -> Result<(), Option<$non_zero_type>> {
+                self.compare_set_weak(current, new)
+            }
+
+            #[inline]
+            fn compare_exchange(
+                &self,
+                current: Option<$non_zero_type>,
+                new: Option<$non_zero_type>,
+            ) -> Option<$non_zero_type> {
+                self.compare_and_exchange(current, new)
+            }
+
+            #[inline]
+            fn compare_exchange_weak(
+                &self,
+                current: Option<$non_zero_type>,
+                new: Option<$non_zero_type>,
+            ) -> Option<$non_zero_type> {
+                self.compare_and_exchange_weak(current, new)
+            }
+
+            #[inline]
+            fn fetch_update<F>(&self, f: F) -> Option<$non_zero_type>
+            where
+                F: FnMut(Option<$non_zero_type>) -> Option<$non_zero_type>,
+            {
+                self.fetch_update(f)
+            }
+
+            #[inline]
+            fn update_and_get<F>(&self, mut f: F) -> Option<$non_zero_type>
+            where
+                F: FnMut(Option<$non_zero_type>) -> Option<$non_zero_type>,
+            {
+                $non_zero_type::new(self.inner.update_and_get(|current| {
+                    from_option(f($non_zero_type::new(current)))
+                }))
+            }
+
+            #[inline]
+            fn try_update<F>(
+                &self,
+                mut f: F,
+            ) -> Result<Option<$non_zero_type>, Option<$non_zero_type>>
+            where
+                F: FnMut(
+                    Option<$non_zero_type>,
+                ) -> Option<Option<$non_zero_type>>,
+            {
+                self.inner
+                    .try_update(|current| {
+                        f($non_zero_type::new(current)).map(from_option)
+                    })
+                    .map($non_zero_type::new)
+                    .map_err($non_zero_type::new)
+            }
+
+            #[inline]
+            fn try_update_result<F, E>(
+                &self,
+                mut f: F,
+            ) -> Result<Option<$non_zero_type>, E>
+            where
+                F: FnMut(
+                    Option<$non_zero_type>,
+                ) -> Result<Option<$non_zero_type>, E>,
+            {
+                self.inner
+                    .try_update_result(|current| {
+                        f($non_zero_type::new(current)).map(from_option)
+                    })
+                    .map($non_zero_type::new)
+            }
+        }
+
+        impl<P: OrderingPolicy> fmt::Debug for $name<P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("value", &self.load())
+                    .finish()
+            }
+        }
+    };
+}
@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero 128-bit Unsigned Integer
+//!
+//! Provides atomic wrappers of `NonZeroU128` and `Option<NonZeroU128>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroU128;
+
+use crate::atomic::atomic_u128::AtomicU128;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroU128,
+    AtomicU128,
+    NonZeroU128,
+    u128,
+    "128-bit unsigned integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroU128,
+    AtomicU128,
+    NonZeroU128,
+    u128,
+    "128-bit unsigned integer"
+);
@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero 16-bit Unsigned Integer
+//!
+//! Provides atomic wrappers of `NonZeroU16` and `Option<NonZeroU16>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroU16;
+
+use crate::atomic::atomic_u16::AtomicU16;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroU16,
+    AtomicU16,
+    NonZeroU16,
+    u16,
+    "16-bit unsigned integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroU16,
+    AtomicU16,
+    NonZeroU16,
+    u16,
+    "16-bit unsigned integer"
+);
@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero 32-bit Unsigned Integer
+//!
+//! Provides atomic wrappers of `NonZeroU32` and `Option<NonZeroU32>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroU32;
+
+use crate::atomic::atomic_u32::AtomicU32;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroU32,
+    AtomicU32,
+    NonZeroU32,
+    u32,
+    "32-bit unsigned integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroU32,
+    AtomicU32,
+    NonZeroU32,
+    u32,
+    "32-bit unsigned integer"
+);
@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero 64-bit Unsigned Integer
+//!
+//! Provides atomic wrappers of `NonZeroU64` and `Option<NonZeroU64>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroU64;
+
+use crate::atomic::atomic_u64::AtomicU64;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroU64,
+    AtomicU64,
+    NonZeroU64,
+    u64,
+    "64-bit unsigned integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroU64,
+    AtomicU64,
+    NonZeroU64,
+    u64,
+    "64-bit unsigned integer"
+);
@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero 8-bit Unsigned Integer
+//!
+//! Provides atomic wrappers of `NonZeroU8` and `Option<NonZeroU8>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroU8;
+
+use crate::atomic::atomic_u8::AtomicU8;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroU8,
+    AtomicU8,
+    NonZeroU8,
+    u8,
+    "8-bit unsigned integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroU8,
+    AtomicU8,
+    NonZeroU8,
+    u8,
+    "8-bit unsigned integer"
+);
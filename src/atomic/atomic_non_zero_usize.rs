@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Non-Zero Pointer-Sized Unsigned Integer
+//!
+//! Provides atomic wrappers of `NonZeroUsize` and `Option<NonZeroUsize>` that
+//! keep the non-zero invariant of the value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::num::NonZeroUsize;
+
+use crate::atomic::atomic_usize::AtomicUsize;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+
+impl_atomic_non_zero!(
+    AtomicNonZeroUsize,
+    AtomicUsize,
+    NonZeroUsize,
+    usize,
+    "pointer-sized unsigned integer"
+);
+
+impl_atomic_option_non_zero!(
+    AtomicOptionNonZeroUsize,
+    AtomicUsize,
+    NonZeroUsize,
+    usize,
+    "pointer-sized unsigned integer"
+);
@@ -0,0 +1,923 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Optional Reference
+//!
+//! Provides a nullable atomic reference type storing an `Option<Arc<T>>`.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::convert::Infallible;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use crate::atomic::backoff::Backoff;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::PublishingPolicy;
+use crate::atomic::ref_slot::RefSlot;
+use crate::atomic::traits::Atomic;
+
+/// Nullable atomic reference type.
+///
+/// The nullable counterpart of [`AtomicRef`](crate::atomic::AtomicRef): it
+/// holds an `Option<Arc<T>>`, `None` being stored as a null pointer. Typical
+/// uses are lazily built values, with
+/// [`get_or_init`](Self::get_or_init), and one-shot handoff slots, with
+/// [`take`](Self::take).
+///
+/// # Memory Ordering Strategy
+///
+/// The orderings are the same as those of `AtomicRef`: `Acquire` for
+/// loads, `Release` for stores, `AcqRel` for read-modify-write operations
+/// and `Acquire` on CAS failure with the default policy. Only policies
+/// implementing [`PublishingPolicy`] are accepted.
+///
+/// # Implementation Details
+///
+/// Like `AtomicRef`, every instance owns an epoch-based reclamation domain,
+/// so that a concurrent `store` cannot release the stored reference between
+/// reading the pointer and incrementing its reference count. Unlinking
+/// `None` releases nothing and does not wait for readers.
+///
+/// CAS operations compare by pointer identity (`Arc::ptr_eq`); `None` only
+/// matches `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+/// use std::sync::Arc;
+///
+/// let cache: AtomicOptionRef<Vec<u32>> = AtomicOptionRef::default();
+/// assert!(cache.load().is_none());
+///
+/// let value = cache.get_or_init(|| Arc::new(vec![1, 2, 3]));
+/// assert_eq!(*value, vec![1, 2, 3]);
+/// // The closure is not called again once the value is built
+/// let again = cache.get_or_init(|| unreachable!());
+/// assert!(Arc::ptr_eq(&value, &again));
+///
+/// assert!(cache.take().is_some());
+/// assert!(cache.take().is_none());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct AtomicOptionRef<T, P = DefaultOrdering> {
+    slot: RefSlot<Option<Arc<T>>>,
+    _policy: PhantomData<fn() -> P>,
+}
+
+impl<T> AtomicOptionRef<T> {
+    /// Creates a new nullable atomic reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial reference, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(Some(Arc::new(42)));
+    /// assert_eq!(atomic.load().as_deref(), Some(&42));
+    /// ```
+    #[inline]
+    pub fn new(value: Option<Arc<T>>) -> Self {
+        Self::with_policy(value)
+    }
+}
+
+impl<T, P: PublishingPolicy> AtomicOptionRef<T, P> {
+    /// Creates a new nullable atomic reference using the ordering policy
+    /// `P`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial reference, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicOptionRef, SeqCst};
+    ///
+    /// let atomic = AtomicOptionRef::<i32, SeqCst>::with_policy(None);
+    /// assert!(atomic.load().is_none());
+    /// ```
+    #[inline]
+    pub fn with_policy(value: Option<Arc<T>>) -> Self {
+        Self {
+            slot: RefSlot::new(value),
+            _policy: PhantomData,
+        }
+    }
+
+    /// Gets the current reference.
+    ///
+    /// # Returns
+    ///
+    /// A cloned `Arc` pointing to the current value, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(Some(Arc::new(42)));
+    /// assert_eq!(*atomic.load().unwrap(), 42);
+    /// ```
+    #[inline]
+    pub fn load(&self) -> Option<Arc<T>> {
+        self.load_with_ordering(P::LOAD)
+    }
+
+    /// Gets the current reference with an explicit memory ordering.
+    ///
+    /// # Parameters
+    ///
+    /// * `ordering` - The memory ordering to use. Must be `Acquire` or
+    ///   `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// A cloned `Arc` pointing to the current value, or `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `Acquire` nor `SeqCst`, also in
+    /// release builds, like [`AtomicRef`](crate::atomic::AtomicRef).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(Some(Arc::new(42)));
+    /// let value = atomic.load_with_ordering(Ordering::SeqCst);
+    /// assert_eq!(value.as_deref(), Some(&42));
+    /// ```
+    #[inline]
+    pub fn load_with_ordering(&self, ordering: Ordering) -> Option<Arc<T>> {
+        self.slot.load(ordering)
+    }
+
+    /// Sets a new reference, or clears it.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new reference, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(None);
+    /// atomic.store(Some(Arc::new(100)));
+    /// assert_eq!(atomic.load().as_deref(), Some(&100));
+    /// atomic.store(None);
+    /// assert!(atomic.load().is_none());
+    /// ```
+    #[inline]
+    pub fn store(&self, value: Option<Arc<T>>) {
+        self.store_with_ordering(value, P::STORE);
+    }
+
+    /// Sets a new reference, or clears it, with an explicit memory
+    /// ordering.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new reference, or `None`.
+    /// * `ordering` - The memory ordering to use. Must be `Release` or
+    ///   `SeqCst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `Release` nor `SeqCst`, also in
+    /// release builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(None);
+    /// atomic.store_with_ordering(Some(Arc::new(100)), Ordering::SeqCst);
+    /// assert_eq!(atomic.load().as_deref(), Some(&100));
+    /// ```
+    #[inline]
+    pub fn store_with_ordering(&self, value: Option<Arc<T>>, ordering: Ordering) {
+        self.slot.store(value, ordering);
+    }
+
+    /// Swaps the current reference with a new one, returning the old
+    /// reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new reference, or `None`.
+    ///
+    /// # Returns
+    ///
+    /// The old reference, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(Some(Arc::new(10)));
+    /// let old = atomic.swap(Some(Arc::new(20)));
+    /// assert_eq!(old.as_deref(), Some(&10));
+    /// assert_eq!(atomic.load().as_deref(), Some(&20));
+    /// ```
+    #[inline]
+    pub fn swap(&self, value: Option<Arc<T>>) -> Option<Arc<T>> {
+        self.swap_with_ordering(value, P::RMW)
+    }
+
+    /// Swaps the current reference with a new one using an explicit memory
+    /// ordering, returning the old reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new reference, or `None`.
+    /// * `ordering` - The memory ordering to use. Must be `AcqRel` or
+    ///   `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// The old reference, or `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `AcqRel` nor `SeqCst`, also in
+    /// release builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(Some(Arc::new(10)));
+    /// let old = atomic.swap_with_ordering(None, Ordering::SeqCst);
+    /// assert_eq!(old.as_deref(), Some(&10));
+    /// assert!(atomic.load().is_none());
+    /// ```
+    #[inline]
+    pub fn swap_with_ordering(&self, value: Option<Arc<T>>, ordering: Ordering) -> Option<Arc<T>> {
+        self.slot.swap(value, ordering)
+    }
+
+    /// Takes the current reference, leaving `None` in its place.
+    ///
+    /// # Returns
+    ///
+    /// The old reference, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let slot = AtomicOptionRef::new(Some(Arc::new("message")));
+    /// assert_eq!(slot.take().as_deref(), Some(&"message"));
+    /// assert!(slot.take().is_none());
+    /// ```
+    #[inline]
+    pub fn take(&self) -> Option<Arc<T>> {
+        self.swap(None)
+    }
+
+    /// Compares and sets the reference atomically.
+    ///
+    /// If the current reference is `current`, sets it to `new` and returns
+    /// `Ok(())`. Otherwise, returns `Err(actual)` where `actual` is the
+    /// current reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current reference, or `None`.
+    /// * `new` - The new reference to set if current matches, or `None`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Note
+    ///
+    /// Comparison uses pointer equality (`Arc::ptr_eq`), not value
+    /// equality.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(None);
+    /// assert!(atomic.compare_set(None, Some(Arc::new(1))).is_ok());
+    ///
+    /// let actual = atomic.compare_set(None, Some(Arc::new(2))).unwrap_err();
+    /// assert_eq!(actual.as_deref(), Some(&1));
+    ///
+    /// assert!(atomic.compare_set(actual.as_ref(), None).is_ok());
+    /// assert!(atomic.load().is_none());
+    /// ```
+    #[inline]
+    pub fn compare_set(
+        &self,
+        current: Option<&Arc<T>>,
+        new: Option<Arc<T>>,
+    ) -> Result<(), Option<Arc<T>>> {
+        self.compare_set_with_ordering(current, new, P::CAS_SUCCESS, P::CAS_FAILURE)
+    }
+
+    /// Compares and sets the reference atomically with explicit memory
+    /// orderings.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current reference, or `None`.
+    /// * `new` - The new reference to set if current matches, or `None`.
+    /// * `success` - The memory ordering on success. Must be `AcqRel` or
+    ///   `SeqCst`.
+    /// * `failure` - The memory ordering on failure. Must be `Acquire` or
+    ///   `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `success` or `failure` is weaker than required, also in
+    /// release builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(None);
+    /// assert!(atomic
+    ///     .compare_set_with_ordering(
+    ///         None,
+    ///         Some(Arc::new(1)),
+    ///         Ordering::SeqCst,
+    ///         Ordering::SeqCst,
+    ///     )
+    ///     .is_ok());
+    /// ```
+    #[inline]
+    pub fn compare_set_with_ordering(
+        &self,
+        current: Option<&Arc<T>>,
+        new: Option<Arc<T>>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<(), Option<Arc<T>>> {
+        self.compare_and_exchange_impl(current, new, false, success, failure)
+            .map(drop)
+    }
+
+    /// Weak version of compare-and-set.
+    ///
+    /// May spuriously fail even when the comparison succeeds. Should be
+    /// used in a loop.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current reference, or `None`.
+    /// * `new` - The new reference to set if current matches, or `None`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(None);
+    /// let mut current = atomic.load();
+    /// loop {
+    ///     match atomic.compare_set_weak(current.as_ref(), Some(Arc::new(1))) {
+    ///         Ok(_) => break,
+    ///         Err(actual) => current = actual,
+    ///     }
+    /// }
+    /// assert_eq!(atomic.load().as_deref(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn compare_set_weak(
+        &self,
+        current: Option<&Arc<T>>,
+        new: Option<Arc<T>>,
+    ) -> Result<(), Option<Arc<T>>> {
+        self.compare_and_exchange_impl(current, new, true, P::CAS_SUCCESS, P::CAS_FAILURE)
+            .map(drop)
+    }
+
+    /// Compares and exchanges the reference atomically, returning the
+    /// previous reference.
+    ///
+    /// If the current reference is `current`, sets it to `new`. The
+    /// returned reference is the one witnessed by the operation; it is the
+    /// same pointer as `current` if and only if the exchange succeeded.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current reference, or `None`.
+    /// * `new` - The new reference to set if current matches, or `None`.
+    ///
+    /// # Returns
+    ///
+    /// The reference before the operation, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(None);
+    /// let prev = atomic.compare_and_exchange(None, Some(Arc::new(1)));
+    /// assert!(prev.is_none());
+    /// let prev = atomic.compare_and_exchange(None, Some(Arc::new(2)));
+    /// assert_eq!(prev.as_deref(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn compare_and_exchange(
+        &self,
+        current: Option<&Arc<T>>,
+        new: Option<Arc<T>>,
+    ) -> Option<Arc<T>> {
+        match self.compare_and_exchange_impl(current, new, false, P::CAS_SUCCESS, P::CAS_FAILURE) {
+            Ok(prev) => prev,
+            Err(actual) => actual,
+        }
+    }
+
+    /// Weak version of compare-and-exchange.
+    ///
+    /// May spuriously fail even when the comparison succeeds. Should be
+    /// used in a loop.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current reference, or `None`.
+    /// * `new` - The new reference to set if current matches, or `None`.
+    ///
+    /// # Returns
+    ///
+    /// The reference witnessed by the operation, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(None);
+    /// let mut current = atomic.load();
+    /// loop {
+    ///     let new = Some(Arc::new(1));
+    ///     let prev = atomic.compare_and_exchange_weak(current.as_ref(), new);
+    ///     let same = match (&prev, &current) {
+    ///         (Some(p), Some(c)) => Arc::ptr_eq(p, c),
+    ///         (p, c) => p.is_none() && c.is_none(),
+    ///     };
+    ///     if same {
+    ///         break;
+    ///     }
+    ///     current = prev;
+    /// }
+    /// assert_eq!(atomic.load().as_deref(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn compare_and_exchange_weak(
+        &self,
+        current: Option<&Arc<T>>,
+        new: Option<Arc<T>>,
+    ) -> Option<Arc<T>> {
+        match self.compare_and_exchange_impl(current, new, true, P::CAS_SUCCESS, P::CAS_FAILURE) {
+            Ok(prev) => prev,
+            Err(actual) => actual,
+        }
+    }
+
+    /// Gets the current reference, initializing it first if it is `None`.
+    ///
+    /// If several threads race to initialize the reference, `f` may be
+    /// called by each of them, but only one result is stored, and all
+    /// threads return that result.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - Function building the initial value.
+    ///
+    /// # Returns
+    ///
+    /// The current reference, which is never `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let cache = AtomicOptionRef::new(None);
+    /// assert_eq!(*cache.get_or_init(|| Arc::new(42)), 42);
+    /// assert_eq!(*cache.get_or_init(|| Arc::new(0)), 42);
+    /// ```
+    #[inline]
+    pub fn get_or_init<F>(&self, f: F) -> Arc<T>
+    where
+        F: FnOnce() -> Arc<T>,
+    {
+        if let Some(current) = self.load() {
+            return current;
+        }
+        let new = f();
+        loop {
+            match self.compare_set(None, Some(Arc::clone(&new))) {
+                Ok(_) => return new,
+                Err(Some(actual)) => return actual,
+                // Cleared again in between; try to store our value again.
+                Err(None) => continue,
+            }
+        }
+    }
+
+    /// Updates the reference using a function, returning the old
+    /// reference.
+    ///
+    /// Internally uses a CAS loop until the update succeeds.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current reference and returns the
+    ///   new reference. It may be called several times.
+    ///
+    /// # Returns
+    ///
+    /// The old reference before the update, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(None);
+    /// let increment = |x: &Option<Arc<i32>>| {
+    ///     Some(Arc::new(x.as_deref().map_or(1, |v| v + 1)))
+    /// };
+    /// assert!(atomic.fetch_update(increment).is_none());
+    /// assert_eq!(atomic.fetch_update(increment).as_deref(), Some(&1));
+    /// assert_eq!(atomic.load().as_deref(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn fetch_update<F>(&self, f: F) -> Option<Arc<T>>
+    where
+        F: FnMut(&Option<Arc<T>>) -> Option<Arc<T>>,
+    {
+        self.fetch_update_with_backoff(P::BACKOFF, f)
+    }
+
+    /// Updates the reference using a function and a caller-supplied
+    /// backoff strategy, returning the old reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `backoff` - The backoff strategy applied after each failed CAS.
+    /// * `f` - A function that takes the current reference and returns the
+    ///   new reference.
+    ///
+    /// # Returns
+    ///
+    /// The old reference before the update, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicOptionRef, SpinBackoff};
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(Some(Arc::new(1)));
+    /// let old = atomic.fetch_update_with_backoff(SpinBackoff, |_| None);
+    /// assert_eq!(old.as_deref(), Some(&1));
+    /// assert!(atomic.load().is_none());
+    /// ```
+    #[inline]
+    pub fn fetch_update_with_backoff<B, F>(&self, backoff: B, mut f: F) -> Option<Arc<T>>
+    where
+        B: Backoff,
+        F: FnMut(&Option<Arc<T>>) -> Option<Arc<T>>,
+    {
+        self.try_update_result_with_backoff(backoff, |current| Ok::<_, Infallible>(f(current)))
+            .unwrap_or_else(|never| match never {})
+    }
+
+    /// Updates the reference using a function, returning the new
+    /// reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current reference and returns the
+    ///   new reference.
+    ///
+    /// # Returns
+    ///
+    /// The new reference after the update, or `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicOptionRef::new(Some(Arc::new(1)));
+    /// let new =
+    ///     atomic.update_and_get(|x| x.as_deref().map(|v| Arc::new(v * 10)));
+    /// assert_eq!(new.as_deref(), Some(&10));
+    /// ```
+    #[inline]
+    pub fn update_and_get<F>(&self, mut f: F) -> Option<Arc<T>>
+    where
+        F: FnMut(&Option<Arc<T>>) -> Option<Arc<T>>,
+    {
+        let mut stored = None;
+        self.fetch_update(|current| {
+            let new = f(current);
+            stored = Some(new.clone());
+            new
+        });
+        stored.expect("fetch_update calls the update function at least once")
+    }
+
+    /// Conditionally updates the reference using a function, returning the
+    /// old reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current reference and returns
+    ///   `Some(new)` to store `new`, or `None` to abort the update.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(old)` with the reference before the update, or `Err(current)`
+    /// with the reference for which `f` returned `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let slot = AtomicOptionRef::new(None);
+    /// // Only fill the slot if it is empty
+    /// let fill =
+    ///     |x: &Option<Arc<i32>>| x.is_none().then(|| Some(Arc::new(7)));
+    /// assert!(slot.try_update(fill).is_ok());
+    /// assert!(slot.try_update(fill).is_err());
+    /// ```
+    #[inline]
+    pub fn try_update<F>(&self, mut f: F) -> Result<Option<Arc<T>>, Option<Arc<T>>>
+    where
+        F: FnMut(&Option<Arc<T>>) -> Option<Option<Arc<T>>>,
+    {
+        self.try_update_result(|current| f(current).ok_or_else(|| current.clone()))
+    }
+
+    /// Conditionally updates the reference using a fallible function,
+    /// returning the old reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current reference and returns
+    ///   `Ok(new)` to store `new`, or `Err(e)` to abort the update.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(old)` with the reference before the update, or the error
+    /// returned by `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicOptionRef;
+    /// use std::sync::Arc;
+    ///
+    /// let slot: AtomicOptionRef<i32> = AtomicOptionRef::new(None);
+    /// let result = slot.try_update_result(|x| match x {
+    ///     Some(_) => Err("occupied"),
+    ///     None => Ok(Some(Arc::new(1))),
+    /// });
+    /// assert!(result.unwrap().is_none());
+    /// ```
+    #[inline]
+    pub fn try_update_result<F, E>(&self, f: F) -> Result<Option<Arc<T>>, E>
+    where
+        F: FnMut(&Option<Arc<T>>) -> Result<Option<Arc<T>>, E>,
+    {
+        self.try_update_result_with_backoff(P::BACKOFF, f)
+    }
+
+    /// Shared CAS loop of the update methods.
+    ///
+    /// Calls `f` with the current reference until the reference it returns
+    /// is stored, applying `backoff` after each failed CAS. Returns the old
+    /// reference, or the error returned by `f`, which stops the loop.
+    #[inline]
+    fn try_update_result_with_backoff<B, F, E>(
+        &self,
+        mut backoff: B,
+        mut f: F,
+    ) -> Result<Option<Arc<T>>, E>
+    where
+        B: Backoff,
+        F: FnMut(&Option<Arc<T>>) -> Result<Option<Arc<T>>, E>,
+    {
+        let mut current = self.load_with_ordering(P::CAS_FAILURE);
+        loop {
+            let new = f(&current)?;
+            match self.compare_set_weak(current.as_ref(), new) {
+                Ok(_) => return Ok(current),
+                Err(actual) => {
+                    current = actual;
+                    backoff.backoff();
+                }
+            }
+        }
+    }
+
+    /// Shared implementation of the compare-and-exchange operations.
+    ///
+    /// Returns `Ok(previous)` on success, or `Err(actual)` with a clone of
+    /// the witnessed reference on failure.
+    #[inline]
+    fn compare_and_exchange_impl(
+        &self,
+        current: Option<&Arc<T>>,
+        new: Option<Arc<T>>,
+        weak: bool,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Option<Arc<T>>, Option<Arc<T>>> {
+        let current_ptr = current.map_or(ptr::null_mut(), |c| Arc::as_ptr(c) as *mut T);
+        self.slot
+            .compare_exchange(current_ptr, new, weak, success, failure)
+    }
+}
+
+impl<T, P: PublishingPolicy> Atomic for AtomicOptionRef<T, P> {
+    type Value = Option<Arc<T>>;
+
+    #[inline]
+    fn load(&self) -> Option<Arc<T>> {
+        self.load()
+    }
+
+    #[inline]
+    fn store(&self, value: Option<Arc<T>>) {
+        self.store(value);
+    }
+
+    #[inline]
+    fn swap(&self, value: Option<Arc<T>>) -> Option<Arc<T>> {
+        self.swap(value)
+    }
+
+    #[inline]
+    fn compare_set(
+        &self,
+        current: Option<Arc<T>>,
+        new: Option<Arc<T>>,
+    ) -> Result<(), Option<Arc<T>>> {
+        self.compare_set(current.as_ref(), new)
+    }
+
+    #[inline]
+    fn compare_set_weak(
+        &self,
+        current: Option<Arc<T>>,
+        new: Option<Arc<T>>,
+    ) -> Result<(), Option<Arc<T>>> {
+        self.compare_set_weak(current.as_ref(), new)
+    }
+
+    #[inline]
+    fn compare_exchange(&self, current: Option<Arc<T>>, new: Option<Arc<T>>) -> Option<Arc<T>> {
+        self.compare_and_exchange(current.as_ref(), new)
+    }
+
+    #[inline]
+    fn compare_exchange_weak(
+        &self,
+        current: Option<Arc<T>>,
+        new: Option<Arc<T>>,
+    ) -> Option<Arc<T>> {
+        self.compare_and_exchange_weak(current.as_ref(), new)
+    }
+
+    #[inline]
+    fn fetch_update<F>(&self, mut f: F) -> Option<Arc<T>>
+    where
+        F: FnMut(Option<Arc<T>>) -> Option<Arc<T>>,
+    {
+        self.fetch_update(|x| f(x.clone()))
+    }
+
+    #[inline]
+    fn update_and_get<F>(&self, mut f: F) -> Option<Arc<T>>
+    where
+        F: FnMut(Option<Arc<T>>) -> Option<Arc<T>>,
+    {
+        self.update_and_get(|x| f(x.clone()))
+    }
+
+    #[inline]
+    fn try_update<F>(&self, mut f: F) -> Result<Option<Arc<T>>, Option<Arc<T>>>
+    where
+        F: FnMut(Option<Arc<T>>) -> Option<Option<Arc<T>>>,
+    {
+        self.try_update(|x| f(x.clone()))
+    }
+
+    #[inline]
+    fn try_update_result<F, E>(&self, mut f: F) -> Result<Option<Arc<T>>, E>
+    where
+        F: FnMut(Option<Arc<T>>) -> Result<Option<Arc<T>>, E>,
+    {
+        self.try_update_result(|x| f(x.clone()))
+    }
+}
+
+impl<T> Default for AtomicOptionRef<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<T> From<Option<Arc<T>>> for AtomicOptionRef<T> {
+    #[inline]
+    fn from(value: Option<Arc<T>>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<Arc<T>> for AtomicOptionRef<T> {
+    #[inline]
+    fn from(value: Arc<T>) -> Self {
+        Self::new(Some(value))
+    }
+}
+
+impl<T, P: PublishingPolicy> Clone for AtomicOptionRef<T, P> {
+    fn clone(&self) -> Self {
+        Self::with_policy(self.load())
+    }
+}
+
+unsafe impl<T: Send + Sync, P> Send for AtomicOptionRef<T, P> {}
+unsafe impl<T: Send + Sync, P> Sync for AtomicOptionRef<T, P> {}
+
+impl<T: fmt::Debug, P: PublishingPolicy> fmt::Debug for AtomicOptionRef<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicOptionRef")
+            .field("value", &self.load())
+            .finish()
+    }
+}
@@ -16,11 +16,19 @@
 //!
 //! Haixing Hu
 
+use std::convert::Infallible;
 use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::sync::atomic::AtomicPtr;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use crate::atomic::backoff::Backoff;
+use crate::atomic::ordering::assert_acq_rel;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::PublishingPolicy;
+use crate::atomic::ref_slot::RefSlot;
 use crate::atomic::traits::Atomic;
 
 /// Atomic reference type.
@@ -46,18 +54,42 @@ use crate::atomic::traits::Atomic;
 /// - **CAS failure**: Use `Acquire` ordering on failure to observe the
 ///   actual value written by another thread.
 ///
+/// These are the orderings of the default policy
+/// [`DefaultOrdering`](crate::atomic::DefaultOrdering). Another policy can be
+/// selected with the type parameter `P`, e.g. `AtomicRef<T, SeqCst>`; such
+/// instances are created with [`with_policy`](Self::with_policy). Only
+/// policies implementing [`PublishingPolicy`] are accepted, because weaker
+/// orderings would let a reader dereference a value before it is published.
+///
 /// # Implementation Details
 ///
 /// This type stores an `Arc<T>` as a raw pointer in `AtomicPtr<T>`. All
 /// operations properly manage reference counts to prevent memory leaks or
 /// use-after-free errors.
 ///
+/// Reading the pointer and incrementing its reference count are two separate
+/// steps, so a concurrent `store` or `swap` could otherwise release the last
+/// reference in between. To close this race, every instance owns a small
+/// epoch-based reclamation domain:
+///
+/// - `load` (and the failure path of the CAS operations) registers itself
+///   as a reader for the duration of those two steps.
+/// - Every operation that unlinks the stored reference (`store`, `swap`,
+///   successful CAS) waits for the readers that might still see the old
+///   pointer before releasing or returning it.
+///
+/// Readers never block. Writers wait only for the readers that were
+/// already in flight when the pointer was replaced, so the wait is short
+/// and bounded even under continuous reads.
+///
 /// # Features
 ///
 /// - Automatic memory ordering selection
+/// - Type-level ordering policies
 /// - Thread-safe reference counting via `Arc`
 /// - Functional update operations
 /// - Zero-cost abstraction with inline methods
+/// - Explicit memory orderings via the `*_with_ordering` methods
 ///
 /// # Example
 ///
@@ -92,8 +124,9 @@ use crate::atomic::traits::Atomic;
 /// # Author
 ///
 /// Haixing Hu
-pub struct AtomicRef<T> {
-    inner: AtomicPtr<T>,
+pub struct AtomicRef<T, P = DefaultOrdering> {
+    slot: RefSlot<Arc<T>>,
+    _policy: PhantomData<fn() -> P>,
 }
 
 impl<T> AtomicRef<T> {
@@ -115,9 +148,31 @@ impl<T> AtomicRef<T> {
     /// ```
     #[inline]
     pub fn new(value: Arc<T>) -> Self {
-        let ptr = Arc::into_raw(value) as *mut T;
+        Self::with_policy(value)
+    }
+}
+
+impl<T, P: PublishingPolicy> AtomicRef<T, P> {
+    /// Creates a new atomic reference using the ordering policy `P`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicRef, SeqCst};
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::<_, SeqCst>::with_policy(Arc::new(42));
+    /// assert_eq!(*atomic.load(), 42);
+    /// ```
+    #[inline]
+    pub fn with_policy(value: Arc<T>) -> Self {
         Self {
-            inner: AtomicPtr::new(ptr),
+            slot: RefSlot::new(value),
+            _policy: PhantomData,
         }
     }
 
@@ -145,14 +200,40 @@ impl<T> AtomicRef<T> {
     /// ```
     #[inline]
     pub fn load(&self) -> Arc<T> {
-        let ptr = self.inner.load(Ordering::Acquire);
-        unsafe {
-            // Increment reference count but don't drop the original pointer
-            let arc = Arc::from_raw(ptr);
-            let cloned = arc.clone();
-            let _ = Arc::into_raw(arc); // Prevent dropping
-            cloned
-        }
+        self.load_with_ordering(P::LOAD)
+    }
+
+    /// Gets the current reference with an explicit memory ordering.
+    ///
+    /// # Parameters
+    ///
+    /// * `ordering` - The memory ordering to use. Must be `Acquire` or
+    ///   `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// A cloned `Arc` pointing to the current value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `Acquire` nor `SeqCst`. Unlike the other
+    /// atomic types, this check is also performed in release builds: the
+    /// referenced value is dereferenced through an `Arc`, so a weaker ordering
+    /// would let safe code read it before it is fully published.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(42));
+    /// assert_eq!(*atomic.load_with_ordering(Ordering::SeqCst), 42);
+    /// ```
+    #[inline]
+    pub fn load_with_ordering(&self, ordering: Ordering) -> Arc<T> {
+        self.slot.load(ordering)
     }
 
     /// Sets a new reference.
@@ -178,14 +259,38 @@ impl<T> AtomicRef<T> {
     /// ```
     #[inline]
     pub fn store(&self, value: Arc<T>) {
-        let new_ptr = Arc::into_raw(value) as *mut T;
-        let old_ptr = self.inner.swap(new_ptr, Ordering::AcqRel);
-        unsafe {
-            if !old_ptr.is_null() {
-                // Drop the old value
-                Arc::from_raw(old_ptr);
-            }
-        }
+        self.store_with_ordering(value, P::STORE);
+    }
+
+    /// Sets a new reference with an explicit memory ordering.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new reference to set.
+    /// * `ordering` - The memory ordering to use. Must be `Release` or
+    ///   `SeqCst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `Release` nor `SeqCst`. Unlike the other
+    /// atomic types, this check is also performed in release builds: the
+    /// referenced value is dereferenced through an `Arc`, so a weaker ordering
+    /// would let safe code read it before it is fully published.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(42));
+    /// atomic.store_with_ordering(Arc::new(100), Ordering::SeqCst);
+    /// assert_eq!(*atomic.load(), 100);
+    /// ```
+    #[inline]
+    pub fn store_with_ordering(&self, value: Arc<T>, ordering: Ordering) {
+        self.slot.store(value, ordering);
     }
 
     /// Swaps the current reference with a new reference, returning the old
@@ -217,9 +322,43 @@ impl<T> AtomicRef<T> {
     /// ```
     #[inline]
     pub fn swap(&self, value: Arc<T>) -> Arc<T> {
-        let new_ptr = Arc::into_raw(value) as *mut T;
-        let old_ptr = self.inner.swap(new_ptr, Ordering::AcqRel);
-        unsafe { Arc::from_raw(old_ptr) }
+        self.swap_with_ordering(value, P::RMW)
+    }
+
+    /// Swaps the current reference with a new reference using an explicit
+    /// memory ordering, returning the old reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new reference to swap in.
+    /// * `ordering` - The memory ordering to use. Must be `AcqRel` or `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// The old reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `AcqRel` nor `SeqCst`. Unlike the other
+    /// atomic types, this check is also performed in release builds: the
+    /// referenced value is dereferenced through an `Arc`, so a weaker ordering
+    /// would let safe code read it before it is fully published.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let old = atomic.swap_with_ordering(Arc::new(20), Ordering::SeqCst);
+    /// assert_eq!(*old, 10);
+    /// assert_eq!(*atomic.load(), 20);
+    /// ```
+    #[inline]
+    pub fn swap_with_ordering(&self, value: Arc<T>, ordering: Ordering) -> Arc<T> {
+        self.slot.swap(value, ordering)
     }
 
     /// Compares and sets the reference atomically.
@@ -262,23 +401,61 @@ impl<T> AtomicRef<T> {
     /// ```
     #[inline]
     pub fn compare_set(&self, current: &Arc<T>, new: Arc<T>) -> Result<(), Arc<T>> {
-        let current_ptr = Arc::as_ptr(current) as *mut T;
-        let new_ptr = Arc::into_raw(new) as *mut T;
-
-        match self
-            .inner
-            .compare_exchange(current_ptr, new_ptr, Ordering::AcqRel, Ordering::Acquire)
-        {
-            Ok(_) => Ok(()),
-            Err(actual_ptr) => unsafe {
-                // CAS failed, need to restore the new Arc and return actual
-                let _new_arc = Arc::from_raw(new_ptr);
-                let actual_arc = Arc::from_raw(actual_ptr);
-                let cloned = actual_arc.clone();
-                let _ = Arc::into_raw(actual_arc); // Prevent dropping
-                Err(cloned)
-            },
-        }
+        self.compare_set_with_ordering(current, new, P::CAS_SUCCESS, P::CAS_FAILURE)
+    }
+
+    /// Compares and sets the reference atomically with explicit memory
+    /// orderings.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current reference.
+    /// * `new` - The new reference to set if current matches.
+    /// * `success` - The ordering of the read-modify-write if the comparison
+    ///   succeeds. Must be `AcqRel` or `SeqCst`.
+    /// * `failure` - The ordering of the load if the comparison fails. Must be
+    ///   `Acquire` or `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `success` is neither `AcqRel` nor `SeqCst`, or if `failure` is
+    /// neither `Acquire` nor `SeqCst`. Unlike the other atomic types, this
+    /// check is also performed in release builds: the referenced value is
+    /// dereferenced through an `Arc`, so a weaker ordering would let safe code
+    /// read it before it is fully published.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let current = atomic.load();
+    /// let result = atomic.compare_set_with_ordering(
+    ///     &current,
+    ///     Arc::new(20),
+    ///     Ordering::SeqCst,
+    ///     Ordering::SeqCst,
+    /// );
+    /// assert!(result.is_ok());
+    /// assert_eq!(*atomic.load(), 20);
+    /// ```
+    #[inline]
+    pub fn compare_set_with_ordering(
+        &self,
+        current: &Arc<T>,
+        new: Arc<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<(), Arc<T>> {
+        self.compare_and_exchange_impl(current, new, false, success, failure)
+            .map(drop)
     }
 
     /// Weak version of compare-and-set.
@@ -315,25 +492,63 @@ impl<T> AtomicRef<T> {
     /// ```
     #[inline]
     pub fn compare_set_weak(&self, current: &Arc<T>, new: Arc<T>) -> Result<(), Arc<T>> {
-        let current_ptr = Arc::as_ptr(current) as *mut T;
-        let new_ptr = Arc::into_raw(new) as *mut T;
-
-        match self.inner.compare_exchange_weak(
-            current_ptr,
-            new_ptr,
-            Ordering::AcqRel,
-            Ordering::Acquire,
-        ) {
-            Ok(_) => Ok(()),
-            Err(actual_ptr) => unsafe {
-                // CAS failed, need to restore the new Arc and return actual
-                let _new_arc = Arc::from_raw(new_ptr);
-                let actual_arc = Arc::from_raw(actual_ptr);
-                let cloned = actual_arc.clone();
-                let _ = Arc::into_raw(actual_arc); // Prevent dropping
-                Err(cloned)
-            },
-        }
+        self.compare_set_weak_with_ordering(current, new, P::CAS_SUCCESS, P::CAS_FAILURE)
+    }
+
+    /// Weak version of compare-and-set with explicit memory orderings.
+    ///
+    /// May spuriously fail even when the comparison succeeds. Should be used in
+    /// a loop.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current reference.
+    /// * `new` - The new reference to set if current matches.
+    /// * `success` - The ordering of the read-modify-write if the comparison
+    ///   succeeds. Must be `AcqRel` or `SeqCst`.
+    /// * `failure` - The ordering of the load if the comparison fails. Must be
+    ///   `Acquire` or `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `success` is neither `AcqRel` nor `SeqCst`, or if `failure` is
+    /// neither `Acquire` nor `SeqCst`. Unlike the other atomic types, this
+    /// check is also performed in release builds: the referenced value is
+    /// dereferenced through an `Arc`, so a weaker ordering would let safe code
+    /// read it before it is fully published.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let current = atomic.load();
+    /// let result = atomic.compare_set_weak_with_ordering(
+    ///     &current,
+    ///     Arc::new(20),
+    ///     Ordering::SeqCst,
+    ///     Ordering::SeqCst,
+    /// );
+    /// assert!(result.is_ok());
+    /// assert_eq!(*atomic.load(), 20);
+    /// ```
+    #[inline]
+    pub fn compare_set_weak_with_ordering(
+        &self,
+        current: &Arc<T>,
+        new: Arc<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<(), Arc<T>> {
+        self.compare_and_exchange_impl(current, new, true, success, failure)
+            .map(drop)
     }
 
     /// Compares and exchanges the reference atomically, returning the
@@ -373,22 +588,62 @@ impl<T> AtomicRef<T> {
     /// ```
     #[inline]
     pub fn compare_and_exchange(&self, current: &Arc<T>, new: Arc<T>) -> Arc<T> {
-        let current_ptr = Arc::as_ptr(current) as *mut T;
-        let new_ptr = Arc::into_raw(new) as *mut T;
-
-        match self
-            .inner
-            .compare_exchange(current_ptr, new_ptr, Ordering::AcqRel, Ordering::Acquire)
-        {
-            Ok(prev_ptr) => unsafe { Arc::from_raw(prev_ptr) },
-            Err(actual_ptr) => unsafe {
-                // CAS failed, need to restore the new Arc and return actual
-                let _ = Arc::from_raw(new_ptr);
-                let actual_arc = Arc::from_raw(actual_ptr);
-                let cloned = actual_arc.clone();
-                let _ = Arc::into_raw(actual_arc); // Prevent dropping
-                cloned
-            },
+        self.compare_and_exchange_with_ordering(current, new, P::CAS_SUCCESS, P::CAS_FAILURE)
+    }
+
+    /// Compares and exchanges the reference atomically with explicit memory
+    /// orderings, returning the previous reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current reference.
+    /// * `new` - The new reference to set if current matches.
+    /// * `success` - The ordering of the read-modify-write if the comparison
+    ///   succeeds. Must be `AcqRel` or `SeqCst`.
+    /// * `failure` - The ordering of the load if the comparison fails. Must be
+    ///   `Acquire` or `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// The reference before the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `success` is neither `AcqRel` nor `SeqCst`, or if `failure` is
+    /// neither `Acquire` nor `SeqCst`. Unlike the other atomic types, this
+    /// check is also performed in release builds: the referenced value is
+    /// dereferenced through an `Arc`, so a weaker ordering would let safe code
+    /// read it before it is fully published.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let current = atomic.load();
+    /// let prev = atomic.compare_and_exchange_with_ordering(
+    ///     &current,
+    ///     Arc::new(20),
+    ///     Ordering::SeqCst,
+    ///     Ordering::SeqCst,
+    /// );
+    /// assert!(Arc::ptr_eq(&prev, &current));
+    /// assert_eq!(*atomic.load(), 20);
+    /// ```
+    #[inline]
+    pub fn compare_and_exchange_with_ordering(
+        &self,
+        current: &Arc<T>,
+        new: Arc<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Arc<T> {
+        match self.compare_and_exchange_impl(current, new, false, success, failure) {
+            Ok(prev) => prev,
+            Err(actual) => actual,
         }
     }
 
@@ -428,27 +683,176 @@ impl<T> AtomicRef<T> {
     /// ```
     #[inline]
     pub fn compare_and_exchange_weak(&self, current: &Arc<T>, new: Arc<T>) -> Arc<T> {
-        let current_ptr = Arc::as_ptr(current) as *mut T;
-        let new_ptr = Arc::into_raw(new) as *mut T;
-
-        match self.inner.compare_exchange_weak(
-            current_ptr,
-            new_ptr,
-            Ordering::AcqRel,
-            Ordering::Acquire,
-        ) {
-            Ok(prev_ptr) => unsafe { Arc::from_raw(prev_ptr) },
-            Err(actual_ptr) => unsafe {
-                // CAS failed, need to restore the new Arc and return actual
-                let _ = Arc::from_raw(new_ptr);
-                let actual_arc = Arc::from_raw(actual_ptr);
-                let cloned = actual_arc.clone();
-                let _ = Arc::into_raw(actual_arc); // Prevent dropping
-                cloned
-            },
+        self.compare_and_exchange_weak_with_ordering(current, new, P::CAS_SUCCESS, P::CAS_FAILURE)
+    }
+
+    /// Weak version of compare-and-exchange with explicit memory orderings.
+    ///
+    /// May spuriously fail even when the comparison succeeds. Should be used in
+    /// a loop.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current reference.
+    /// * `new` - The new reference to set if current matches.
+    /// * `success` - The ordering of the read-modify-write if the comparison
+    ///   succeeds. Must be `AcqRel` or `SeqCst`.
+    /// * `failure` - The ordering of the load if the comparison fails. Must be
+    ///   `Acquire` or `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// The reference before the operation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `success` is neither `AcqRel` nor `SeqCst`, or if `failure` is
+    /// neither `Acquire` nor `SeqCst`. Unlike the other atomic types, this
+    /// check is also performed in release builds: the referenced value is
+    /// dereferenced through an `Arc`, so a weaker ordering would let safe code
+    /// read it before it is fully published.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let current = atomic.load();
+    /// let prev = atomic.compare_and_exchange_weak_with_ordering(
+    ///     &current,
+    ///     Arc::new(20),
+    ///     Ordering::SeqCst,
+    ///     Ordering::SeqCst,
+    /// );
+    /// assert!(Arc::ptr_eq(&prev, &current));
+    /// assert_eq!(*atomic.load(), 20);
+    /// ```
+    #[inline]
+    pub fn compare_and_exchange_weak_with_ordering(
+        &self,
+        current: &Arc<T>,
+        new: Arc<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Arc<T> {
+        match self.compare_and_exchange_impl(current, new, true, success, failure) {
+            Ok(prev) => prev,
+            Err(actual) => actual,
         }
     }
 
+    /// Compares the referenced value and sets the reference atomically.
+    ///
+    /// Unlike [`compare_set`](Self::compare_set), which compares pointers,
+    /// succeeds if the current value equals `current` by `PartialEq`, even
+    /// if it is a different `Arc`, e.g. a configuration rebuilt from the
+    /// same settings.
+    ///
+    /// # Memory Ordering
+    ///
+    /// Internally uses a CAS loop with `compare_set`, which uses `AcqRel` on
+    /// success and `Acquire` on failure. The loop only retries while the
+    /// reference is replaced by an equal value.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current value.
+    /// * `new` - The new reference to set if the current value is equal to
+    ///   `current`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` where `actual` is the current
+    /// reference, whose value differs from `current`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(String::from("v1")));
+    ///
+    /// // A different `Arc` holding an equal value matches
+    /// let expected = String::from("v1");
+    /// let new = Arc::new(String::from("v2"));
+    /// assert!(atomic.compare_set_by_value(&expected, new).is_ok());
+    ///
+    /// let actual = atomic
+    ///     .compare_set_by_value(&expected, Arc::new(String::from("v3")))
+    ///     .unwrap_err();
+    /// assert_eq!(*actual, "v2");
+    /// ```
+    #[inline]
+    pub fn compare_set_by_value(&self, current: &T, new: Arc<T>) -> Result<(), Arc<T>>
+    where
+        T: PartialEq,
+    {
+        self.compare_set_by(|value| value == current, new)
+    }
+
+    /// Tests the referenced value with a predicate and sets the reference
+    /// atomically.
+    ///
+    /// Succeeds if the current value satisfies `predicate`. If the
+    /// reference is replaced between the test and the exchange, the new
+    /// value is tested again, so `new` is only stored over a value that
+    /// satisfied the predicate.
+    ///
+    /// # Memory Ordering
+    ///
+    /// Internally uses a CAS loop with `compare_set`, which uses `AcqRel` on
+    /// success and `Acquire` on failure.
+    ///
+    /// # Parameters
+    ///
+    /// * `predicate` - Tests whether the current value may be replaced. It
+    ///   may be called several times.
+    /// * `new` - The new reference to set if the current value satisfies
+    ///   `predicate`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` where `actual` is the current
+    /// reference, whose value does not satisfy `predicate`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Debug)]
+    /// struct Config {
+    ///     version: u32,
+    /// }
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(Config { version: 1 }));
+    /// let new = Arc::new(Config { version: 2 });
+    /// assert!(atomic.compare_set_by(|c| c.version < 2, new).is_ok());
+    ///
+    /// let old = Arc::new(Config { version: 1 });
+    /// let actual = atomic.compare_set_by(|c| c.version < 2, old).unwrap_err();
+    /// assert_eq!(actual.version, 2);
+    /// ```
+    #[inline]
+    pub fn compare_set_by<F>(&self, mut predicate: F, new: Arc<T>) -> Result<(), Arc<T>>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.try_update_result(|current| {
+            if predicate(current) {
+                Ok(Arc::clone(&new))
+            } else {
+                Err(Arc::clone(current))
+            }
+        })
+        .map(drop)
+    }
+
     /// Updates the reference using a function, returning the old reference.
     ///
     /// # Memory Ordering
@@ -480,23 +884,483 @@ impl<T> AtomicRef<T> {
     #[inline]
     pub fn fetch_update<F>(&self, f: F) -> Arc<T>
     where
-        F: Fn(&Arc<T>) -> Arc<T>,
+        F: FnMut(&Arc<T>) -> Arc<T>,
     {
-        let mut current = self.load();
-        loop {
-            let new = f(&current);
-            match self.compare_set_weak(&current, new) {
-                Ok(_) => return current,
-                Err(actual) => current = actual,
-            }
-        }
+        self.fetch_update_with_ordering(P::CAS_SUCCESS, P::CAS_FAILURE, f)
     }
 
-    /// Gets a reference to the underlying standard library atomic type.
+    /// Updates the reference using a function with explicit memory orderings,
+    /// returning the old reference.
     ///
-    /// This allows direct access to the standard library's atomic operations
-    /// for advanced use cases that require fine-grained control over memory
-    /// ordering.
+    /// Internally uses a CAS loop until the update succeeds. The parameter
+    /// order follows `std::sync::atomic`'s `fetch_update`.
+    ///
+    /// # Parameters
+    ///
+    /// * `set_order` - The ordering of a successful CAS. Must be `AcqRel` or
+    ///   `SeqCst`.
+    /// * `fetch_order` - The ordering of the initial load and of a failed CAS.
+    ///   Must be `Acquire` or `SeqCst`.
+    /// * `f` - A function that takes the current reference and returns the new
+    ///   reference.
+    ///
+    /// # Returns
+    ///
+    /// The old reference before the update.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `set_order` is neither `AcqRel` nor `SeqCst`, or if
+    /// `fetch_order` is neither `Acquire` nor `SeqCst`. Unlike the other atomic
+    /// types, this check is also performed in release builds: the referenced
+    /// value is dereferenced through an `Arc`, so a weaker ordering would let
+    /// safe code read it before it is fully published.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let old = atomic.fetch_update_with_ordering(
+    ///     Ordering::SeqCst,
+    ///     Ordering::SeqCst,
+    ///     |x| Arc::new(**x * 2),
+    /// );
+    /// assert_eq!(*old, 10);
+    /// assert_eq!(*atomic.load(), 20);
+    /// ```
+    #[inline]
+    pub fn fetch_update_with_ordering<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Arc<T>
+    where
+        F: FnMut(&Arc<T>) -> Arc<T>,
+    {
+        self.try_update_result_with_backoff(set_order, fetch_order, P::BACKOFF, |current| {
+            Ok::<_, Infallible>(f(current))
+        })
+        .unwrap_or_else(|never| match never {})
+    }
+
+    /// Updates the reference using a function, returning the old reference and
+    /// the number of CAS attempts.
+    ///
+    /// Behaves like `fetch_update`, using the same memory orderings, but also
+    /// reports how many times `f` was called and the result offered to a
+    /// compare-and-exchange. An uncontended update takes exactly one attempt,
+    /// so the count is a cheap measure of contention on the reference. Spurious
+    /// failures of the weak CAS are counted as well.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current reference and returns the new
+    ///   reference.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the old reference before the update and the number of
+    /// attempts, which is at least 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let (old, attempts) =
+    ///     atomic.fetch_update_counted(|x| Arc::new(**x * 2));
+    /// assert_eq!(*old, 10);
+    /// assert!(attempts >= 1);
+    /// ```
+    #[inline]
+    pub fn fetch_update_counted<F>(&self, mut f: F) -> (Arc<T>, usize)
+    where
+        F: FnMut(&Arc<T>) -> Arc<T>,
+    {
+        let mut attempts = 0;
+        let old = self.fetch_update(|current| {
+            attempts += 1;
+            f(current)
+        });
+        (old, attempts)
+    }
+
+    /// Updates the reference using a function and a custom backoff strategy,
+    /// returning the old reference.
+    ///
+    /// Behaves like `fetch_update`, using the same memory orderings, but calls
+    /// `backoff` after each failed CAS instead of the `BACKOFF` strategy of the
+    /// policy. Pass `&mut backoff` to inspect the strategy's state afterwards.
+    ///
+    /// # Parameters
+    ///
+    /// * `backoff` - The strategy applied after each failed attempt.
+    /// * `f` - A function that takes the current reference and returns the new
+    ///   reference.
+    ///
+    /// # Returns
+    ///
+    /// The old reference before the update.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicRef, YieldBackoff};
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let backoff = YieldBackoff::new(4);
+    /// let old =
+    ///     atomic.fetch_update_with_backoff(backoff, |x| Arc::new(**x * 2));
+    /// assert_eq!(*old, 10);
+    /// assert_eq!(*atomic.load(), 20);
+    /// ```
+    #[inline]
+    pub fn fetch_update_with_backoff<B, F>(&self, backoff: B, mut f: F) -> Arc<T>
+    where
+        B: Backoff,
+        F: FnMut(&Arc<T>) -> Arc<T>,
+    {
+        self.try_update_result_with_backoff(P::CAS_SUCCESS, P::CAS_FAILURE, backoff, |current| {
+            Ok::<_, Infallible>(f(current))
+        })
+        .unwrap_or_else(|never| match never {})
+    }
+
+    /// Updates the referenced value by read-copy-update.
+    ///
+    /// Clones the current value, applies `f` to the copy and stores it in a
+    /// new `Arc` if the reference was not replaced in between. Otherwise,
+    /// starts again from the new current value. Readers holding the old
+    /// `Arc` keep seeing the old value.
+    ///
+    /// # Memory Ordering
+    ///
+    /// Built on [`fetch_update`](Self::fetch_update), so it uses the same
+    /// orderings: `AcqRel` on success and `Acquire` on failure.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function mutating a copy of the current value. It may be
+    ///   called several times, each time on a fresh copy.
+    ///
+    /// # Returns
+    ///
+    /// An [`RcuGuard`] holding the replaced reference and the stored one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::Arc;
+    ///
+    /// #[derive(Clone)]
+    /// struct Config {
+    ///     timeout: u64,
+    ///     max_retries: u32,
+    /// }
+    ///
+    /// let config = AtomicRef::new(Arc::new(Config {
+    ///     timeout: 1000,
+    ///     max_retries: 3,
+    /// }));
+    /// let update = config.rcu(|c| c.timeout = 2000);
+    /// assert_eq!(update.old().timeout, 1000);
+    /// assert_eq!(update.updated().timeout, 2000);
+    /// assert_eq!(update.updated().max_retries, 3);
+    /// assert_eq!(config.load().timeout, 2000);
+    /// ```
+    #[inline]
+    pub fn rcu<F>(&self, f: F) -> RcuGuard<T>
+    where
+        T: Clone,
+        F: FnMut(&mut T),
+    {
+        self.rcu_with_backoff(P::BACKOFF, f)
+    }
+
+    /// Updates the referenced value by read-copy-update, using a custom
+    /// backoff strategy.
+    ///
+    /// Behaves like [`rcu`](Self::rcu), but calls `backoff` after each failed
+    /// CAS instead of the `BACKOFF` strategy of the policy.
+    ///
+    /// # Parameters
+    ///
+    /// * `backoff` - The strategy applied after each failed attempt.
+    /// * `f` - A function mutating a copy of the current value. It may be
+    ///   called several times, each time on a fresh copy.
+    ///
+    /// # Returns
+    ///
+    /// An [`RcuGuard`] holding the replaced reference and the stored one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicRef, YieldBackoff};
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(vec![1, 2]));
+    /// let backoff = YieldBackoff::new(4);
+    /// let update = atomic.rcu_with_backoff(backoff, |v| v.push(3));
+    /// assert_eq!(**update.old(), vec![1, 2]);
+    /// assert_eq!(**update.updated(), vec![1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn rcu_with_backoff<B, F>(&self, backoff: B, mut f: F) -> RcuGuard<T>
+    where
+        T: Clone,
+        B: Backoff,
+        F: FnMut(&mut T),
+    {
+        let mut stored = None;
+        let old = self.fetch_update_with_backoff(backoff, |current| {
+            let mut value = T::clone(current);
+            f(&mut value);
+            let new = Arc::new(value);
+            stored = Some(Arc::clone(&new));
+            new
+        });
+        RcuGuard {
+            old,
+            new: stored.expect("fetch_update calls the update function at least once"),
+        }
+    }
+
+    /// Updates the reference using a function, returning the new reference.
+    ///
+    /// Internally uses a CAS loop until the update succeeds. The returned
+    /// reference is exactly the one that was stored.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current reference and returns the new
+    ///   reference.
+    ///
+    /// # Returns
+    ///
+    /// The new reference after the update.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let new = atomic.update_and_get(|x| Arc::new(**x * 2));
+    /// assert_eq!(*new, 20);
+    /// assert!(Arc::ptr_eq(&new, &atomic.load()));
+    /// ```
+    #[inline]
+    pub fn update_and_get<F>(&self, mut f: F) -> Arc<T>
+    where
+        F: FnMut(&Arc<T>) -> Arc<T>,
+    {
+        let mut stored = None;
+        self.fetch_update(|current| {
+            let new = f(current);
+            stored = Some(new.clone());
+            new
+        });
+        stored.expect("fetch_update calls the update function at least once")
+    }
+
+    /// Accumulates a value using a binary function, returning the new
+    /// reference.
+    ///
+    /// Internally uses the same CAS loop as `update_and_get`.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - The reference to accumulate with.
+    /// * `f` - A binary function that takes the current reference and `x`,
+    ///   returning the new reference.
+    ///
+    /// # Returns
+    ///
+    /// The new reference after the accumulation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(vec![1, 2]));
+    /// let new = atomic.accumulate_and_get(Arc::new(vec![3]), |a, b| {
+    ///     Arc::new(a.iter().chain(b.iter()).copied().collect())
+    /// });
+    /// assert_eq!(*new, vec![1, 2, 3]);
+    /// assert!(Arc::ptr_eq(&new, &atomic.load()));
+    /// ```
+    #[inline]
+    pub fn accumulate_and_get<F>(&self, x: Arc<T>, mut f: F) -> Arc<T>
+    where
+        F: FnMut(&Arc<T>, &Arc<T>) -> Arc<T>,
+    {
+        self.update_and_get(|current| f(current, &x))
+    }
+
+    /// Updates the reference using a function that may decline, returning the
+    /// old reference.
+    ///
+    /// Internally uses a CAS loop. Unlike `fetch_update`, the loop stops as
+    /// soon as `f` returns `None`, and the reference is left unchanged. This
+    /// matches the semantics of `std`'s `fetch_update`.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current reference and returns the new
+    ///   reference, or `None` to abort the update.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(old_reference)` if the reference was updated, or
+    /// `Err(current_reference)` if `f` returned `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let double = |x: &Arc<i32>| (**x < 20).then(|| Arc::new(**x * 2));
+    /// assert_eq!(*atomic.try_update(double).unwrap(), 10);
+    /// assert_eq!(*atomic.try_update(double).unwrap_err(), 20);
+    /// ```
+    #[inline]
+    pub fn try_update<F>(&self, mut f: F) -> Result<Arc<T>, Arc<T>>
+    where
+        F: FnMut(&Arc<T>) -> Option<Arc<T>>,
+    {
+        self.try_update_result(|current| f(current).ok_or_else(|| Arc::clone(current)))
+    }
+
+    /// Updates the reference using a fallible function, returning the old
+    /// reference.
+    ///
+    /// Internally uses a CAS loop. The loop stops as soon as `f` returns an
+    /// error, which is passed through to the caller, and the reference is left
+    /// unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current reference and returns the new
+    ///   reference, or an error to abort the update.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(old_reference)` if the reference was updated, or the error returned
+    /// by `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicRef;
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let old = atomic.try_update_result(|x| Ok::<_, ()>(Arc::new(**x + 1)));
+    /// assert_eq!(*old.unwrap(), 10);
+    /// let result = atomic.try_update_result(|_| Err("busy"));
+    /// assert_eq!(result.unwrap_err(), "busy");
+    /// ```
+    #[inline]
+    pub fn try_update_result<F, E>(&self, f: F) -> Result<Arc<T>, E>
+    where
+        F: FnMut(&Arc<T>) -> Result<Arc<T>, E>,
+    {
+        self.try_update_result_with_backoff(P::CAS_SUCCESS, P::CAS_FAILURE, P::BACKOFF, f)
+    }
+
+    /// Updates the reference using a function that may decline and a custom
+    /// backoff strategy, returning the old reference.
+    ///
+    /// Behaves like `try_update`, using the same memory orderings, but calls
+    /// `backoff` after each failed CAS instead of the `BACKOFF` strategy of the
+    /// policy.
+    ///
+    /// # Parameters
+    ///
+    /// * `backoff` - The strategy applied after each failed attempt.
+    /// * `f` - A function that takes the current reference and returns the new
+    ///   reference, or `None` to abort the update.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(old_reference)` if the reference was updated, or
+    /// `Err(current_reference)` if `f` returned `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicRef, YieldBackoff};
+    /// use std::sync::Arc;
+    ///
+    /// let atomic = AtomicRef::new(Arc::new(10));
+    /// let backoff = YieldBackoff::new(4);
+    /// let increment = |x: &Arc<i32>| Some(Arc::new(**x + 1));
+    /// let old = atomic.try_update_with_backoff(backoff, increment);
+    /// assert_eq!(*old.unwrap(), 10);
+    /// assert_eq!(*atomic.load(), 11);
+    /// ```
+    #[inline]
+    pub fn try_update_with_backoff<B, F>(&self, backoff: B, mut f: F) -> Result<Arc<T>, Arc<T>>
+    where
+        B: Backoff,
+        F: FnMut(&Arc<T>) -> Option<Arc<T>>,
+    {
+        self.try_update_result_with_backoff(P::CAS_SUCCESS, P::CAS_FAILURE, backoff, |current| {
+            f(current).ok_or_else(|| Arc::clone(current))
+        })
+    }
+
+    /// Shared CAS loop of the update methods.
+    ///
+    /// Calls `f` with the current reference until the reference it returns
+    /// is stored, applying `backoff` after each failed CAS. Returns the old
+    /// reference, or the error returned by `f`, which stops the loop.
+    #[inline]
+    fn try_update_result_with_backoff<B, F, E>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut backoff: B,
+        mut f: F,
+    ) -> Result<Arc<T>, E>
+    where
+        B: Backoff,
+        F: FnMut(&Arc<T>) -> Result<Arc<T>, E>,
+    {
+        assert_acq_rel(set_order);
+        let mut current = self.load_with_ordering(fetch_order);
+        loop {
+            let new = f(&current)?;
+            match self.compare_and_exchange_impl(&current, new, true, set_order, fetch_order) {
+                Ok(_) => return Ok(current),
+                Err(actual) => {
+                    current = actual;
+                    backoff.backoff();
+                }
+            }
+        }
+    }
+
+    /// Gets a reference to the underlying standard library atomic type.
+    ///
+    /// This allows direct access to the standard library's atomic operations
+    /// for advanced use cases that require fine-grained control over memory
+    /// ordering.
     ///
     /// # Memory Ordering
     ///
@@ -512,14 +1376,35 @@ impl<T> AtomicRef<T> {
     ///
     /// Direct manipulation of the underlying pointer requires careful
     /// management of Arc reference counts to avoid memory leaks or
-    /// use-after-free bugs.
+    /// use-after-free bugs. Operations performed through the returned
+    /// reference bypass the deferred reclamation described in the type
+    /// documentation, so a pointer read from it must not be dereferenced
+    /// while other threads may replace it.
     #[inline]
     pub fn inner(&self) -> &AtomicPtr<T> {
-        &self.inner
+        self.slot.as_atomic_ptr()
+    }
+
+    /// Shared implementation of the strong and weak compare-and-exchange
+    /// operations.
+    ///
+    /// Returns `Ok(previous)` on success and `Err(actual)` on failure.
+    #[inline]
+    fn compare_and_exchange_impl(
+        &self,
+        current: &Arc<T>,
+        new: Arc<T>,
+        weak: bool,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Arc<T>, Arc<T>> {
+        let current_ptr = Arc::as_ptr(current) as *mut T;
+        self.slot
+            .compare_exchange(current_ptr, new, weak, success, failure)
     }
 }
 
-impl<T> Atomic for AtomicRef<T> {
+impl<T, P: PublishingPolicy> Atomic for AtomicRef<T, P> {
     type Value = Arc<T>;
 
     #[inline]
@@ -549,54 +1434,69 @@ impl<T> Atomic for AtomicRef<T> {
 
     #[inline]
     fn compare_exchange(&self, current: Arc<T>, new: Arc<T>) -> Arc<T> {
-        match self.compare_set(&current, new.clone()) {
-            Ok(_) => current,
-            Err(_) => self.load(),
-        }
+        self.compare_and_exchange(&current, new)
     }
 
     #[inline]
     fn compare_exchange_weak(&self, current: Arc<T>, new: Arc<T>) -> Arc<T> {
-        match self.compare_set_weak(&current, new.clone()) {
-            Ok(_) => current,
-            Err(_) => self.load(),
-        }
+        self.compare_and_exchange_weak(&current, new)
     }
 
     #[inline]
-    fn fetch_update<F>(&self, f: F) -> Arc<T>
+    fn fetch_update<F>(&self, mut f: F) -> Arc<T>
     where
-        F: Fn(Arc<T>) -> Arc<T>,
+        F: FnMut(Arc<T>) -> Arc<T>,
     {
         self.fetch_update(|x| f(x.clone()))
     }
+
+    #[inline]
+    fn update_and_get<F>(&self, mut f: F) -> Arc<T>
+    where
+        F: FnMut(Arc<T>) -> Arc<T>,
+    {
+        self.update_and_get(|x| f(x.clone()))
+    }
+
+    #[inline]
+    fn try_update<F>(&self, mut f: F) -> Result<Arc<T>, Arc<T>>
+    where
+        F: FnMut(Arc<T>) -> Option<Arc<T>>,
+    {
+        self.try_update(|x| f(x.clone()))
+    }
+
+    #[inline]
+    fn try_update_result<F, E>(&self, mut f: F) -> Result<Arc<T>, E>
+    where
+        F: FnMut(Arc<T>) -> Result<Arc<T>, E>,
+    {
+        self.try_update_result(|x| f(x.clone()))
+    }
+
+    #[inline]
+    fn accumulate_and_get<F>(&self, x: Arc<T>, mut f: F) -> Arc<T>
+    where
+        F: FnMut(Arc<T>, Arc<T>) -> Arc<T>,
+    {
+        self.accumulate_and_get(x, |a, b| f(a.clone(), b.clone()))
+    }
 }
 
-impl<T> Clone for AtomicRef<T> {
+impl<T, P: PublishingPolicy> Clone for AtomicRef<T, P> {
     /// Clones the atomic reference.
     ///
     /// Creates a new `AtomicRef` that initially points to the same value as
     /// the original, but subsequent atomic operations are independent.
     fn clone(&self) -> Self {
-        Self::new(self.load())
+        Self::with_policy(self.load())
     }
 }
 
-impl<T> Drop for AtomicRef<T> {
-    fn drop(&mut self) {
-        let ptr = self.inner.load(Ordering::Acquire);
-        unsafe {
-            if !ptr.is_null() {
-                Arc::from_raw(ptr);
-            }
-        }
-    }
-}
+unsafe impl<T: Send + Sync, P> Send for AtomicRef<T, P> {}
+unsafe impl<T: Send + Sync, P> Sync for AtomicRef<T, P> {}
 
-unsafe impl<T: Send + Sync> Send for AtomicRef<T> {}
-unsafe impl<T: Send + Sync> Sync for AtomicRef<T> {}
-
-impl<T: fmt::Debug> fmt::Debug for AtomicRef<T> {
+impl<T: fmt::Debug, P: PublishingPolicy> fmt::Debug for AtomicRef<T, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("AtomicRef")
             .field("value", &self.load())
@@ -604,8 +1504,79 @@ impl<T: fmt::Debug> fmt::Debug for AtomicRef<T> {
     }
 }
 
-impl<T: fmt::Display> fmt::Display for AtomicRef<T> {
+impl<T: fmt::Display, P: PublishingPolicy> fmt::Display for AtomicRef<T, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.load())
     }
 }
+
+/// Result of a read-copy-update on an [`AtomicRef`].
+///
+/// Created by [`AtomicRef::rcu`] and [`AtomicRef::rcu_with_backoff`]. Holds
+/// the reference that was replaced and the one that was stored, and
+/// dereferences to the stored value.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicRef;
+/// use std::sync::Arc;
+///
+/// let counter = AtomicRef::new(Arc::new(1));
+/// let update = counter.rcu(|n| *n += 1);
+/// assert_eq!(*update, 2);
+/// let (old, new) = update.into_parts();
+/// assert_eq!((*old, *new), (1, 2));
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone)]
+pub struct RcuGuard<T> {
+    old: Arc<T>,
+    new: Arc<T>,
+}
+
+impl<T> RcuGuard<T> {
+    /// Returns the reference replaced by the update.
+    ///
+    /// # Returns
+    ///
+    /// The old reference.
+    #[inline]
+    pub fn old(&self) -> &Arc<T> {
+        &self.old
+    }
+
+    /// Returns the reference stored by the update.
+    ///
+    /// It may already have been replaced by another thread.
+    ///
+    /// # Returns
+    ///
+    /// The new reference.
+    #[inline]
+    pub fn updated(&self) -> &Arc<T> {
+        &self.new
+    }
+
+    /// Consumes the guard, returning both references.
+    ///
+    /// # Returns
+    ///
+    /// The old and the new reference, in this order.
+    #[inline]
+    pub fn into_parts(self) -> (Arc<T>, Arc<T>) {
+        (self.old, self.new)
+    }
+}
+
+impl<T> Deref for RcuGuard<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.new
+    }
+}
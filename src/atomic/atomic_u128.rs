@@ -0,0 +1,61 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic 128-bit Unsigned Integer
+//!
+//! Provides an easy-to-use atomic 128-bit unsigned integer type with sensible
+//! default memory orderings.
+//!
+//! The underlying type is [`RawAtomicU128`], which is lock-free where the
+//! processor supports a 128-bit compare-and-exchange and lock-based
+//! elsewhere.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
+
+use crate::atomic::backoff::Backoff;
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::OrderingPolicy;
+use crate::atomic::raw_atomic_128::RawAtomicU128;
+
+impl_atomic_number!(AtomicU128, RawAtomicU128, u128, "128-bit unsigned integer");
+
+impl AtomicU128 {
+    /// Returns whether the operations of this type are lock-free on this
+    /// machine.
+    ///
+    /// The result does not depend on the ordering policy. On `x86_64`
+    /// processors supporting `cmpxchg16b` and on little-endian `aarch64`
+    /// the operations use a 128-bit compare-and-exchange; elsewhere they
+    /// are serialized by a set of spin locks, see [`RawAtomicU128`].
+    ///
+    /// # Returns
+    ///
+    /// `true` if the operations are lock-free, `false` if they use the
+    /// lock-based fallback.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicU128;
+    ///
+    /// if AtomicU128::is_lock_free() {
+    ///     println!("using a 128-bit compare-and-exchange");
+    /// }
+    /// ```
+    #[inline]
+    pub fn is_lock_free() -> bool {
+        RawAtomicU128::is_lock_free()
+    }
+}
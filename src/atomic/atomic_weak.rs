@@ -0,0 +1,549 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Atomic Weak Reference
+//!
+//! Provides an atomic slot holding a `Weak<T>`, which does not keep its
+//! target alive.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Weak;
+
+use crate::atomic::ordering_policy::DefaultOrdering;
+use crate::atomic::ordering_policy::PublishingPolicy;
+use crate::atomic::ref_slot::RefSlot;
+
+/// Atomic weak reference type.
+///
+/// Works like [`AtomicRef`](crate::atomic::AtomicRef), but stores a
+/// `Weak<T>` instead of an `Arc<T>`, so that the referenced value can be
+/// dropped while the slot still refers to it. Typical uses are observer
+/// registries and back references that must not create reference cycles.
+///
+/// # Memory Ordering Strategy
+///
+/// The orderings are the same as those of `AtomicRef`: `Acquire` for
+/// loads, `Release` for stores, `AcqRel` for read-modify-write operations
+/// and `Acquire` on CAS failure with the default policy. Only policies
+/// implementing [`PublishingPolicy`] are accepted.
+///
+/// # Implementation Details
+///
+/// The `Weak<T>` is stored as the raw pointer returned by `Weak::into_raw`,
+/// and the slot owns the weak count it represents. Loading is two steps,
+/// reading the pointer and incrementing the weak count, and a concurrent
+/// `store` dropping the last weak reference in between would free the
+/// allocation. Every instance therefore owns the same epoch-based
+/// reclamation domain as `AtomicRef`: loads pin it, and every operation
+/// unlinking the stored reference waits for the pinned readers before
+/// releasing or returning it.
+///
+/// CAS operations compare by pointer identity (`Weak::ptr_eq`). Since the
+/// slot keeps the allocation of its target alive, a stored pointer cannot
+/// be reused by another allocation while it is stored, even after the
+/// target is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::AtomicWeak;
+/// use std::sync::Arc;
+///
+/// let observer = Arc::new(String::from("observer"));
+/// let slot = AtomicWeak::new(Arc::downgrade(&observer));
+/// assert_eq!(slot.upgrade().as_deref(), Some(&*observer));
+///
+/// // The slot does not keep the observer alive
+/// drop(observer);
+/// assert!(slot.upgrade().is_none());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub struct AtomicWeak<T, P = DefaultOrdering> {
+    slot: RefSlot<Weak<T>>,
+    _policy: PhantomData<fn() -> P>,
+}
+
+impl<T> AtomicWeak<T> {
+    /// Creates a new atomic weak reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial weak reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let target = Arc::new(42);
+    /// let slot = AtomicWeak::new(Arc::downgrade(&target));
+    /// assert_eq!(slot.upgrade().as_deref(), Some(&42));
+    ///
+    /// let empty: AtomicWeak<i32> = AtomicWeak::new(Weak::new());
+    /// assert!(empty.upgrade().is_none());
+    /// ```
+    #[inline]
+    pub fn new(value: Weak<T>) -> Self {
+        Self::with_policy(value)
+    }
+}
+
+impl<T, P: PublishingPolicy> AtomicWeak<T, P> {
+    /// Creates a new atomic weak reference using the ordering policy `P`.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The initial weak reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::{AtomicWeak, SeqCst};
+    /// use std::sync::Arc;
+    ///
+    /// let target = Arc::new(42);
+    /// let slot =
+    ///     AtomicWeak::<_, SeqCst>::with_policy(Arc::downgrade(&target));
+    /// assert_eq!(slot.upgrade().as_deref(), Some(&42));
+    /// ```
+    #[inline]
+    pub fn with_policy(value: Weak<T>) -> Self {
+        Self {
+            slot: RefSlot::new(value),
+            _policy: PhantomData,
+        }
+    }
+
+    /// Gets the current weak reference.
+    ///
+    /// # Returns
+    ///
+    /// A clone of the stored `Weak`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let target = Arc::new(42);
+    /// let slot = AtomicWeak::new(Arc::downgrade(&target));
+    /// assert!(Weak::ptr_eq(&slot.load(), &Arc::downgrade(&target)));
+    /// ```
+    #[inline]
+    pub fn load(&self) -> Weak<T> {
+        self.load_with_ordering(P::LOAD)
+    }
+
+    /// Gets the current weak reference with an explicit memory ordering.
+    ///
+    /// # Parameters
+    ///
+    /// * `ordering` - The memory ordering to use. Must be `Acquire` or
+    ///   `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// A clone of the stored `Weak`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `Acquire` nor `SeqCst`, also in
+    /// release builds, like [`AtomicRef`](crate::atomic::AtomicRef).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::Arc;
+    ///
+    /// let target = Arc::new(42);
+    /// let slot = AtomicWeak::new(Arc::downgrade(&target));
+    /// let weak = slot.load_with_ordering(Ordering::SeqCst);
+    /// assert_eq!(weak.upgrade().as_deref(), Some(&42));
+    /// ```
+    #[inline]
+    pub fn load_with_ordering(&self, ordering: Ordering) -> Weak<T> {
+        self.slot.load(ordering)
+    }
+
+    /// Upgrades the current weak reference.
+    ///
+    /// # Returns
+    ///
+    /// A strong reference to the target, or `None` if the target has been
+    /// dropped or the slot holds an empty `Weak`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `P::LOAD` is neither `Acquire` nor `SeqCst`, like
+    /// [`load`](Self::load).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::Arc;
+    ///
+    /// let target = Arc::new(42);
+    /// let slot = AtomicWeak::new(Arc::downgrade(&target));
+    /// assert_eq!(slot.upgrade().as_deref(), Some(&42));
+    /// drop(target);
+    /// assert!(slot.upgrade().is_none());
+    /// ```
+    #[inline]
+    pub fn upgrade(&self) -> Option<Arc<T>> {
+        self.slot.load_with(P::LOAD, Weak::upgrade)
+    }
+
+    /// Sets a new weak reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new weak reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::Arc;
+    ///
+    /// let first = Arc::new(1);
+    /// let second = Arc::new(2);
+    /// let slot = AtomicWeak::new(Arc::downgrade(&first));
+    /// slot.store(Arc::downgrade(&second));
+    /// assert_eq!(slot.upgrade().as_deref(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn store(&self, value: Weak<T>) {
+        self.store_with_ordering(value, P::STORE);
+    }
+
+    /// Sets a new weak reference with an explicit memory ordering.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new weak reference.
+    /// * `ordering` - The memory ordering to use. Must be `Release` or
+    ///   `SeqCst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `Release` nor `SeqCst`, also in
+    /// release builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let target = Arc::new(1);
+    /// let slot = AtomicWeak::new(Weak::new());
+    /// slot.store_with_ordering(Arc::downgrade(&target), Ordering::SeqCst);
+    /// assert_eq!(slot.upgrade().as_deref(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn store_with_ordering(&self, value: Weak<T>, ordering: Ordering) {
+        self.slot.store(value, ordering);
+    }
+
+    /// Swaps the current weak reference with a new one, returning the old
+    /// weak reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new weak reference.
+    ///
+    /// # Returns
+    ///
+    /// The old weak reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let target = Arc::new(1);
+    /// let slot = AtomicWeak::new(Arc::downgrade(&target));
+    /// let old = slot.swap(Weak::new());
+    /// assert_eq!(old.upgrade().as_deref(), Some(&1));
+    /// assert!(slot.upgrade().is_none());
+    /// ```
+    #[inline]
+    pub fn swap(&self, value: Weak<T>) -> Weak<T> {
+        self.swap_with_ordering(value, P::RMW)
+    }
+
+    /// Swaps the current weak reference with a new one using an explicit
+    /// memory ordering, returning the old weak reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The new weak reference.
+    /// * `ordering` - The memory ordering to use. Must be `AcqRel` or
+    ///   `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// The old weak reference.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `AcqRel` nor `SeqCst`, also in
+    /// release builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let target = Arc::new(1);
+    /// let slot = AtomicWeak::new(Arc::downgrade(&target));
+    /// let old = slot.swap_with_ordering(Weak::new(), Ordering::SeqCst);
+    /// assert_eq!(old.upgrade().as_deref(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn swap_with_ordering(&self, value: Weak<T>, ordering: Ordering) -> Weak<T> {
+        self.slot.swap(value, ordering)
+    }
+
+    /// Compares and sets the weak reference atomically.
+    ///
+    /// If the current weak reference points to the same allocation as
+    /// `current`, sets it to `new` and returns `Ok(())`. Otherwise, returns
+    /// `Err(actual)` where `actual` is the current weak reference.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current weak reference.
+    /// * `new` - The new weak reference to set if current matches.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Note
+    ///
+    /// Comparison uses pointer equality (`Weak::ptr_eq`). Two empty `Weak`
+    /// references created by `Weak::new` are equal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let target = Arc::new(1);
+    /// let slot = AtomicWeak::new(Weak::new());
+    /// assert!(slot
+    ///     .compare_set(&Weak::new(), Arc::downgrade(&target))
+    ///     .is_ok());
+    /// assert!(slot.compare_set(&Weak::new(), Weak::new()).is_err());
+    /// ```
+    #[inline]
+    pub fn compare_set(&self, current: &Weak<T>, new: Weak<T>) -> Result<(), Weak<T>> {
+        self.compare_set_with_ordering(current, new, P::CAS_SUCCESS, P::CAS_FAILURE)
+    }
+
+    /// Compares and sets the weak reference atomically with explicit memory
+    /// orderings.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current weak reference.
+    /// * `new` - The new weak reference to set if current matches.
+    /// * `success` - The memory ordering on success. Must be `AcqRel` or
+    ///   `SeqCst`.
+    /// * `failure` - The memory ordering on failure. Must be `Acquire` or
+    ///   `SeqCst`.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `success` or `failure` is weaker than required, also in
+    /// release builds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::atomic::Ordering;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let target = Arc::new(1);
+    /// let slot = AtomicWeak::new(Weak::new());
+    /// assert!(slot
+    ///     .compare_set_with_ordering(
+    ///         &Weak::new(),
+    ///         Arc::downgrade(&target),
+    ///         Ordering::SeqCst,
+    ///         Ordering::SeqCst,
+    ///     )
+    ///     .is_ok());
+    /// ```
+    #[inline]
+    pub fn compare_set_with_ordering(
+        &self,
+        current: &Weak<T>,
+        new: Weak<T>,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<(), Weak<T>> {
+        self.compare_and_exchange_impl(current, new, false, success, failure)
+            .map(drop)
+    }
+
+    /// Weak version of compare-and-set.
+    ///
+    /// May spuriously fail even when the comparison succeeds. Should be
+    /// used in a loop.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current weak reference.
+    /// * `new` - The new weak reference to set if current matches.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` on success, or `Err(actual)` on failure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let target = Arc::new(1);
+    /// let slot = AtomicWeak::new(Weak::new());
+    /// let mut current = slot.load();
+    /// loop {
+    ///     match slot.compare_set_weak(&current, Arc::downgrade(&target)) {
+    ///         Ok(_) => break,
+    ///         Err(actual) => current = actual,
+    ///     }
+    /// }
+    /// assert_eq!(slot.upgrade().as_deref(), Some(&1));
+    /// ```
+    #[inline]
+    pub fn compare_set_weak(&self, current: &Weak<T>, new: Weak<T>) -> Result<(), Weak<T>> {
+        self.compare_and_exchange_impl(current, new, true, P::CAS_SUCCESS, P::CAS_FAILURE)
+            .map(drop)
+    }
+
+    /// Compares and exchanges the weak reference atomically, returning the
+    /// previous weak reference.
+    ///
+    /// The returned weak reference is the one witnessed by the operation;
+    /// it points to the same allocation as `current` if and only if the
+    /// exchange succeeded.
+    ///
+    /// # Parameters
+    ///
+    /// * `current` - The expected current weak reference.
+    /// * `new` - The new weak reference to set if current matches.
+    ///
+    /// # Returns
+    ///
+    /// The weak reference before the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use prism3_rust_concurrent::atomic::AtomicWeak;
+    /// use std::sync::{Arc, Weak};
+    ///
+    /// let target = Arc::new(1);
+    /// let slot = AtomicWeak::new(Arc::downgrade(&target));
+    /// let prev = slot.compare_and_exchange(&Weak::new(), Weak::new());
+    /// assert!(Weak::ptr_eq(&prev, &Arc::downgrade(&target)));
+    /// ```
+    #[inline]
+    pub fn compare_and_exchange(&self, current: &Weak<T>, new: Weak<T>) -> Weak<T> {
+        match self.compare_and_exchange_impl(current, new, false, P::CAS_SUCCESS, P::CAS_FAILURE) {
+            Ok(prev) => prev,
+            Err(actual) => actual,
+        }
+    }
+
+    /// Shared implementation of the compare-and-exchange operations.
+    ///
+    /// Returns `Ok(previous)` on success, or `Err(actual)` with a clone of
+    /// the witnessed weak reference on failure.
+    #[inline]
+    fn compare_and_exchange_impl(
+        &self,
+        current: &Weak<T>,
+        new: Weak<T>,
+        weak: bool,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Weak<T>, Weak<T>> {
+        let current_ptr = Weak::as_ptr(current) as *mut T;
+        self.slot
+            .compare_exchange(current_ptr, new, weak, success, failure)
+    }
+}
+
+impl<T> Default for AtomicWeak<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Weak::new())
+    }
+}
+
+impl<T> From<Weak<T>> for AtomicWeak<T> {
+    #[inline]
+    fn from(value: Weak<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<&Arc<T>> for AtomicWeak<T> {
+    #[inline]
+    fn from(value: &Arc<T>) -> Self {
+        Self::new(Arc::downgrade(value))
+    }
+}
+
+impl<T, P: PublishingPolicy> Clone for AtomicWeak<T, P> {
+    fn clone(&self) -> Self {
+        Self::with_policy(self.load())
+    }
+}
+
+unsafe impl<T: Send + Sync, P> Send for AtomicWeak<T, P> {}
+unsafe impl<T: Send + Sync, P> Sync for AtomicWeak<T, P> {}
+
+impl<T: fmt::Debug, P: PublishingPolicy> fmt::Debug for AtomicWeak<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicWeak")
+            .field("value", &self.upgrade())
+            .finish()
+    }
+}
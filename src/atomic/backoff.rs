@@ -0,0 +1,311 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Backoff Strategies
+//!
+//! Provides pluggable strategies that a CAS loop applies after each failed
+//! compare-and-exchange, to reduce cache-line contention between cores.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::hint;
+use std::thread;
+
+/// A strategy applied by a CAS loop after each failed attempt.
+///
+/// A fresh strategy is used for every loop, so implementors may keep state
+/// such as the number of failures seen so far.
+///
+/// The CAS-loop methods without a backoff parameter, e.g. `fetch_update` or
+/// the floating-point `fetch_add`, use the [`BackoffStrategy`] selected by
+/// the `BACKOFF` constant of the ordering policy, [`ExponentialBackoff`] by
+/// default. The `*_with_backoff` methods accept any strategy.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::{AtomicI64, Backoff};
+///
+/// /// Counts the failed attempts of a CAS loop.
+/// struct CountingBackoff(usize);
+///
+/// impl Backoff for CountingBackoff {
+///     fn backoff(&mut self) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let atomic = AtomicI64::new(10);
+/// let mut backoff = CountingBackoff(0);
+/// atomic.fetch_update_with_backoff(&mut backoff, |x| x * 2);
+/// assert_eq!(atomic.load(), 20);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait Backoff {
+    /// Waits before the next attempt of a CAS loop.
+    fn backoff(&mut self);
+}
+
+impl<B: Backoff + ?Sized> Backoff for &mut B {
+    #[inline]
+    fn backoff(&mut self) {
+        (**self).backoff();
+    }
+}
+
+/// Retries immediately, without any delay.
+///
+/// Gives the lowest latency when contention is rare, but can livelock
+/// cache lines when many cores update the same value.
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NoBackoff;
+
+impl Backoff for NoBackoff {
+    #[inline]
+    fn backoff(&mut self) {}
+}
+
+/// Emits a single spin-loop hint after each failed attempt.
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SpinBackoff;
+
+impl Backoff for SpinBackoff {
+    #[inline]
+    fn backoff(&mut self) {
+        hint::spin_loop();
+    }
+}
+
+/// Spins for an exponentially growing number of spin-loop hints.
+///
+/// The `n`-th failure spins `2^min(n - 1, max_shift)` times, so the delay
+/// doubles on each failure until it reaches `2^max_shift` hints.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::{AtomicF64, ExponentialBackoff};
+///
+/// let atomic = AtomicF64::new(1.0);
+/// let backoff = ExponentialBackoff::with_max_shift(4);
+/// atomic.fetch_update_with_backoff(backoff, |x| x + 1.0);
+/// assert_eq!(atomic.load(), 2.0);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExponentialBackoff {
+    shift: u32,
+    max_shift: u32,
+}
+
+impl ExponentialBackoff {
+    /// The default maximum shift, i.e. at most 64 spin-loop hints per
+    /// failure.
+    pub const DEFAULT_MAX_SHIFT: u32 = 6;
+
+    /// Creates a new exponential backoff with the default maximum shift.
+    ///
+    /// # Returns
+    ///
+    /// A new `ExponentialBackoff` instance.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_max_shift(Self::DEFAULT_MAX_SHIFT)
+    }
+
+    /// Creates a new exponential backoff with the given maximum shift.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_shift` - The base-2 logarithm of the maximum number of
+    ///   spin-loop hints per failure. Values above 16 are clamped to 16.
+    ///
+    /// # Returns
+    ///
+    /// A new `ExponentialBackoff` instance.
+    #[inline]
+    pub const fn with_max_shift(max_shift: u32) -> Self {
+        let max_shift = if max_shift > 16 { 16 } else { max_shift };
+        Self {
+            shift: 0,
+            max_shift,
+        }
+    }
+}
+
+impl Default for ExponentialBackoff {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Backoff for ExponentialBackoff {
+    #[inline]
+    fn backoff(&mut self) {
+        for _ in 0..(1u32 << self.shift) {
+            hint::spin_loop();
+        }
+        if self.shift < self.max_shift {
+            self.shift += 1;
+        }
+    }
+}
+
+/// Spins exponentially for the first failures, then yields the thread.
+///
+/// After `spin_limit` failures, each further failure calls
+/// `std::thread::yield_now()`, which lets other threads, including the one
+/// holding the contended cache line, make progress on oversubscribed
+/// machines.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::{AtomicRef, YieldBackoff};
+/// use std::sync::Arc;
+///
+/// let atomic = AtomicRef::new(Arc::new(1));
+/// let backoff = YieldBackoff::new(10);
+/// atomic.fetch_update_with_backoff(backoff, |x| Arc::new(**x + 1));
+/// assert_eq!(*atomic.load(), 2);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YieldBackoff {
+    spin: ExponentialBackoff,
+    failures: u32,
+    spin_limit: u32,
+}
+
+impl YieldBackoff {
+    /// The default number of failures before yielding.
+    pub const DEFAULT_SPIN_LIMIT: u32 = 6;
+
+    /// Creates a new backoff that yields after `spin_limit` failures.
+    ///
+    /// # Parameters
+    ///
+    /// * `spin_limit` - The number of failures handled by spinning before
+    ///   the thread starts yielding.
+    ///
+    /// # Returns
+    ///
+    /// A new `YieldBackoff` instance.
+    #[inline]
+    pub const fn new(spin_limit: u32) -> Self {
+        Self {
+            spin: ExponentialBackoff::new(),
+            failures: 0,
+            spin_limit,
+        }
+    }
+}
+
+impl Default for YieldBackoff {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SPIN_LIMIT)
+    }
+}
+
+impl Backoff for YieldBackoff {
+    #[inline]
+    fn backoff(&mut self) {
+        if self.failures < self.spin_limit {
+            self.failures += 1;
+            self.spin.backoff();
+        } else {
+            thread::yield_now();
+        }
+    }
+}
+
+/// A built-in backoff strategy selected by value.
+///
+/// Used as the [`BACKOFF`](crate::atomic::OrderingPolicy::BACKOFF) constant
+/// of an ordering policy, which selects the strategy of the CAS loops that
+/// take no backoff parameter. Custom [`Backoff`] implementations, as well
+/// as [`NoBackoff`] and [`SpinBackoff`], can only be passed to the
+/// `*_with_backoff` methods; a single spin-loop hint per failure is also
+/// `Exponential(ExponentialBackoff::with_max_shift(0))`.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::{
+///     AtomicF64, BackoffStrategy, OrderingPolicy, YieldBackoff,
+/// };
+///
+/// /// Yields early, for machines running more threads than cores.
+/// struct Oversubscribed;
+///
+/// impl OrderingPolicy for Oversubscribed {
+///     const BACKOFF: BackoffStrategy =
+///         BackoffStrategy::Yield(YieldBackoff::new(2));
+/// }
+///
+/// let total = AtomicF64::<Oversubscribed>::with_policy(0.0);
+/// total.fetch_add(1.5);
+/// assert_eq!(total.load(), 1.5);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BackoffStrategy {
+    /// Spins exponentially, see [`ExponentialBackoff`].
+    Exponential(ExponentialBackoff),
+
+    /// Spins, then yields the thread, see [`YieldBackoff`].
+    Yield(YieldBackoff),
+}
+
+impl BackoffStrategy {
+    /// The default strategy, an [`ExponentialBackoff`] with the default
+    /// maximum shift.
+    pub const DEFAULT: Self = Self::Exponential(ExponentialBackoff::new());
+}
+
+impl Default for BackoffStrategy {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Backoff for BackoffStrategy {
+    #[inline]
+    fn backoff(&mut self) {
+        match self {
+            Self::Exponential(backoff) => backoff.backoff(),
+            Self::Yield(backoff) => backoff.backoff(),
+        }
+    }
+}
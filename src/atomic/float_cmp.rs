@@ -0,0 +1,159 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Floating-Point Comparisons
+//!
+//! Provides the value comparisons and the NaN-aware max/min selection shared
+//! by `AtomicF32`, `AtomicF64`, `AtomicF16` and `AtomicBf16`. The 16-bit
+//! types compute in `f32` and only supply their own bit patterns for the
+//! ULP distance.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::cmp;
+use std::mem;
+use std::ops::Sub;
+
+use crate::atomic::nan_policy::NanPolicy;
+
+/// The primitive floating-point types the helpers operate on.
+pub(crate) trait Float: Copy + PartialOrd + Sub<Output = Self> {
+    /// The width of the bit pattern.
+    const BITS: u32;
+
+    /// Returns whether the value is NaN.
+    fn is_nan(self) -> bool;
+
+    /// Returns the absolute value.
+    fn abs(self) -> Self;
+
+    /// Compares two values with the IEEE 754 `totalOrder` predicate.
+    fn total_cmp(&self, other: &Self) -> cmp::Ordering;
+
+    /// Returns the bit pattern, zero-extended to 64 bits.
+    fn to_bits_u64(self) -> u64;
+}
+
+macro_rules! impl_float {
+    ($float:ty) => {
+        impl Float for $float {
+            const BITS: u32 = mem::size_of::<$float>() as u32 * 8;
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                <$float>::is_nan(self)
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                <$float>::abs(self)
+            }
+
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> cmp::Ordering {
+                <$float>::total_cmp(self, other)
+            }
+
+            #[inline]
+            fn to_bits_u64(self) -> u64 {
+                u64::from(<$float>::to_bits(self))
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+/// Returns whether two values are equal as numbers, treating all NaNs as
+/// equal to each other.
+#[inline]
+pub(crate) fn value_eq<F: Float>(a: F, b: F) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+/// Returns the maximum of `current` and `value` under `policy`, or `None` if
+/// the policy rejects a NaN operand.
+#[inline]
+pub(crate) fn select_max<F: Float>(current: F, value: F, policy: NanPolicy) -> Option<F> {
+    if current.is_nan() || value.is_nan() {
+        return select_nan(current, value, policy);
+    }
+    // total_cmp orders -0.0 before +0.0 and agrees with < otherwise
+    if value.total_cmp(&current).is_gt() {
+        Some(value)
+    } else {
+        Some(current)
+    }
+}
+
+/// Returns the minimum of `current` and `value` under `policy`, or `None` if
+/// the policy rejects a NaN operand.
+#[inline]
+pub(crate) fn select_min<F: Float>(current: F, value: F, policy: NanPolicy) -> Option<F> {
+    if current.is_nan() || value.is_nan() {
+        return select_nan(current, value, policy);
+    }
+    if value.total_cmp(&current).is_lt() {
+        Some(value)
+    } else {
+        Some(current)
+    }
+}
+
+/// Returns the result of a max/min operation with at least one NaN operand.
+#[inline]
+fn select_nan<F: Float>(current: F, value: F, policy: NanPolicy) -> Option<F> {
+    match policy {
+        NanPolicy::Propagate if current.is_nan() => Some(current),
+        NanPolicy::Propagate => Some(value),
+        NanPolicy::Ignore if value.is_nan() => Some(current),
+        NanPolicy::Ignore => Some(value),
+        NanPolicy::Reject => None,
+    }
+}
+
+/// Returns whether `a` is within `tolerance` of `b`.
+#[inline]
+pub(crate) fn approx_eq<F: Float>(a: F, b: F, tolerance: F) -> bool {
+    a == b || (a - b).abs() <= tolerance
+}
+
+/// Returns whether `a` is within `max_ulps` units in the last place of `b`.
+#[inline]
+pub(crate) fn ulps_eq<F: Float>(a: F, b: F, max_ulps: u64) -> bool {
+    if a.is_nan() || b.is_nan() {
+        return false;
+    }
+    ulps_distance(a.to_bits_u64(), b.to_bits_u64(), F::BITS) <= max_ulps
+}
+
+/// Returns the number of representable values between two non-NaN values,
+/// given by their `bits`-wide bit patterns. Both zeros are the same value.
+#[inline]
+pub(crate) fn ulps_distance(a: u64, b: u64, bits: u32) -> u64 {
+    ordered_bits(a, bits).abs_diff(ordered_bits(b, bits))
+}
+
+/// Maps the bit pattern of a non-NaN value to an integer with the same
+/// order, in which adjacent representable values differ by one and both
+/// zeros map to 0.
+#[inline]
+fn ordered_bits(pattern: u64, bits: u32) -> i64 {
+    let sign = 1u64 << (bits - 1);
+    // Without the sign bit, the magnitude fits in an i64 for every width
+    let magnitude = (pattern & (sign - 1)) as i64;
+    if pattern & sign != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
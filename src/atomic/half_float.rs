@@ -0,0 +1,132 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Half-Precision Conversions
+//!
+//! Provides the conversions between `f32` and the bit patterns of the
+//! IEEE 754 binary16 ("half precision") and bfloat16 formats used by
+//! `AtomicF16` and `AtomicBf16`.
+//!
+//! Conversions from `f32` round to the nearest representable value, ties to
+//! even. Conversions to `f32` are exact. NaN payloads survive a round trip
+//! from the 16-bit format to `f32` and back, so that CAS loops comparing bit
+//! patterns terminate for every stored value.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+/// Sign bit of a binary16 value.
+const F16_SIGN: u16 = 0x8000;
+
+/// Exponent bits of a binary16 value, i.e. the bits of infinity.
+const F16_EXP: u16 = 0x7c00;
+
+/// Quiet bit of a binary16 NaN.
+const F16_QUIET: u16 = 0x0200;
+
+/// Quiet bit of a bfloat16 NaN.
+const BF16_QUIET: u16 = 0x0040;
+
+/// Rounds `value` to the nearest binary16 value and returns its bits.
+///
+/// Values too large for binary16 become infinities, values too small become
+/// subnormals or zeros.
+#[inline]
+pub(crate) fn f32_to_f16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) as u16) & F16_SIGN;
+    let exp = ((bits >> 23) & 0xff) as i32;
+    let man = bits & 0x007f_ffff;
+
+    if exp == 0xff {
+        if man == 0 {
+            return sign | F16_EXP;
+        }
+        // Keep the upper payload bits, and make sure the result is a NaN
+        let payload = (man >> 13) as u16;
+        let payload = if payload == 0 { F16_QUIET } else { payload };
+        return sign | F16_EXP | payload;
+    }
+
+    // Unbiased exponent rebiased for binary16
+    let exp = exp - 127 + 15;
+    if exp >= 0x1f {
+        return sign | F16_EXP;
+    }
+    if exp <= 0 {
+        // Below 2^-25 even the smallest subnormal is more than twice away
+        if exp < -10 {
+            return sign;
+        }
+        let man = man | 0x0080_0000;
+        let shift = (14 - exp) as u32;
+        let half = man >> shift;
+        let rest = man & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        // A carry into the exponent field yields the smallest normal value
+        return sign | round_half_even(half, rest, halfway) as u16;
+    }
+
+    let half = ((exp as u32) << 10) | (man >> 13);
+    // A carry into the exponent field yields the next binade or infinity
+    sign | round_half_even(half, man & 0x1fff, 0x1000) as u16
+}
+
+/// Converts the bits of a binary16 value to `f32`.
+#[inline]
+pub(crate) fn f16_bits_to_f32(bits: u16) -> f32 {
+    let sign = u32::from(bits & F16_SIGN) << 16;
+    let exp = u32::from((bits & F16_EXP) >> 10);
+    let man = u32::from(bits & 0x03ff);
+
+    if exp == 0x1f {
+        return f32::from_bits(sign | 0x7f80_0000 | (man << 13));
+    }
+    if exp == 0 {
+        // Zero or subnormal, i.e. `man * 2^-24`, which is exact in f32
+        let magnitude = man as f32 / 16_777_216.0;
+        return if sign == 0 { magnitude } else { -magnitude };
+    }
+    f32::from_bits(sign | ((exp + 127 - 15) << 23) | (man << 13))
+}
+
+/// Rounds `value` to the nearest bfloat16 value and returns its bits.
+#[inline]
+pub(crate) fn f32_to_bf16_bits(value: f32) -> u16 {
+    let bits = value.to_bits();
+    if value.is_nan() {
+        // Keep the upper payload bits, and make sure the result is a NaN
+        let truncated = (bits >> 16) as u16;
+        return if truncated & 0x007f == 0 {
+            truncated | BF16_QUIET
+        } else {
+            truncated
+        };
+    }
+    // Finite values cannot overflow here: the largest one becomes infinity
+    (round_half_even(bits >> 16, bits & 0xffff, 0x8000)) as u16
+}
+
+/// Converts the bits of a bfloat16 value to `f32`.
+#[inline]
+pub(crate) fn bf16_bits_to_f32(bits: u16) -> f32 {
+    f32::from_bits(u32::from(bits) << 16)
+}
+
+/// Rounds a truncated value given the discarded bits `rest`, where
+/// `halfway` is the value of `rest` exactly between two results.
+#[inline]
+fn round_half_even(truncated: u32, rest: u32, halfway: u32) -> u32 {
+    if rest > halfway || (rest == halfway && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
@@ -16,6 +16,27 @@
 //! # Features
 //!
 //! - Automatic memory ordering selection for common use cases
+//! - Type-level ordering policies (`AtomicI64<SeqCst>`, ...)
+//! - Pluggable backoff strategies for CAS loops
+//! - Floating-point max/min with configurable NaN semantics
+//! - Striped adders (`AtomicAdderI64`, ...) for heavily contended counters
+//! - Compensated floating-point summation (`AtomicCompensatedF64`)
+//! - Half-precision and bfloat16 floats (`AtomicF16`, `AtomicBf16`)
+//! - 128-bit integers (`AtomicI128`, `AtomicU128`), lock-free where the
+//!   processor supports a 128-bit compare-and-exchange
+//! - Non-zero integers (`AtomicNonZeroU32`, `AtomicOptionNonZeroI64`, ...)
+//!   that never hold zero, or use it to represent `None`
+//! - Fieldless enums (`AtomicEnum<E>`), e.g. the states of a state machine,
+//!   with the `atomic_enum!` macro implementing `AtomicEnumValue`
+//! - Any `Copy` value without padding (`AtomicCell<T>`), lock-free up to 8
+//!   bytes and guarded by striped locks beyond, with the `no_uninit!` macro
+//!   implementing `NoUninit` for structs
+//! - Packed bit-field structs over `AtomicU32`/`AtomicU64`, generated by the
+//!   `atomic_bitfield!` macro
+//! - Fixed-size bit sets (`AtomicBitSet<N>`) with lock-free slot allocation
+//! - Nullable references (`AtomicOptionRef<T>`) holding an `Option<Arc<T>>`
+//! - Weak references (`AtomicWeak<T>`) that do not keep their target alive
+//! - Owned slots (`AtomicBox<T>`) moving a `Box<T>` between threads
 //! - Rich set of high-level operations (increment, decrement, functional
 //!   updates, etc.)
 //! - Zero-cost abstraction with inline methods
@@ -25,39 +46,183 @@
 //!
 //! Haixing Hu
 
+#[macro_use]
+mod atomic_adder_macro;
+#[macro_use]
+mod atomic_half_float_macro;
 #[macro_use]
 mod atomic_integer_macro;
+#[macro_use]
+mod atomic_non_zero_macro;
 
+mod atomic_adder_f64;
+mod atomic_adder_i64;
+mod atomic_adder_u64;
+mod atomic_bf16;
+mod atomic_bit_set;
+mod atomic_bitfield;
 mod atomic_bool;
+mod atomic_box;
+mod atomic_cell;
+mod atomic_compensated_f64;
+mod atomic_enum;
+mod atomic_f16;
 mod atomic_f32;
 mod atomic_f64;
+mod atomic_i128;
 mod atomic_i16;
 mod atomic_i32;
 mod atomic_i64;
 mod atomic_i8;
 mod atomic_isize;
+mod atomic_non_zero_i128;
+mod atomic_non_zero_i16;
+mod atomic_non_zero_i32;
+mod atomic_non_zero_i64;
+mod atomic_non_zero_i8;
+mod atomic_non_zero_isize;
+mod atomic_non_zero_u128;
+mod atomic_non_zero_u16;
+mod atomic_non_zero_u32;
+mod atomic_non_zero_u64;
+mod atomic_non_zero_u8;
+mod atomic_non_zero_usize;
+mod atomic_option_ref;
 mod atomic_ref;
+mod atomic_u128;
 mod atomic_u16;
 mod atomic_u32;
 mod atomic_u64;
 mod atomic_u8;
 mod atomic_usize;
+mod atomic_weak;
+mod backoff;
+mod float_cmp;
+mod half_float;
+mod nan_policy;
+mod ordering;
+mod ordering_policy;
+mod raw_atomic_128;
+mod reclaim;
+mod ref_slot;
+mod striped;
 mod traits;
 
+pub use atomic_adder_f64::AtomicAdderF64;
+pub use atomic_adder_i64::AtomicAdderI64;
+pub use atomic_adder_u64::AtomicAdderU64;
+pub use atomic_bf16::AtomicBf16;
+pub use atomic_bit_set::{
+    AtomicBitSet,
+    BitSetIter,
+};
+pub use atomic_bitfield::{
+    BitField,
+    BitFieldLayout,
+    BitFieldRepr,
+};
 pub use atomic_bool::AtomicBool;
+pub use atomic_box::AtomicBox;
+pub use atomic_cell::{
+    AtomicCell,
+    NoUninit,
+};
+pub use atomic_compensated_f64::AtomicCompensatedF64;
+pub use atomic_enum::{
+    AtomicEnum,
+    AtomicEnumValue,
+    EnumRepr,
+};
+pub use atomic_f16::AtomicF16;
 pub use atomic_f32::AtomicF32;
 pub use atomic_f64::AtomicF64;
+pub use atomic_i128::AtomicI128;
 pub use atomic_i16::AtomicI16;
 pub use atomic_i32::AtomicI32;
 pub use atomic_i64::AtomicI64;
 pub use atomic_i8::AtomicI8;
 pub use atomic_isize::AtomicIsize;
-pub use atomic_ref::AtomicRef;
+pub use atomic_non_zero_i128::{
+    AtomicNonZeroI128,
+    AtomicOptionNonZeroI128,
+};
+pub use atomic_non_zero_i16::{
+    AtomicNonZeroI16,
+    AtomicOptionNonZeroI16,
+};
+pub use atomic_non_zero_i32::{
+    AtomicNonZeroI32,
+    AtomicOptionNonZeroI32,
+};
+pub use atomic_non_zero_i64::{
+    AtomicNonZeroI64,
+    AtomicOptionNonZeroI64,
+};
+pub use atomic_non_zero_i8::{
+    AtomicNonZeroI8,
+    AtomicOptionNonZeroI8,
+};
+pub use atomic_non_zero_isize::{
+    AtomicNonZeroIsize,
+    AtomicOptionNonZeroIsize,
+};
+pub use atomic_non_zero_u128::{
+    AtomicNonZeroU128,
+    AtomicOptionNonZeroU128,
+};
+pub use atomic_non_zero_u16::{
+    AtomicNonZeroU16,
+    AtomicOptionNonZeroU16,
+};
+pub use atomic_non_zero_u32::{
+    AtomicNonZeroU32,
+    AtomicOptionNonZeroU32,
+};
+pub use atomic_non_zero_u64::{
+    AtomicNonZeroU64,
+    AtomicOptionNonZeroU64,
+};
+pub use atomic_non_zero_u8::{
+    AtomicNonZeroU8,
+    AtomicOptionNonZeroU8,
+};
+pub use atomic_non_zero_usize::{
+    AtomicNonZeroUsize,
+    AtomicOptionNonZeroUsize,
+};
+pub use atomic_option_ref::AtomicOptionRef;
+pub use atomic_ref::{
+    AtomicRef,
+    RcuGuard,
+};
+pub use atomic_u128::AtomicU128;
 pub use atomic_u16::AtomicU16;
 pub use atomic_u32::AtomicU32;
 pub use atomic_u64::AtomicU64;
 pub use atomic_u8::AtomicU8;
 pub use atomic_usize::AtomicUsize;
+pub use atomic_weak::AtomicWeak;
+pub use backoff::{
+    Backoff,
+    BackoffStrategy,
+    ExponentialBackoff,
+    NoBackoff,
+    SpinBackoff,
+    YieldBackoff,
+};
+pub use nan_policy::NanPolicy;
+pub use ordering_policy::{
+    AcqRel,
+    DefaultOrdering,
+    OrderingPolicy,
+    PublishingPolicy,
+    RelaxedCounter,
+    SeqCst,
+};
+pub use raw_atomic_128::{
+    RawAtomicI128,
+    RawAtomicU128,
+};
 pub use traits::{
     Atomic,
     AtomicNumber,
@@ -0,0 +1,68 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # NaN Policies
+//!
+//! Provides the policy that decides how the floating-point max/min
+//! operations treat NaN operands.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+/// Decides how `fetch_max_with_nan_policy` and `fetch_min_with_nan_policy`
+/// of [`AtomicF32`](crate::atomic::AtomicF32) and
+/// [`AtomicF64`](crate::atomic::AtomicF64) treat NaN.
+///
+/// A NaN may be either the argument of the operation or the value already
+/// stored in the atomic. Regardless of the policy, `-0.0` is considered
+/// smaller than `+0.0`.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::{AtomicF64, NanPolicy};
+///
+/// let peak = AtomicF64::new(1.0);
+///
+/// // A NaN sample is ignored by the default policy
+/// assert_eq!(peak.fetch_max(f64::NAN), 1.0);
+/// assert_eq!(peak.load(), 1.0);
+///
+/// // ... and refused by `Reject`
+/// let result = peak.fetch_max_with_nan_policy(f64::NAN, NanPolicy::Reject);
+/// assert_eq!(result, Err(1.0));
+///
+/// // ... but poisons the peak with `Propagate`
+/// let result = peak.fetch_max_with_nan_policy(f64::NAN, NanPolicy::Propagate);
+/// assert_eq!(result, Ok(1.0));
+/// assert!(peak.load().is_nan());
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NanPolicy {
+    /// A NaN operand makes the result NaN, as the IEEE 754-2019 `maximum`
+    /// and `minimum` operations do. Once the stored value is NaN, it stays
+    /// NaN.
+    Propagate,
+
+    /// A NaN operand is ignored and the other operand is the result, as the
+    /// IEEE 754-2008 `maxNum` and `minNum` operations and `f64::max` do. A
+    /// stored NaN is therefore replaced by the first non-NaN argument.
+    ///
+    /// This is the policy of `fetch_max` and `fetch_min`.
+    #[default]
+    Ignore,
+
+    /// A NaN operand makes the operation fail without modifying the value.
+    Reject,
+}
@@ -0,0 +1,134 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Memory Ordering Validation
+//!
+//! Provides helpers that validate caller-supplied memory orderings for the
+//! `*_with_ordering` methods of the atomic types.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::sync::atomic::Ordering;
+
+/// Returns `true` if `ordering` can be used for a load.
+#[inline]
+pub(crate) const fn is_load_ordering(ordering: Ordering) -> bool {
+    matches!(
+        ordering,
+        Ordering::Relaxed | Ordering::Acquire | Ordering::SeqCst
+    )
+}
+
+/// Returns `true` if `ordering` can be used for a store.
+#[inline]
+pub(crate) const fn is_store_ordering(ordering: Ordering) -> bool {
+    matches!(
+        ordering,
+        Ordering::Relaxed | Ordering::Release | Ordering::SeqCst
+    )
+}
+
+/// Validates the ordering of a load in debug builds.
+///
+/// # Panics
+///
+/// In debug builds, panics if `ordering` is `Release` or `AcqRel`.
+#[inline]
+#[track_caller]
+pub(crate) fn debug_validate_load(ordering: Ordering) {
+    debug_assert!(
+        is_load_ordering(ordering),
+        "invalid memory ordering for a load: {:?}",
+        ordering
+    );
+}
+
+/// Validates the ordering of a store in debug builds.
+///
+/// # Panics
+///
+/// In debug builds, panics if `ordering` is `Acquire` or `AcqRel`.
+#[inline]
+#[track_caller]
+pub(crate) fn debug_validate_store(ordering: Ordering) {
+    debug_assert!(
+        is_store_ordering(ordering),
+        "invalid memory ordering for a store: {:?}",
+        ordering
+    );
+}
+
+/// Validates the orderings of a compare-and-exchange in debug builds.
+///
+/// The success ordering may be any ordering; the failure ordering is used
+/// for a plain load and therefore must be a valid load ordering.
+///
+/// # Panics
+///
+/// In debug builds, panics if `failure` is `Release` or `AcqRel`.
+#[inline]
+#[track_caller]
+pub(crate) fn debug_validate_cas(success: Ordering, failure: Ordering) {
+    debug_assert!(
+        is_load_ordering(failure),
+        "invalid failure ordering for a compare-and-exchange: {:?} \
+         (success ordering: {:?})",
+        failure,
+        success
+    );
+}
+
+/// Validates that `ordering` acquires, as required when the loaded value is
+/// dereferenced afterwards.
+///
+/// # Panics
+///
+/// Panics if `ordering` is neither `Acquire` nor `SeqCst`.
+#[inline]
+#[track_caller]
+pub(crate) fn assert_acquire(ordering: Ordering) {
+    assert!(
+        matches!(ordering, Ordering::Acquire | Ordering::SeqCst),
+        "memory ordering must be Acquire or SeqCst: {:?}",
+        ordering
+    );
+}
+
+/// Validates that `ordering` releases, as required when the stored value is
+/// dereferenced by other threads.
+///
+/// # Panics
+///
+/// Panics if `ordering` is neither `Release` nor `SeqCst`.
+#[inline]
+#[track_caller]
+pub(crate) fn assert_release(ordering: Ordering) {
+    assert!(
+        matches!(ordering, Ordering::Release | Ordering::SeqCst),
+        "memory ordering must be Release or SeqCst: {:?}",
+        ordering
+    );
+}
+
+/// Validates that a read-modify-write `ordering` both acquires and releases.
+///
+/// # Panics
+///
+/// Panics if `ordering` is neither `AcqRel` nor `SeqCst`.
+#[inline]
+#[track_caller]
+pub(crate) fn assert_acq_rel(ordering: Ordering) {
+    assert!(
+        matches!(ordering, Ordering::AcqRel | Ordering::SeqCst),
+        "memory ordering must be AcqRel or SeqCst: {:?}",
+        ordering
+    );
+}
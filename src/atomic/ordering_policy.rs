@@ -0,0 +1,205 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Memory Ordering Policies
+//!
+//! Provides type-level policies that select the default memory orderings of
+//! an atomic instance, e.g. `AtomicI64<SeqCst>` or
+//! `AtomicU64<RelaxedCounter>`.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::sync::atomic::Ordering;
+
+use crate::atomic::backoff::BackoffStrategy;
+
+/// Maps each class of atomic operation to the memory ordering used by the
+/// convenience methods of an atomic type.
+///
+/// Every atomic type in this crate takes a policy as its last type
+/// parameter, defaulting to [`DefaultOrdering`]. The `*_with_ordering`
+/// methods are not affected by the policy.
+///
+/// All associated constants have defaults matching [`DefaultOrdering`], so
+/// a custom policy only needs to override the classes it changes.
+///
+/// # Operation Classes
+///
+/// | Constant      | Operations                                          |
+/// |---------------|-----------------------------------------------------|
+/// | `LOAD`        | `load`                                              |
+/// | `STORE`       | `store`                                             |
+/// | `RMW`         | `swap`, `fetch_set`, `fetch_clear`                  |
+/// | `CAS_SUCCESS` | successful CAS, including CAS loops                 |
+/// | `CAS_FAILURE` | failed CAS and the initial load of CAS loops        |
+/// | `ARITHMETIC`  | integer `fetch_inc`, `fetch_dec`, `fetch_add`, ...  |
+/// | `BIT_OP`      | `fetch_and`, `fetch_or`, `fetch_xor`, `fetch_not`   |
+/// | `MAX_MIN`     | integer `fetch_max`, `fetch_min`                    |
+///
+/// Floating-point arithmetic and max/min, and `fetch_mul`/`fetch_div` on
+/// integers are implemented with CAS loops and therefore use `CAS_SUCCESS`
+/// and `CAS_FAILURE`. After a failed attempt, these loops apply the
+/// [`BackoffStrategy`] given by `BACKOFF`.
+///
+/// # Validity
+///
+/// `LOAD` and `CAS_FAILURE` must be `Relaxed`, `Acquire` or `SeqCst`;
+/// `STORE` must be `Relaxed`, `Release` or `SeqCst`. Invalid orderings are
+/// rejected at the first use in debug builds, and by the standard library
+/// at runtime otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use prism3_rust_concurrent::atomic::{AtomicU64, OrderingPolicy};
+/// use std::sync::atomic::Ordering;
+///
+/// /// Counters whose increments publish the data written before them.
+/// struct PublishingCounter;
+///
+/// impl OrderingPolicy for PublishingCounter {
+///     const ARITHMETIC: Ordering = Ordering::AcqRel;
+/// }
+///
+/// let counter = AtomicU64::<PublishingCounter>::with_policy(0);
+/// counter.fetch_inc();
+/// assert_eq!(counter.load(), 1);
+/// ```
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait OrderingPolicy {
+    /// Ordering of plain loads.
+    const LOAD: Ordering = Ordering::Acquire;
+
+    /// Ordering of plain stores.
+    const STORE: Ordering = Ordering::Release;
+
+    /// Ordering of unconditional read-modify-write operations.
+    const RMW: Ordering = Ordering::AcqRel;
+
+    /// Ordering of a successful compare-and-exchange.
+    const CAS_SUCCESS: Ordering = Ordering::AcqRel;
+
+    /// Ordering of a failed compare-and-exchange.
+    const CAS_FAILURE: Ordering = Ordering::Acquire;
+
+    /// Ordering of integer counter arithmetic.
+    const ARITHMETIC: Ordering = Ordering::Relaxed;
+
+    /// Ordering of bitwise and logical operations.
+    const BIT_OP: Ordering = Ordering::AcqRel;
+
+    /// Ordering of max/min operations.
+    const MAX_MIN: Ordering = Ordering::AcqRel;
+
+    /// Backoff strategy of the CAS loops without a backoff parameter.
+    const BACKOFF: BackoffStrategy = BackoffStrategy::DEFAULT;
+}
+
+/// Policies whose orderings safely publish the data behind a pointer.
+///
+/// Required by the types handing pointed-to values between threads:
+/// [`AtomicRef`](crate::atomic::AtomicRef),
+/// [`AtomicOptionRef`](crate::atomic::AtomicOptionRef),
+/// [`AtomicWeak`](crate::atomic::AtomicWeak) and
+/// [`AtomicBox`](crate::atomic::AtomicBox). Implementors must use `Acquire`
+/// or `SeqCst` for `LOAD` and `CAS_FAILURE`, `Release` or `SeqCst` for
+/// `STORE`, and `AcqRel` or `SeqCst` for `RMW` and `CAS_SUCCESS`. Every
+/// operation of these types checks the orderings it uses and panics on a
+/// weaker one, also in release builds, so that an invalid policy cannot
+/// cause a data race.
+///
+/// # Author
+///
+/// Haixing Hu
+pub trait PublishingPolicy: OrderingPolicy {}
+
+/// The default ordering policy.
+///
+/// Uses `Acquire` loads, `Release` stores, `AcqRel` read-modify-write,
+/// bit and max/min operations, `AcqRel`/`Acquire` CAS, and `Relaxed`
+/// counter arithmetic, as described in the design document.
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DefaultOrdering;
+
+impl OrderingPolicy for DefaultOrdering {}
+
+impl PublishingPolicy for DefaultOrdering {}
+
+/// Ordering policy that synchronizes every operation.
+///
+/// Same as [`DefaultOrdering`] except that counter arithmetic uses
+/// `AcqRel`, so increments can be used to publish data.
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AcqRel;
+
+impl OrderingPolicy for AcqRel {
+    const ARITHMETIC: Ordering = Ordering::AcqRel;
+}
+
+impl PublishingPolicy for AcqRel {}
+
+/// Ordering policy that uses `SeqCst` for every operation.
+///
+/// Matches the semantics of Java `volatile` fields and of
+/// `java.util.concurrent.atomic`, which makes it a convenient choice when
+/// porting Java code.
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SeqCst;
+
+impl OrderingPolicy for SeqCst {
+    const LOAD: Ordering = Ordering::SeqCst;
+    const STORE: Ordering = Ordering::SeqCst;
+    const RMW: Ordering = Ordering::SeqCst;
+    const CAS_SUCCESS: Ordering = Ordering::SeqCst;
+    const CAS_FAILURE: Ordering = Ordering::SeqCst;
+    const ARITHMETIC: Ordering = Ordering::SeqCst;
+    const BIT_OP: Ordering = Ordering::SeqCst;
+    const MAX_MIN: Ordering = Ordering::SeqCst;
+}
+
+impl PublishingPolicy for SeqCst {}
+
+/// Ordering policy that uses `Relaxed` for every operation.
+///
+/// Suitable for statistics counters and other values that do not guard any
+/// other data. Never use such an instance to signal that data is ready.
+///
+/// # Author
+///
+/// Haixing Hu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RelaxedCounter;
+
+impl OrderingPolicy for RelaxedCounter {
+    const LOAD: Ordering = Ordering::Relaxed;
+    const STORE: Ordering = Ordering::Relaxed;
+    const RMW: Ordering = Ordering::Relaxed;
+    const CAS_SUCCESS: Ordering = Ordering::Relaxed;
+    const CAS_FAILURE: Ordering = Ordering::Relaxed;
+    const ARITHMETIC: Ordering = Ordering::Relaxed;
+    const BIT_OP: Ordering = Ordering::Relaxed;
+    const MAX_MIN: Ordering = Ordering::Relaxed;
+}
@@ -0,0 +1,675 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Raw 128-bit Atomic Integers
+//!
+//! Provides `RawAtomicU128` and `RawAtomicI128`, 128-bit atomic integers
+//! with the API of the standard library atomic integers, which are not
+//! available on stable Rust. They are the underlying types of
+//! [`AtomicU128`](crate::atomic::AtomicU128) and
+//! [`AtomicI128`](crate::atomic::AtomicI128).
+//!
+//! # Implementation
+//!
+//! Every operation is built on a 128-bit compare-and-exchange, which is
+//! lock-free on the following targets:
+//!
+//! - `x86_64` processors supporting the `cmpxchg16b` instruction, which is
+//!   detected at runtime.
+//! - Little-endian `aarch64`, using a loop of the `ldaxp`/`stlxp` exclusive
+//!   pair instructions, which every ARMv8-A processor supports. The `casp`
+//!   instruction of the LSE extension is not used: it would need runtime
+//!   detection as well, and only shortens the uncontended path.
+//!
+//! On other targets, every operation is serialized by one of a fixed set of
+//! spin locks, selected by the address of the value. `is_lock_free()`
+//! reports which implementation is active.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::sync::atomic::Ordering;
+
+use crate::atomic::striped::StripedLockGuard;
+
+/// Returns whether the 128-bit operations are lock-free on this machine.
+#[inline]
+fn is_lock_free() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        std::is_x86_feature_detected!("cmpxchg16b")
+    }
+    #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+    {
+        true
+    }
+    #[cfg(not(any(
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_endian = "little")
+    )))]
+    {
+        false
+    }
+}
+
+/// Compares and exchanges the value at `dst` with `lock cmpxchg16b`.
+///
+/// # Safety
+///
+/// `dst` must be valid, aligned to 16 bytes, and only accessed atomically,
+/// and the processor must support `cmpxchg16b`.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+unsafe fn cmpxchg16b(dst: *mut u128, current: u128, new: u128) -> Result<u128, u128> {
+    let prev_lo: u64;
+    let prev_hi: u64;
+    let success: u8;
+    // `rbx` is reserved by LLVM, so the low half of `new` is swapped into it
+    // for the instruction and the original `rbx` is restored afterwards.
+    // SAFETY: guaranteed by the caller.
+    unsafe {
+        std::arch::asm!(
+            "xchg {new_lo}, rbx",
+            "lock cmpxchg16b xmmword ptr [{dst}]",
+            "sete {success}",
+            "mov rbx, {new_lo}",
+            dst = in(reg) dst,
+            new_lo = inout(reg) new as u64 => _,
+            success = out(reg_byte) success,
+            in("rcx") (new >> 64) as u64,
+            inout("rax") current as u64 => prev_lo,
+            inout("rdx") (current >> 64) as u64 => prev_hi,
+            options(nostack),
+        );
+    }
+    let prev = u128::from(prev_lo) | (u128::from(prev_hi) << 64);
+    if success != 0 {
+        Ok(prev)
+    } else {
+        Err(prev)
+    }
+}
+
+/// Compares and exchanges the value at `dst` with a loop of `ldaxp` and
+/// `stlxp`.
+///
+/// An exclusive pair load is only single-copy atomic if the paired store
+/// succeeds, so the loaded value is also stored back when the comparison
+/// fails.
+///
+/// # Safety
+///
+/// `dst` must be valid, aligned to 16 bytes, and only accessed atomically.
+#[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+#[inline]
+unsafe fn ldaxp_stlxp(dst: *mut u128, current: u128, new: u128) -> Result<u128, u128> {
+    let prev_lo: u64;
+    let prev_hi: u64;
+    let failed: u32;
+    // SAFETY: guaranteed by the caller.
+    unsafe {
+        std::arch::asm!(
+            "2:",
+            "ldaxp {prev_lo}, {prev_hi}, [{dst}]",
+            "cmp {prev_lo}, {current_lo}",
+            "ccmp {prev_hi}, {current_hi}, #0, eq",
+            "b.ne 3f",
+            "stlxp {failed:w}, {new_lo}, {new_hi}, [{dst}]",
+            "cbnz {failed:w}, 2b",
+            "b 4f",
+            "3:",
+            "stlxp {failed:w}, {prev_lo}, {prev_hi}, [{dst}]",
+            "cbnz {failed:w}, 2b",
+            "mov {failed:w}, #1",
+            "4:",
+            dst = in(reg) dst,
+            current_lo = in(reg) current as u64,
+            current_hi = in(reg) (current >> 64) as u64,
+            new_lo = in(reg) new as u64,
+            new_hi = in(reg) (new >> 64) as u64,
+            prev_lo = out(reg) prev_lo,
+            prev_hi = out(reg) prev_hi,
+            failed = out(reg) failed,
+            options(nostack),
+        );
+    }
+    let prev = u128::from(prev_lo) | (u128::from(prev_hi) << 64);
+    if failed == 0 {
+        Ok(prev)
+    } else {
+        Err(prev)
+    }
+}
+
+/// Compares and exchanges the value at `dst`.
+///
+/// # Safety
+///
+/// `dst` must be valid, aligned to 16 bytes, and only accessed through the
+/// functions of this module.
+#[inline]
+unsafe fn compare_exchange(dst: *mut u128, current: u128, new: u128) -> Result<u128, u128> {
+    #[cfg(target_arch = "x86_64")]
+    if is_lock_free() {
+        // SAFETY: guaranteed by the caller, and `cmpxchg16b` is supported.
+        return unsafe { cmpxchg16b(dst, current, new) };
+    }
+    #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+    if is_lock_free() {
+        // SAFETY: guaranteed by the caller.
+        return unsafe { ldaxp_stlxp(dst, current, new) };
+    }
+    // SAFETY: guaranteed by the caller, and no lock-free operation is
+    // available.
+    unsafe { locked_compare_exchange(dst, current, new) }
+}
+
+/// Loads the value at `dst`.
+///
+/// # Safety
+///
+/// Same as `compare_exchange`.
+#[inline]
+unsafe fn load(dst: *mut u128) -> u128 {
+    if is_lock_free() {
+        // Exchanging 0 with 0 never changes the value.
+        // SAFETY: guaranteed by the caller.
+        return match unsafe { compare_exchange(dst, 0, 0) } {
+            Ok(value) | Err(value) => value,
+        };
+    }
+    // SAFETY: guaranteed by the caller, and no lock-free operation is
+    // available.
+    unsafe { locked_load(dst) }
+}
+
+/// Replaces the value at `dst` with `f(value)` and returns the previous
+/// value. `f` may be called several times and must not block.
+///
+/// # Safety
+///
+/// Same as `compare_exchange`.
+#[inline]
+unsafe fn update<F>(dst: *mut u128, mut f: F) -> u128
+where
+    F: FnMut(u128) -> u128,
+{
+    if is_lock_free() {
+        let mut current = 0;
+        loop {
+            // SAFETY: guaranteed by the caller.
+            match unsafe { compare_exchange(dst, current, f(current)) } {
+                Ok(prev) => return prev,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+    // SAFETY: guaranteed by the caller, and no lock-free operation is
+    // available.
+    unsafe { locked_update(dst, f) }
+}
+
+/// Compares and exchanges the value at `dst` with the lock-based fallback.
+///
+/// # Safety
+///
+/// `dst` must be valid, aligned to 16 bytes, and only accessed through the
+/// `locked_*` functions of this module while this call runs.
+#[inline]
+pub(crate) unsafe fn locked_compare_exchange(
+    dst: *mut u128,
+    current: u128,
+    new: u128,
+) -> Result<u128, u128> {
+    let _guard = StripedLockGuard::acquire(dst as usize);
+    // SAFETY: guaranteed by the caller, and the lock is held.
+    unsafe {
+        let value = *dst;
+        if value == current {
+            *dst = new;
+            Ok(value)
+        } else {
+            Err(value)
+        }
+    }
+}
+
+/// Loads the value at `dst` with the lock-based fallback.
+///
+/// # Safety
+///
+/// Same as `locked_compare_exchange`.
+#[inline]
+pub(crate) unsafe fn locked_load(dst: *mut u128) -> u128 {
+    let _guard = StripedLockGuard::acquire(dst as usize);
+    // SAFETY: guaranteed by the caller, and the lock is held.
+    unsafe { *dst }
+}
+
+/// Replaces the value at `dst` with `f(value)` with the lock-based
+/// fallback, and returns the previous value. `f` must not block.
+///
+/// # Safety
+///
+/// Same as `locked_compare_exchange`.
+#[inline]
+pub(crate) unsafe fn locked_update<F>(dst: *mut u128, f: F) -> u128
+where
+    F: FnOnce(u128) -> u128,
+{
+    let _guard = StripedLockGuard::acquire(dst as usize);
+    // SAFETY: guaranteed by the caller, and the lock is held.
+    unsafe {
+        let prev = *dst;
+        *dst = f(prev);
+        prev
+    }
+}
+
+/// Panics if `ordering` cannot be used for a load, like the standard
+/// library atomics do.
+#[inline]
+#[track_caller]
+fn validate_load(ordering: Ordering) {
+    match ordering {
+        Ordering::Release => panic!("there is no such thing as a release load"),
+        Ordering::AcqRel => panic!("there is no such thing as an acquire-release load"),
+        _ => {}
+    }
+}
+
+/// Panics if `ordering` cannot be used for a store, like the standard
+/// library atomics do.
+#[inline]
+#[track_caller]
+fn validate_store(ordering: Ordering) {
+    match ordering {
+        Ordering::Acquire => panic!("there is no such thing as an acquire store"),
+        Ordering::AcqRel => panic!("there is no such thing as an acquire-release store"),
+        _ => {}
+    }
+}
+
+/// Panics if `failure` cannot be used as the failure ordering of a
+/// compare-and-exchange, like the standard library atomics do.
+#[inline]
+#[track_caller]
+fn validate_failure(failure: Ordering) {
+    match failure {
+        Ordering::Release => panic!("there is no such thing as a release failure ordering"),
+        Ordering::AcqRel => {
+            panic!("there is no such thing as an acquire-release failure ordering")
+        }
+        _ => {}
+    }
+}
+
+/// Generates a raw 128-bit atomic integer type.
+macro_rules! impl_raw_atomic_128 {
+    ($name:ident, $value_type:ty, $doc_type:expr) => {
+        #[doc = concat!("Raw atomic ", $doc_type, ".")]
+        ///
+        /// Mirrors the API of the standard library atomic integers, such as
+        /// `std::sync::atomic::AtomicU64`, for 128-bit values, which the
+        /// standard library only offers on nightly Rust. Most code should
+        #[doc = concat!("use [`", stringify!($name), "`](crate::atomic::", stringify!($name), ")")]
+        /// without the `Raw` prefix, whose `inner()` returns this type.
+        ///
+        /// # Implementation
+        ///
+        /// On `x86_64` processors supporting `cmpxchg16b` and on
+        /// little-endian `aarch64`, every operation is lock-free: loads,
+        /// stores and read-modify-write operations are implemented with a
+        /// 128-bit compare-and-exchange (`lock cmpxchg16b`, or an
+        /// `ldaxp`/`stlxp` loop), so all operations behave as `SeqCst`.
+        ///
+        /// On other targets, every operation takes one of 64 global spin
+        /// locks, selected by the address of the value, and behaves as
+        /// `SeqCst`. Values sharing a lock do not block each other for
+        /// longer than one operation, and a closure passed to
+        /// `fetch_update` is never called with a lock held.
+        ///
+        /// [`is_lock_free`](Self::is_lock_free) reports which
+        /// implementation is active. The memory orderings passed to the
+        /// methods are validated like those of the standard library types,
+        /// but stronger orderings may be used.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        #[doc = concat!("use prism3_rust_concurrent::atomic::", stringify!($name), ";")]
+        /// use std::sync::atomic::Ordering;
+        ///
+        #[doc = concat!("let atomic = ", stringify!($name), "::new(1 << 100);")]
+        /// atomic.fetch_add(1, Ordering::Relaxed);
+        /// assert_eq!(atomic.load(Ordering::Relaxed), (1 << 100) + 1);
+        /// ```
+        ///
+        /// # Author
+        ///
+        /// Haixing Hu
+        #[repr(C, align(16))]
+        pub struct $name {
+            value: UnsafeCell<u128>,
+        }
+
+        // SAFETY: the value is only accessed through atomic operations
+        unsafe impl Sync for $name {}
+
+        impl $name {
+            /// Creates a new atomic integer.
+            ///
+            /// # Parameters
+            ///
+            /// * `value` - The initial value.
+            #[inline]
+            pub const fn new(value: $value_type) -> Self {
+                Self {
+                    value: UnsafeCell::new(value as u128),
+                }
+            }
+
+            /// Returns whether the operations of this type are lock-free on
+            /// this machine.
+            ///
+            /// The result is the same for all values and all calls within a
+            /// process.
+            ///
+            /// # Returns
+            ///
+            /// `true` if the operations use a 128-bit compare-and-exchange
+            /// instruction, `false` if they use the lock-based fallback.
+            #[inline]
+            pub fn is_lock_free() -> bool {
+                is_lock_free()
+            }
+
+            /// Returns a mutable reference to the value.
+            ///
+            /// This is safe because the mutable reference guarantees that
+            /// no other threads are concurrently accessing the value.
+            #[inline]
+            pub fn get_mut(&mut self) -> &mut $value_type {
+                // SAFETY: both types have the same size, and the alignment
+                // of the cell is at least that of the value type
+                unsafe { &mut *(self.value.get() as *mut $value_type) }
+            }
+
+            /// Consumes the atomic and returns the contained value.
+            #[inline]
+            pub fn into_inner(self) -> $value_type {
+                self.value.into_inner() as $value_type
+            }
+
+            /// Loads the value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is `Release` or `AcqRel`.
+            #[inline]
+            #[track_caller]
+            pub fn load(&self, order: Ordering) -> $value_type {
+                validate_load(order);
+                // SAFETY: the value is only accessed atomically
+                unsafe { load(self.value.get()) as $value_type }
+            }
+
+            /// Stores a value.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `order` is `Acquire` or `AcqRel`.
+            #[inline]
+            #[track_caller]
+            pub fn store(&self, value: $value_type, order: Ordering) {
+                validate_store(order);
+                self.swap(value, Ordering::SeqCst);
+            }
+
+            /// Stores a value and returns the previous value.
+            #[inline]
+            pub fn swap(&self, value: $value_type, _order: Ordering) -> $value_type {
+                // SAFETY: the value is only accessed atomically
+                unsafe { update(self.value.get(), |_| value as u128) as $value_type }
+            }
+
+            /// Stores `new` if the value equals `current`.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(previous)` if the value was replaced, `Err(actual)`
+            /// otherwise.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is `Release` or `AcqRel`.
+            #[inline]
+            #[track_caller]
+            pub fn compare_exchange(
+                &self,
+                current: $value_type,
+                new: $value_type,
+                _success: Ordering,
+                failure: Ordering,
+            ) -> Result<$value_type, $value_type> {
+                validate_failure(failure);
+                // SAFETY: the value is only accessed atomically
+                unsafe { compare_exchange(self.value.get(), current as u128, new as u128) }
+                    .map(|prev| prev as $value_type)
+                    .map_err(|actual| actual as $value_type)
+            }
+
+            /// Stores `new` if the value equals `current`.
+            ///
+            /// Unlike the standard library types, this method never fails
+            /// spuriously; it exists for API compatibility.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(previous)` if the value was replaced, `Err(actual)`
+            /// otherwise.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `failure` is `Release` or `AcqRel`.
+            #[inline]
+            #[track_caller]
+            pub fn compare_exchange_weak(
+                &self,
+                current: $value_type,
+                new: $value_type,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$value_type, $value_type> {
+                self.compare_exchange(current, new, success, failure)
+            }
+
+            /// Adds to the value, wrapping around on overflow, and returns
+            /// the previous value.
+            #[inline]
+            pub fn fetch_add(&self, value: $value_type, _order: Ordering) -> $value_type {
+                self.apply(|x| x.wrapping_add(value))
+            }
+
+            /// Subtracts from the value, wrapping around on overflow, and
+            /// returns the previous value.
+            #[inline]
+            pub fn fetch_sub(&self, value: $value_type, _order: Ordering) -> $value_type {
+                self.apply(|x| x.wrapping_sub(value))
+            }
+
+            /// Bitwise AND with the value, returning the previous value.
+            #[inline]
+            pub fn fetch_and(&self, value: $value_type, _order: Ordering) -> $value_type {
+                self.apply(|x| x & value)
+            }
+
+            /// Bitwise NAND with the value, returning the previous value.
+            #[inline]
+            pub fn fetch_nand(&self, value: $value_type, _order: Ordering) -> $value_type {
+                self.apply(|x| !(x & value))
+            }
+
+            /// Bitwise OR with the value, returning the previous value.
+            #[inline]
+            pub fn fetch_or(&self, value: $value_type, _order: Ordering) -> $value_type {
+                self.apply(|x| x | value)
+            }
+
+            /// Bitwise XOR with the value, returning the previous value.
+            #[inline]
+            pub fn fetch_xor(&self, value: $value_type, _order: Ordering) -> $value_type {
+                self.apply(|x| x ^ value)
+            }
+
+            /// Stores the maximum of the value and `value`, returning the
+            /// previous value.
+            #[inline]
+            pub fn fetch_max(&self, value: $value_type, _order: Ordering) -> $value_type {
+                self.apply(|x| x.max(value))
+            }
+
+            /// Stores the minimum of the value and `value`, returning the
+            /// previous value.
+            #[inline]
+            pub fn fetch_min(&self, value: $value_type, _order: Ordering) -> $value_type {
+                self.apply(|x| x.min(value))
+            }
+
+            /// Replaces the value with `f(value)` while `f` returns `Some`,
+            /// retrying if the value changed concurrently.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(previous)` if the value was replaced, `Err(current)` if
+            /// `f` returned `None`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `fetch_order` is `Release` or `AcqRel`.
+            #[inline]
+            #[track_caller]
+            pub fn fetch_update<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> Result<$value_type, $value_type>
+            where
+                F: FnMut($value_type) -> Option<$value_type>,
+            {
+                let mut current = self.load(fetch_order);
+                while let Some(new) = f(current) {
+                    match self.compare_exchange(current, new, set_order, fetch_order) {
+                        Ok(prev) => return Ok(prev),
+                        Err(actual) => current = actual,
+                    }
+                }
+                Err(current)
+            }
+
+            /// Replaces the value with `f(value)` and returns the previous
+            /// value. `f` must be cheap and must not block, since it may
+            /// run with a lock held.
+            #[inline]
+            fn apply<F>(&self, f: F) -> $value_type
+            where
+                F: Fn($value_type) -> $value_type,
+            {
+                // SAFETY: the value is only accessed atomically
+                unsafe { update(self.value.get(), |x| f(x as $value_type) as u128) as $value_type }
+            }
+
+            /// Loads the value with the lock-based fallback, whichever
+            /// implementation is active, so that it can be tested on every
+            /// target.
+            ///
+            /// # Safety
+            ///
+            /// While this call runs, the value must only be accessed through
+            /// the `locked_*` methods, since the other methods may not take
+            /// the lock.
+            #[doc(hidden)]
+            #[inline]
+            pub unsafe fn locked_load(&self) -> $value_type {
+                // SAFETY: guaranteed by the caller.
+                unsafe { locked_load(self.value.get()) as $value_type }
+            }
+
+            /// Compares and exchanges the value with the lock-based
+            /// fallback, whichever implementation is active.
+            ///
+            /// # Returns
+            ///
+            /// `Ok(previous)` if the value was replaced, `Err(actual)`
+            /// otherwise.
+            ///
+            /// # Safety
+            ///
+            /// Same as `locked_load`.
+            #[doc(hidden)]
+            #[inline]
+            pub unsafe fn locked_compare_exchange(
+                &self,
+                current: $value_type,
+                new: $value_type,
+            ) -> Result<$value_type, $value_type> {
+                // SAFETY: guaranteed by the caller.
+                unsafe { locked_compare_exchange(self.value.get(), current as u128, new as u128) }
+                    .map(|prev| prev as $value_type)
+                    .map_err(|actual| actual as $value_type)
+            }
+
+            /// Replaces the value with `f(value)` with the lock-based
+            /// fallback, whichever implementation is active, and returns
+            /// the previous value.
+            ///
+            /// # Safety
+            ///
+            /// Same as `locked_load`.
+            #[doc(hidden)]
+            #[inline]
+            pub unsafe fn locked_update<F>(&self, f: F) -> $value_type
+            where
+                F: FnOnce($value_type) -> $value_type,
+            {
+                // SAFETY: guaranteed by the caller.
+                unsafe {
+                    locked_update(self.value.get(), |x| f(x as $value_type) as u128) as $value_type
+                }
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::new(0)
+            }
+        }
+
+        impl From<$value_type> for $name {
+            #[inline]
+            fn from(value: $value_type) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+            }
+        }
+    };
+}
+
+impl_raw_atomic_128!(RawAtomicU128, u128, "128-bit unsigned integer");
+impl_raw_atomic_128!(RawAtomicI128, i128, "128-bit signed integer");
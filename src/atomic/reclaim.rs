@@ -0,0 +1,136 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Deferred Reclamation
+//!
+//! Provides a lightweight per-instance epoch scheme used by the atomic
+//! reference types. Readers pin the current epoch while they turn a raw
+//! pointer into an owned reference; writers wait for a grace period before
+//! releasing the reference they swapped out.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::thread;
+
+/// Number of busy-wait iterations before a writer starts yielding while
+/// waiting for a grace period.
+const SPIN_LIMIT: u32 = 64;
+
+/// Per-instance reclamation domain.
+///
+/// The domain keeps two reader counters, selected by the parity of an epoch
+/// counter:
+///
+/// - A reader increments the counter of the current epoch, re-checks that
+///   the epoch did not change, reads the pointer and takes its own
+///   reference, then decrements the counter again.
+/// - A writer first unlinks the old pointer, then advances the epoch and
+///   waits until the counter of the previous epoch drains to zero. Readers
+///   arriving after the advance register on the other counter and can only
+///   observe the new pointer, so the wait is bounded by the readers that
+///   were already in flight.
+///
+/// Grace periods are serialized by a mutex, so concurrent writers never
+/// wait on each other's reader counter.
+///
+/// # Author
+///
+/// Haixing Hu
+pub(crate) struct Reclaimer {
+    epoch: AtomicUsize,
+    readers: [AtomicUsize; 2],
+    writer: Mutex<()>,
+}
+
+impl Reclaimer {
+    /// Creates a new reclamation domain.
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            epoch: AtomicUsize::new(0),
+            readers: [AtomicUsize::new(0), AtomicUsize::new(0)],
+            writer: Mutex::new(()),
+        }
+    }
+
+    /// Enters a read-side critical section.
+    ///
+    /// While the returned guard is alive, any pointer loaded from the
+    /// protected slot stays valid, so the caller may safely increment its
+    /// reference count.
+    ///
+    /// # Returns
+    ///
+    /// A guard that leaves the critical section when dropped.
+    #[inline]
+    pub(crate) fn pin(&self) -> ReadGuard<'_> {
+        loop {
+            let slot = self.epoch.load(Ordering::SeqCst) & 1;
+            let readers = &self.readers[slot];
+            readers.fetch_add(1, Ordering::SeqCst);
+            if self.epoch.load(Ordering::SeqCst) & 1 == slot {
+                return ReadGuard { readers };
+            }
+            // A writer advanced the epoch in between; register again on
+            // the counter it will not wait for.
+            readers.fetch_sub(1, Ordering::Release);
+        }
+    }
+
+    /// Waits for a grace period.
+    ///
+    /// When this method returns, every read-side critical section that
+    /// could have observed a pointer unlinked before the call has ended.
+    ///
+    /// # Note
+    ///
+    /// Must not be called while the current thread holds a guard returned
+    /// by [`pin`](Self::pin) on the same domain, otherwise it deadlocks.
+    pub(crate) fn synchronize(&self) {
+        let _lock = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+        let previous = self.epoch.fetch_add(1, Ordering::SeqCst) & 1;
+        let readers = &self.readers[previous];
+        let mut spins = 0;
+        while readers.load(Ordering::SeqCst) != 0 {
+            if spins < SPIN_LIMIT {
+                spins += 1;
+                std::hint::spin_loop();
+            } else {
+                thread::yield_now();
+            }
+        }
+    }
+}
+
+impl Default for Reclaimer {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Guard of a read-side critical section.
+///
+/// Created by [`Reclaimer::pin`]. Leaves the critical section when dropped.
+pub(crate) struct ReadGuard<'a> {
+    readers: &'a AtomicUsize,
+}
+
+impl Drop for ReadGuard<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.readers.fetch_sub(1, Ordering::Release);
+    }
+}
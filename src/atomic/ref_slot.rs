@@ -0,0 +1,280 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Reference Slots
+//!
+//! Provides the unsafe core shared by `AtomicRef`, `AtomicOptionRef` and
+//! `AtomicWeak`: an atomic pointer owning the reference count of a handle,
+//! whose readers and writers are coordinated by a [`Reclaimer`].
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Weak;
+
+use crate::atomic::ordering::assert_acq_rel;
+use crate::atomic::ordering::assert_acquire;
+use crate::atomic::ordering::assert_release;
+use crate::atomic::reclaim::Reclaimer;
+
+/// Reference-counted handles which can be stored as a raw pointer.
+///
+/// # Safety
+///
+/// `from_raw` must take back the count given up by `into_raw` for the same
+/// pointer, and cloning the handle must only increment its count.
+pub(crate) unsafe trait RawRef: Clone {
+    /// The type of the pointer.
+    type Target;
+
+    /// Converts the handle into a raw pointer, giving up its count.
+    fn into_raw(self) -> *mut Self::Target;
+
+    /// Converts a raw pointer back into the handle.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must come from `into_raw`, and the caller must own the count
+    /// it represents.
+    unsafe fn from_raw(ptr: *mut Self::Target) -> Self;
+
+    /// Returns whether releasing the count of `ptr` may free memory still
+    /// used by readers, so that it must wait for a grace period.
+    #[inline]
+    fn needs_grace_period(_ptr: *mut Self::Target) -> bool {
+        true
+    }
+}
+
+// SAFETY: the conversions are those of `Arc`.
+unsafe impl<T> RawRef for Arc<T> {
+    type Target = T;
+
+    #[inline]
+    fn into_raw(self) -> *mut T {
+        Arc::into_raw(self) as *mut T
+    }
+
+    #[inline]
+    unsafe fn from_raw(ptr: *mut T) -> Self {
+        // SAFETY: guaranteed by the caller.
+        unsafe { Arc::from_raw(ptr) }
+    }
+}
+
+// SAFETY: `None` is the null pointer, which owns no count; other values
+// use the conversions of `Arc`.
+unsafe impl<T> RawRef for Option<Arc<T>> {
+    type Target = T;
+
+    #[inline]
+    fn into_raw(self) -> *mut T {
+        self.map_or(ptr::null_mut(), RawRef::into_raw)
+    }
+
+    #[inline]
+    unsafe fn from_raw(ptr: *mut T) -> Self {
+        // SAFETY: guaranteed by the caller.
+        (!ptr.is_null()).then(|| unsafe { Arc::from_raw(ptr) })
+    }
+
+    #[inline]
+    fn needs_grace_period(ptr: *mut T) -> bool {
+        !ptr.is_null()
+    }
+}
+
+// SAFETY: the conversions are those of `Weak`, including the dangling
+// pointer of an empty `Weak`.
+unsafe impl<T> RawRef for Weak<T> {
+    type Target = T;
+
+    #[inline]
+    fn into_raw(self) -> *mut T {
+        Weak::into_raw(self) as *mut T
+    }
+
+    #[inline]
+    unsafe fn from_raw(ptr: *mut T) -> Self {
+        // SAFETY: guaranteed by the caller.
+        unsafe { Weak::from_raw(ptr) }
+    }
+}
+
+/// Atomic slot owning the count of a reference-counted handle.
+///
+/// Loading a handle reads the pointer, then increments its count, and a
+/// concurrent writer releasing the last count in between would free it.
+/// Every slot therefore owns a reclamation domain: readers pin it, and
+/// every operation unlinking the stored handle waits until the pinned
+/// readers are done before releasing or returning it.
+///
+/// The operations check that their orderings publish the data behind the
+/// pointer, and panic otherwise, also in release builds.
+pub(crate) struct RefSlot<R: RawRef> {
+    ptr: AtomicPtr<R::Target>,
+    reclaimer: Reclaimer,
+    _owned: PhantomData<R>,
+}
+
+impl<R: RawRef> RefSlot<R> {
+    /// Creates a slot owning `value`.
+    #[inline]
+    pub(crate) fn new(value: R) -> Self {
+        Self {
+            ptr: AtomicPtr::new(value.into_raw()),
+            reclaimer: Reclaimer::new(),
+            _owned: PhantomData,
+        }
+    }
+
+    /// Returns the underlying atomic pointer.
+    #[inline]
+    pub(crate) fn as_atomic_ptr(&self) -> &AtomicPtr<R::Target> {
+        &self.ptr
+    }
+
+    /// Clones the stored handle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `Acquire` nor `SeqCst`.
+    #[inline]
+    pub(crate) fn load(&self, ordering: Ordering) -> R {
+        self.load_with(ordering, R::clone)
+    }
+
+    /// Calls `f` with the stored handle while pinned, without cloning it.
+    ///
+    /// `f` must not write to this slot, since a write waits for the pinned
+    /// readers to leave.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `Acquire` nor `SeqCst`.
+    #[inline]
+    pub(crate) fn load_with<U, F>(&self, ordering: Ordering, f: F) -> U
+    where
+        F: FnOnce(&R) -> U,
+    {
+        assert_acquire(ordering);
+        let _guard = self.reclaimer.pin();
+        let ptr = self.ptr.load(ordering);
+        // SAFETY: the pointer was loaded while pinned, so the count owned
+        // by this slot cannot be released during the call; the borrowed
+        // handle is never dropped, so that count is left untouched.
+        let current = ManuallyDrop::new(unsafe { R::from_raw(ptr) });
+        f(&current)
+    }
+
+    /// Replaces the stored handle, releasing the old one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `Release` nor `SeqCst`.
+    #[inline]
+    pub(crate) fn store(&self, value: R, ordering: Ordering) {
+        assert_release(ordering);
+        let old_ptr = self.ptr.swap(value.into_raw(), ordering);
+        drop(self.retire(old_ptr));
+    }
+
+    /// Replaces the stored handle, returning the old one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is neither `AcqRel` nor `SeqCst`.
+    #[inline]
+    pub(crate) fn swap(&self, value: R, ordering: Ordering) -> R {
+        assert_acq_rel(ordering);
+        let old_ptr = self.ptr.swap(value.into_raw(), ordering);
+        self.retire(old_ptr)
+    }
+
+    /// Replaces the stored handle with `new` if its pointer is `current`.
+    ///
+    /// Returns `Ok(previous)` on success, or `Err(actual)` with a clone of
+    /// the witnessed handle on failure. The CAS itself runs while pinned so
+    /// that the witnessed pointer can be cloned on failure. Any handle is
+    /// retired or dropped only after leaving the critical section, since
+    /// dropping it may run a destructor writing to this slot, which waits
+    /// for a grace period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `success` is neither `AcqRel` nor `SeqCst`, or if
+    /// `failure` is neither `Acquire` nor `SeqCst`.
+    pub(crate) fn compare_exchange(
+        &self,
+        current: *mut R::Target,
+        new: R,
+        weak: bool,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<R, R> {
+        assert_acq_rel(success);
+        assert_acquire(failure);
+        let new_ptr = new.into_raw();
+        let guard = self.reclaimer.pin();
+        let result = if weak {
+            self.ptr
+                .compare_exchange_weak(current, new_ptr, success, failure)
+        } else {
+            self.ptr
+                .compare_exchange(current, new_ptr, success, failure)
+        };
+        match result {
+            Ok(prev_ptr) => {
+                drop(guard);
+                Ok(self.retire(prev_ptr))
+            }
+            Err(actual_ptr) => {
+                // SAFETY: `new_ptr` was never published, so we still own its
+                // count; `actual_ptr` was witnessed while pinned, and the
+                // borrowed handle is never dropped.
+                let (rejected, actual) = unsafe {
+                    let actual = ManuallyDrop::new(R::from_raw(actual_ptr));
+                    (R::from_raw(new_ptr), R::clone(&actual))
+                };
+                drop(guard);
+                drop(rejected);
+                Err(actual)
+            }
+        }
+    }
+
+    /// Takes back the count of a pointer that has just been unlinked,
+    /// waiting until no reader can still observe it.
+    #[inline]
+    fn retire(&self, ptr: *mut R::Target) -> R {
+        if R::needs_grace_period(ptr) {
+            self.reclaimer.synchronize();
+        }
+        // SAFETY: `ptr` came from `into_raw` and has been unlinked by the
+        // caller, so the count of this slot is transferred back.
+        unsafe { R::from_raw(ptr) }
+    }
+}
+
+impl<R: RawRef> Drop for RefSlot<R> {
+    fn drop(&mut self) {
+        // Exclusive access: no reader can be pinned at this point.
+        let ptr = *self.ptr.get_mut();
+        // SAFETY: the pointer came from `into_raw` and this slot owns the
+        // count it represents.
+        drop(unsafe { R::from_raw(ptr) });
+    }
+}
@@ -0,0 +1,161 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+//! # Striped Cells
+//!
+//! Provides the cache-padded cell array shared by the striped adders. Each
+//! thread is assigned a probe that selects its home cell, so threads
+//! updating the same adder mostly touch different cache lines.
+//!
+//! Also provides the global striped spin locks used by the lock-based
+//! fallbacks of `RawAtomicU128`, `RawAtomicI128` and `AtomicCell`.
+//!
+//! # Author
+//!
+//! Haixing Hu
+
+use std::cell::Cell;
+use std::ops::Deref;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::thread;
+
+use crate::atomic::backoff::Backoff;
+use crate::atomic::backoff::ExponentialBackoff;
+
+/// The maximum number of cells of a striped value.
+const MAX_CELLS: usize = 64;
+
+/// Number of global striped spin locks.
+const LOCK_COUNT: usize = 64;
+
+/// Golden-ratio increment used to spread probes over the cells.
+const PROBE_INCREMENT: usize = 0x9E37_79B9;
+
+/// Source of the initial probes of new threads.
+static NEXT_PROBE: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static PROBE: Cell<usize> = Cell::new(
+        NEXT_PROBE
+            .fetch_add(1, Ordering::Relaxed)
+            .wrapping_mul(PROBE_INCREMENT),
+    );
+}
+
+/// Aligns a value to 128 bytes so that it never shares a cache line, or a
+/// pair of adjacent lines fetched together, with another value.
+#[repr(align(128))]
+#[derive(Debug, Default)]
+pub(crate) struct CachePadded<T> {
+    value: T,
+}
+
+impl<T> CachePadded<T> {
+    /// Wraps a value.
+    #[inline]
+    pub(crate) const fn new(value: T) -> Self {
+        Self { value }
+    }
+}
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// A fixed array of cache-padded cells, one per hardware thread up to
+/// `MAX_CELLS`, rounded up to a power of two.
+pub(crate) struct Striped<T> {
+    cells: Box<[CachePadded<T>]>,
+}
+
+impl<T> Striped<T> {
+    /// Creates the cells, initializing each one with `init`.
+    pub(crate) fn new<F>(mut init: F) -> Self
+    where
+        F: FnMut() -> T,
+    {
+        let count = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .next_power_of_two()
+            .min(MAX_CELLS);
+        let cells = (0..count).map(|_| CachePadded::new(init())).collect();
+        Self { cells }
+    }
+
+    /// Returns the home cell of the current thread.
+    #[inline]
+    pub(crate) fn cell(&self) -> &T {
+        let probe = PROBE.with(Cell::get);
+        &self.cells[probe & (self.cells.len() - 1)]
+    }
+
+    /// Moves the current thread to another cell after it observed
+    /// contention on its home cell.
+    #[inline]
+    pub(crate) fn rehash(&self) {
+        PROBE.with(|probe| {
+            probe.set(probe.get().wrapping_add(PROBE_INCREMENT));
+        });
+    }
+
+    /// Returns an iterator over all cells.
+    #[inline]
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter().map(|cell| &**cell)
+    }
+}
+
+/// An unlocked spin lock, used to initialize `LOCKS`.
+#[allow(clippy::declare_interior_mutable_const)]
+const UNLOCKED: CachePadded<AtomicBool> = CachePadded::new(AtomicBool::new(false));
+
+/// The global striped spin locks.
+static LOCKS: [CachePadded<AtomicBool>; LOCK_COUNT] = [UNLOCKED; LOCK_COUNT];
+
+/// Holds the striped spin lock guarding a value until dropped.
+///
+/// The lock is selected by the address of the value, so values sharing a
+/// lock serialize each other's operations. The lock is acquired and
+/// released with `SeqCst` ordering, so the operations performed while
+/// holding it behave as `SeqCst` operations.
+pub(crate) struct StripedLockGuard {
+    lock: &'static AtomicBool,
+}
+
+impl StripedLockGuard {
+    /// Acquires the spin lock guarding the value at address `addr`.
+    #[inline]
+    pub(crate) fn acquire(addr: usize) -> Self {
+        let index = ((addr >> 4) ^ (addr >> 10)) % LOCK_COUNT;
+        let lock: &'static AtomicBool = &LOCKS[index];
+        let mut backoff = ExponentialBackoff::new();
+        while lock
+            .compare_exchange_weak(false, true, Ordering::SeqCst, Ordering::Relaxed)
+            .is_err()
+        {
+            backoff.backoff();
+        }
+        Self { lock }
+    }
+}
+
+impl Drop for StripedLockGuard {
+    #[inline]
+    fn drop(&mut self) {
+        self.lock.store(false, Ordering::SeqCst);
+    }
+}
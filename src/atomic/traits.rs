@@ -155,7 +155,83 @@ pub trait Atomic {
     /// The old value before the update.
     fn fetch_update<F>(&self, f: F) -> Self::Value
     where
-        F: Fn(Self::Value) -> Self::Value;
+        F: FnMut(Self::Value) -> Self::Value;
+
+    /// Updates the value using a function, returning the new value.
+    ///
+    /// Internally uses a CAS loop until the update succeeds. The returned
+    /// value is exactly the one that was stored.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current value and returns
+    ///   the new value.
+    ///
+    /// # Returns
+    ///
+    /// The new value after the update.
+    fn update_and_get<F>(&self, f: F) -> Self::Value
+    where
+        F: FnMut(Self::Value) -> Self::Value;
+
+    /// Updates the value using a function that may decline, returning the
+    /// old value.
+    ///
+    /// Internally uses a CAS loop that stops as soon as `f` returns `None`,
+    /// leaving the value unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current value and returns the new
+    ///   value, or `None` to abort the update.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(old_value)` if the value was updated, or `Err(current_value)` if
+    /// `f` returned `None`.
+    fn try_update<F>(&self, f: F) -> Result<Self::Value, Self::Value>
+    where
+        F: FnMut(Self::Value) -> Option<Self::Value>;
+
+    /// Updates the value using a fallible function, returning the old value.
+    ///
+    /// Internally uses a CAS loop that stops as soon as `f` returns an
+    /// error, leaving the value unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// * `f` - A function that takes the current value and returns the new
+    ///   value, or an error to abort the update.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(old_value)` if the value was updated, or the error returned by
+    /// `f`.
+    fn try_update_result<F, E>(&self, f: F) -> Result<Self::Value, E>
+    where
+        F: FnMut(Self::Value) -> Result<Self::Value, E>;
+
+    /// Accumulates a value using a binary function, returning the new value.
+    ///
+    /// The default implementation calls `update_and_get` with a closure
+    /// applying `f` to the current value and a clone of `x`.
+    ///
+    /// # Parameters
+    ///
+    /// * `x` - The value to accumulate with.
+    /// * `f` - A binary function that takes the current value and `x`,
+    ///   returning the new value.
+    ///
+    /// # Returns
+    ///
+    /// The new value after the accumulation.
+    fn accumulate_and_get<F>(&self, x: Self::Value, mut f: F) -> Self::Value
+    where
+        Self::Value: Clone,
+        F: FnMut(Self::Value, Self::Value) -> Self::Value,
+    {
+        self.update_and_get(|current| f(current, x.clone()))
+    }
 }
 
 /// Trait for atomic numeric types that support arithmetic operations.
@@ -169,6 +245,9 @@ pub trait Atomic {
 /// Integer types also provide `fetch_inc()` and `fetch_dec()` methods
 /// as convenient shortcuts for incrementing/decrementing by 1, but these
 /// are not part of this trait as they are integer-specific operations.
+/// The trait does provide `inc_and_get()` and `dec_and_get()`, which are
+/// available for every value type convertible from `bool`, including
+/// the floating-point types.
 ///
 /// # Author
 ///
@@ -227,4 +306,102 @@ pub trait AtomicNumber: Atomic {
     ///
     /// The old value before dividing.
     fn fetch_div(&self, divisor: Self::Value) -> Self::Value;
+
+    /// Adds a delta to the value, returning the new value.
+    ///
+    /// Uses the same ordering as `fetch_add`.
+    ///
+    /// # Parameters
+    ///
+    /// * `delta` - The value to add.
+    ///
+    /// # Returns
+    ///
+    /// The new value after adding.
+    fn add_and_get(&self, delta: Self::Value) -> Self::Value;
+
+    /// Subtracts a delta from the value, returning the new value.
+    ///
+    /// Uses the same ordering as `fetch_sub`.
+    ///
+    /// # Parameters
+    ///
+    /// * `delta` - The value to subtract.
+    ///
+    /// # Returns
+    ///
+    /// The new value after subtracting.
+    fn sub_and_get(&self, delta: Self::Value) -> Self::Value;
+
+    /// Increments the value by one, returning the new value.
+    ///
+    /// Uses the same ordering as `add_and_get`.
+    ///
+    /// # Returns
+    ///
+    /// The new value after incrementing.
+    fn inc_and_get(&self) -> Self::Value;
+
+    /// Decrements the value by one, returning the new value.
+    ///
+    /// Uses the same ordering as `sub_and_get`.
+    ///
+    /// # Returns
+    ///
+    /// The new value after decrementing.
+    fn dec_and_get(&self) -> Self::Value;
+
+    /// Sets the value to the maximum of the current value and the given
+    /// value, returning the new value.
+    ///
+    /// The default implementation uses `update_and_get` and keeps the
+    /// current value unless `value` is greater. Floating-point types
+    /// override it to follow their NaN policy.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to compare with.
+    ///
+    /// # Returns
+    ///
+    /// The new value after the operation.
+    fn max_and_get(&self, value: Self::Value) -> Self::Value
+    where
+        Self::Value: Clone + PartialOrd,
+    {
+        self.update_and_get(|current| {
+            if value > current {
+                value.clone()
+            } else {
+                current
+            }
+        })
+    }
+
+    /// Sets the value to the minimum of the current value and the given
+    /// value, returning the new value.
+    ///
+    /// The default implementation uses `update_and_get` and keeps the
+    /// current value unless `value` is smaller. Floating-point types
+    /// override it to follow their NaN policy.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - The value to compare with.
+    ///
+    /// # Returns
+    ///
+    /// The new value after the operation.
+    fn min_and_get(&self, value: Self::Value) -> Self::Value
+    where
+        Self::Value: Clone + PartialOrd,
+    {
+        self.update_and_get(|current| {
+            if value < current {
+                value.clone()
+            } else {
+                current
+            }
+        })
+    }
 }
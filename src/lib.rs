@@ -25,10 +25,31 @@
 //!
 //! - Boolean atomic type: `AtomicBool`
 //! - Integer atomic types: `AtomicI8`, `AtomicU8`, `AtomicI16`, `AtomicU16`,
-//!   `AtomicI32`, `AtomicU32`, `AtomicI64`, `AtomicU64`, `AtomicIsize`,
-//!   `AtomicUsize`
-//! - Floating-point atomic types: `AtomicF32`, `AtomicF64`
-//! - Reference atomic type: `AtomicRef<T>`
+//!   `AtomicI32`, `AtomicU32`, `AtomicI64`, `AtomicU64`, `AtomicI128`,
+//!   `AtomicU128`, `AtomicIsize`, `AtomicUsize`
+//! - Floating-point atomic types: `AtomicF32`, `AtomicF64`, with max/min
+//!   under a configurable `NanPolicy`
+//! - Reference atomic types: `AtomicRef<T>`, `AtomicOptionRef<T>` for
+//!   nullable references, and `AtomicWeak<T>` for weak references
+//! - Striped adders for contended counters: `AtomicAdderI64`,
+//!   `AtomicAdderU64`, `AtomicAdderF64`
+//! - Compensated floating-point accumulator: `AtomicCompensatedF64`
+//! - 16-bit floating-point atomic types: `AtomicF16`, `AtomicBf16`
+//! - Non-zero integer atomic types: `AtomicNonZeroI8`, ...,
+//!   `AtomicNonZeroUsize`, and `AtomicOptionNonZeroI8`, ...,
+//!   `AtomicOptionNonZeroUsize`
+//! - Atomic enum type: `AtomicEnum<E>`, with the `atomic_enum!` macro for
+//!   fieldless enums
+//! - Atomic cell for any `Copy` type without padding: `AtomicCell<T>`, with
+//!   the `no_uninit!` macro for structs
+//! - Atomic slot owning a `Box<T>`: `AtomicBox<T>`
+//! - Packed atomic bit-field structs: the `atomic_bitfield!` macro
+//! - Atomic bit set with lock-free slot allocation: `AtomicBitSet<N>`
+//! - Ordering policies selecting the default orderings at the type level:
+//!   `DefaultOrdering`, `AcqRel`, `SeqCst`, `RelaxedCounter`
+//! - Backoff strategies for CAS loops: `ExponentialBackoff`, `SpinBackoff`,
+//!   `YieldBackoff`, `NoBackoff`, and `BackoffStrategy` to select the
+//!   default strategy of an ordering policy
 //!
 //! ## Example
 //!
@@ -74,20 +95,81 @@ pub mod atomic;
 
 // Re-export all atomic types and traits
 pub use atomic::{
+    AcqRel,
     Atomic,
+    AtomicAdderF64,
+    AtomicAdderI64,
+    AtomicAdderU64,
+    AtomicBf16,
+    AtomicBitSet,
     AtomicBool,
+    AtomicBox,
+    AtomicCell,
+    AtomicCompensatedF64,
+    AtomicEnum,
+    AtomicEnumValue,
+    AtomicF16,
     AtomicF32,
     AtomicF64,
+    AtomicI128,
     AtomicI16,
     AtomicI32,
     AtomicI64,
     AtomicI8,
     AtomicIsize,
+    AtomicNonZeroI128,
+    AtomicNonZeroI16,
+    AtomicNonZeroI32,
+    AtomicNonZeroI64,
+    AtomicNonZeroI8,
+    AtomicNonZeroIsize,
+    AtomicNonZeroU128,
+    AtomicNonZeroU16,
+    AtomicNonZeroU32,
+    AtomicNonZeroU64,
+    AtomicNonZeroU8,
+    AtomicNonZeroUsize,
     AtomicNumber,
+    AtomicOptionNonZeroI128,
+    AtomicOptionNonZeroI16,
+    AtomicOptionNonZeroI32,
+    AtomicOptionNonZeroI64,
+    AtomicOptionNonZeroI8,
+    AtomicOptionNonZeroIsize,
+    AtomicOptionNonZeroU128,
+    AtomicOptionNonZeroU16,
+    AtomicOptionNonZeroU32,
+    AtomicOptionNonZeroU64,
+    AtomicOptionNonZeroU8,
+    AtomicOptionNonZeroUsize,
+    AtomicOptionRef,
     AtomicRef,
+    AtomicU128,
     AtomicU16,
     AtomicU32,
     AtomicU64,
     AtomicU8,
     AtomicUsize,
+    AtomicWeak,
+    Backoff,
+    BackoffStrategy,
+    BitField,
+    BitFieldLayout,
+    BitFieldRepr,
+    BitSetIter,
+    DefaultOrdering,
+    EnumRepr,
+    ExponentialBackoff,
+    NanPolicy,
+    NoBackoff,
+    NoUninit,
+    OrderingPolicy,
+    PublishingPolicy,
+    RawAtomicI128,
+    RawAtomicU128,
+    RcuGuard,
+    RelaxedCounter,
+    SeqCst,
+    SpinBackoff,
+    YieldBackoff,
 };
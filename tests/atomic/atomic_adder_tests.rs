@@ -0,0 +1,196 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    AtomicAdderF64,
+    AtomicAdderI64,
+    AtomicAdderU64,
+    RelaxedCounter,
+    SeqCst,
+};
+use std::sync::Arc;
+use std::thread;
+
+const NUM_THREADS: usize = 8;
+const ITERATIONS_PER_THREAD: usize = 1000;
+
+#[test]
+fn test_i64_adder_basic() {
+    let adder = AtomicAdderI64::new();
+    assert_eq!(adder.sum(), 0);
+    adder.add(10);
+    adder.add(-3);
+    adder.inc();
+    adder.dec();
+    adder.dec();
+    assert_eq!(adder.sum(), 6);
+}
+
+#[test]
+fn test_u64_adder_basic() {
+    let adder = AtomicAdderU64::default();
+    adder.add(10);
+    adder.inc();
+    adder.dec();
+    assert_eq!(adder.sum(), 10);
+}
+
+#[test]
+fn test_f64_adder_basic() {
+    let adder = AtomicAdderF64::new();
+    adder.add(1.5);
+    adder.add(-0.5);
+    assert_eq!(adder.sum(), 1.0);
+}
+
+#[test]
+fn test_reset() {
+    let adder = AtomicAdderI64::new();
+    adder.add(42);
+    adder.reset();
+    assert_eq!(adder.sum(), 0);
+
+    let adder = AtomicAdderF64::default();
+    adder.add(4.2);
+    adder.reset();
+    assert_eq!(adder.sum(), 0.0);
+}
+
+#[test]
+fn test_sum_then_reset() {
+    let adder = AtomicAdderU64::new();
+    adder.add(7);
+    assert_eq!(adder.sum_then_reset(), 7);
+    assert_eq!(adder.sum(), 0);
+    assert_eq!(adder.sum_then_reset(), 0);
+
+    let adder = AtomicAdderF64::new();
+    adder.add(2.5);
+    assert_eq!(adder.sum_then_reset(), 2.5);
+    assert_eq!(adder.sum(), 0.0);
+}
+
+#[test]
+fn test_sum_wraps_around() {
+    let adder = AtomicAdderU64::new();
+    adder.add(u64::MAX);
+    adder.add(2);
+    assert_eq!(adder.sum(), 1);
+
+    // A decrement may land on a cell that is still zero
+    let adder = AtomicAdderU64::new();
+    adder.inc();
+    adder.dec();
+    assert_eq!(adder.sum(), 0);
+}
+
+#[test]
+fn test_with_policy() {
+    let adder = AtomicAdderI64::<SeqCst>::with_policy();
+    adder.add(3);
+    assert_eq!(adder.sum(), 3);
+
+    let adder = AtomicAdderF64::<RelaxedCounter>::with_policy();
+    adder.add(3.0);
+    assert_eq!(adder.sum_then_reset(), 3.0);
+}
+
+#[test]
+fn test_debug_display() {
+    let adder = AtomicAdderI64::new();
+    adder.add(42);
+    assert_eq!(format!("{}", adder), "42");
+    assert_eq!(format!("{:?}", adder), "AtomicAdderI64 { sum: 42 }");
+
+    let adder = AtomicAdderF64::new();
+    adder.add(1.5);
+    assert_eq!(format!("{}", adder), "1.5");
+    assert!(format!("{:?}", adder).contains("1.5"));
+}
+
+#[test]
+fn test_concurrent_i64_adder() {
+    let adder = Arc::new(AtomicAdderI64::new());
+    let mut handles = vec![];
+
+    for _ in 0..NUM_THREADS {
+        let adder = adder.clone();
+        let handle = thread::spawn(move || {
+            for _ in 0..ITERATIONS_PER_THREAD {
+                adder.inc();
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(adder.sum(), (NUM_THREADS * ITERATIONS_PER_THREAD) as i64);
+}
+
+#[test]
+fn test_concurrent_f64_adder() {
+    let adder = Arc::new(AtomicAdderF64::new());
+    let mut handles = vec![];
+
+    for _ in 0..NUM_THREADS {
+        let adder = adder.clone();
+        let handle = thread::spawn(move || {
+            for _ in 0..ITERATIONS_PER_THREAD {
+                adder.add(1.0);
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(adder.sum(), (NUM_THREADS * ITERATIONS_PER_THREAD) as f64);
+}
+
+#[test]
+fn test_concurrent_sum_then_reset_counts_each_update_once() {
+    let adder = Arc::new(AtomicAdderU64::new());
+    let mut handles = vec![];
+
+    for _ in 0..NUM_THREADS {
+        let adder = adder.clone();
+        let handle = thread::spawn(move || {
+            for _ in 0..ITERATIONS_PER_THREAD {
+                adder.inc();
+            }
+        });
+        handles.push(handle);
+    }
+
+    let mut drained = 0;
+    while !handles.iter().all(|handle| handle.is_finished()) {
+        drained += adder.sum_then_reset();
+        thread::yield_now();
+    }
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    drained += adder.sum_then_reset();
+
+    assert_eq!(drained, (NUM_THREADS * ITERATIONS_PER_THREAD) as u64);
+}
+
+#[test]
+fn test_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<AtomicAdderI64>();
+    assert_send_sync::<AtomicAdderU64<SeqCst>>();
+    assert_send_sync::<AtomicAdderF64>();
+}
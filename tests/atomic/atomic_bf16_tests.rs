@@ -0,0 +1,76 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::AtomicBf16;
+
+test_atomic_half_float!(AtomicBf16, atomic_bf16_tests);
+
+#[test]
+fn test_to_bits() {
+    assert_eq!(AtomicBf16::to_bits(0.0), 0x0000);
+    assert_eq!(AtomicBf16::to_bits(-0.0), 0x8000);
+    assert_eq!(AtomicBf16::to_bits(1.0), 0x3f80);
+    assert_eq!(AtomicBf16::to_bits(-2.0), 0xc000);
+    assert_eq!(AtomicBf16::to_bits(0.1), 0x3dcd);
+    assert_eq!(AtomicBf16::to_bits(1.0 / 3.0), 0x3eab);
+    assert_eq!(AtomicBf16::to_bits(f32::INFINITY), 0x7f80);
+    assert_eq!(AtomicBf16::to_bits(f32::NEG_INFINITY), 0xff80);
+}
+
+#[test]
+fn test_to_bits_ties_to_even() {
+    let ulp = 2f32.powi(-7);
+    assert_eq!(AtomicBf16::to_bits(1.0 + ulp / 2.0), 0x3f80);
+    assert_eq!(AtomicBf16::to_bits(1.0 + ulp * 1.5), 0x3f82);
+    assert_eq!(AtomicBf16::to_bits(1.0 + ulp * 0.75), 0x3f81);
+}
+
+#[test]
+fn test_to_bits_overflow() {
+    assert_eq!(AtomicBf16::to_bits(f32::MAX), 0x7f80);
+    assert_eq!(AtomicBf16::to_bits(-f32::MAX), 0xff80);
+    assert_eq!(AtomicBf16::to_bits(f32::from_bits(0x7f7f_7fff)), 0x7f7f);
+}
+
+#[test]
+fn test_to_bits_subnormal() {
+    // bfloat16 has the exponent range of f32, so f32 subnormals are rounded
+    // like any other value
+    assert_eq!(AtomicBf16::to_bits(f32::MIN_POSITIVE), 0x0080);
+    assert_eq!(AtomicBf16::to_bits(f32::from_bits(0x0001_0000)), 0x0001);
+    assert_eq!(AtomicBf16::to_bits(f32::from_bits(0x0000_8000)), 0x0000);
+    assert_eq!(AtomicBf16::to_bits(f32::from_bits(0x0000_8001)), 0x0001);
+}
+
+#[test]
+fn test_to_bits_nan() {
+    assert_eq!(AtomicBf16::to_bits(f32::NAN) & 0x7f80, 0x7f80);
+    assert_ne!(AtomicBf16::to_bits(f32::NAN) & 0x007f, 0);
+    // A payload only in the low bits must not turn into infinity
+    let nan = f32::from_bits(0x7f80_0001);
+    assert!(AtomicBf16::from_bits(AtomicBf16::to_bits(nan)).is_nan());
+}
+
+#[test]
+fn test_from_bits() {
+    assert_eq!(AtomicBf16::from_bits(0x3f80), 1.0);
+    assert_eq!(AtomicBf16::from_bits(0xc000), -2.0);
+    assert_eq!(AtomicBf16::from_bits(0x7f7f), f32::from_bits(0x7f7f_0000));
+    assert_eq!(AtomicBf16::from_bits(0x7f80), f32::INFINITY);
+    assert!(AtomicBf16::from_bits(0x8000).is_sign_negative());
+    assert!(AtomicBf16::from_bits(0x7fc0).is_nan());
+}
+
+#[test]
+fn test_round_trip_all_bits() {
+    for bits in 0..=u16::MAX {
+        let value = AtomicBf16::from_bits(bits);
+        assert_eq!(AtomicBf16::to_bits(value), bits, "bits {:#06x}", bits);
+    }
+}
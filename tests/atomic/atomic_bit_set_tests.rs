@@ -0,0 +1,191 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    AtomicBitSet,
+    SeqCst,
+};
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn test_new() {
+    let set = AtomicBitSet::<130>::new();
+    assert_eq!(set.capacity(), 130);
+    assert_eq!(set.count_ones(), 0);
+    assert_eq!(set.first_set_bit(), None);
+    assert_eq!(set.first_clear_bit(), Some(0));
+    assert_eq!(set.iter().next(), None);
+}
+
+#[test]
+fn test_default() {
+    let set: AtomicBitSet<8> = AtomicBitSet::default();
+    assert_eq!(set.count_ones(), 0);
+}
+
+#[test]
+fn test_with_policy() {
+    let set = AtomicBitSet::<8, SeqCst>::with_policy();
+    assert!(!set.test_and_set_bit(7));
+    assert!(set.test_bit(7));
+}
+
+#[test]
+fn test_set_and_clear_bit() {
+    let set = AtomicBitSet::<130>::new();
+    for i in [0, 63, 64, 129] {
+        assert!(!set.test_bit(i));
+        set.set_bit(i);
+        assert!(set.test_bit(i));
+    }
+    assert_eq!(set.count_ones(), 4);
+    set.set_bit(63);
+    assert_eq!(set.count_ones(), 4);
+    set.clear_bit(64);
+    assert!(!set.test_bit(64));
+    assert!(set.test_bit(63));
+    assert_eq!(set.count_ones(), 3);
+}
+
+#[test]
+fn test_test_and_set_and_clear() {
+    let set = AtomicBitSet::<70>::new();
+    assert!(!set.test_and_set_bit(65));
+    assert!(set.test_and_set_bit(65));
+    assert!(set.test_and_clear_bit(65));
+    assert!(!set.test_and_clear_bit(65));
+}
+
+#[test]
+fn test_first_set_and_clear_bit() {
+    let set = AtomicBitSet::<130>::new();
+    set.set_bit(100);
+    set.set_bit(120);
+    assert_eq!(set.first_set_bit(), Some(100));
+    for i in 0..64 {
+        set.set_bit(i);
+    }
+    assert_eq!(set.first_set_bit(), Some(0));
+    assert_eq!(set.first_clear_bit(), Some(64));
+}
+
+#[test]
+fn test_first_clear_bit_ignores_unused_bits() {
+    let set = AtomicBitSet::<70>::new();
+    for i in 0..70 {
+        set.set_bit(i);
+    }
+    assert_eq!(set.first_clear_bit(), None);
+    assert_eq!(set.count_ones(), 70);
+}
+
+#[test]
+fn test_clear() {
+    let set = AtomicBitSet::<130>::new();
+    set.set_bit(1);
+    set.set_bit(129);
+    set.clear();
+    assert_eq!(set.count_ones(), 0);
+}
+
+#[test]
+fn test_iter() {
+    let set = AtomicBitSet::<200>::new();
+    let bits = [0, 1, 63, 64, 127, 128, 199];
+    for i in bits {
+        set.set_bit(i);
+    }
+    assert_eq!(set.iter().collect::<Vec<_>>(), bits);
+    let mut via_ref = Vec::new();
+    for i in &set {
+        via_ref.push(i);
+    }
+    assert_eq!(via_ref, bits);
+}
+
+#[test]
+fn test_acquire_and_release_slot() {
+    let slots = AtomicBitSet::<65>::new();
+    for i in 0..65 {
+        assert_eq!(slots.acquire_free_slot(), Some(i));
+    }
+    assert_eq!(slots.acquire_free_slot(), None);
+    slots.release_slot(64);
+    slots.release_slot(3);
+    assert_eq!(slots.acquire_free_slot(), Some(3));
+    assert_eq!(slots.acquire_free_slot(), Some(64));
+    assert_eq!(slots.acquire_free_slot(), None);
+}
+
+#[test]
+#[should_panic(expected = "not in use")]
+fn test_release_free_slot_panics() {
+    let slots = AtomicBitSet::<8>::new();
+    slots.release_slot(0);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn test_index_out_of_range_panics() {
+    let set = AtomicBitSet::<70>::new();
+    set.set_bit(70);
+}
+
+#[test]
+fn test_zero_capacity() {
+    let set = AtomicBitSet::<0>::new();
+    assert_eq!(set.acquire_free_slot(), None);
+    assert_eq!(set.first_clear_bit(), None);
+    assert_eq!(set.count_ones(), 0);
+}
+
+#[test]
+fn test_debug() {
+    let set = AtomicBitSet::<100>::new();
+    set.set_bit(2);
+    set.set_bit(70);
+    assert_eq!(format!("{:?}", set), "{2, 70}");
+}
+
+#[test]
+fn test_concurrent_slot_allocation() {
+    const THREADS: usize = 8;
+    const ROUNDS: usize = 1000;
+    let slots = Arc::new(AtomicBitSet::<130>::new());
+    let owners = Arc::new(
+        (0..130)
+            .map(|_| AtomicUsize::new(usize::MAX))
+            .collect::<Vec<_>>(),
+    );
+    let handles: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let slots = slots.clone();
+            let owners = owners.clone();
+            thread::spawn(move || {
+                for _ in 0..ROUNDS {
+                    let slot = slots.acquire_free_slot().unwrap();
+                    // No other thread may own the slot at the same time
+                    let previous = owners[slot].swap(t, Ordering::Relaxed);
+                    assert_eq!(previous, usize::MAX);
+                    owners[slot].store(usize::MAX, Ordering::Relaxed);
+                    slots.release_slot(slot);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(slots.count_ones(), 0);
+}
@@ -0,0 +1,220 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic_bitfield;
+use std::sync::Arc;
+use std::thread;
+
+atomic_bitfield! {
+    /// A slot of a handle table.
+    pub struct AtomicSlot(Slot): u64 {
+        /// Incremented whenever the slot is reused.
+        pub generation: 16,
+        pub index: 32,
+        pub state: 8,
+    }
+}
+
+atomic_bitfield! {
+    struct AtomicPair(Pair): u32 {
+        low: 16,
+        high: 16
+    }
+}
+
+atomic_bitfield! {
+    struct AtomicWhole(Whole): u64 {
+        all: 64,
+    }
+}
+
+#[test]
+fn test_layout() {
+    let slot = AtomicSlot::default();
+    assert_eq!(slot.generation().layout().shift(), 0);
+    assert_eq!(slot.generation().layout().width(), 16);
+    assert_eq!(slot.index().layout().shift(), 16);
+    assert_eq!(slot.index().layout().width(), 32);
+    assert_eq!(slot.state().layout().shift(), 48);
+    assert_eq!(slot.state().layout().name(), "state");
+}
+
+#[test]
+fn test_to_and_from_bits() {
+    let slot = Slot {
+        generation: 0x1234,
+        index: 0xdead_beef,
+        state: 0x56,
+    };
+    assert_eq!(slot.to_bits(), 0x0056_dead_beef_1234);
+    assert_eq!(Slot::from_bits(0x0056_dead_beef_1234), slot);
+    // Bits outside of the fields are ignored
+    assert_eq!(Slot::from_bits(0xff56_dead_beef_1234), slot);
+    let pair = Pair { low: 1, high: 2 };
+    assert_eq!(pair.to_bits(), 0x0002_0001);
+    assert_eq!(Whole { all: u64::MAX }.to_bits(), u64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "value 256 does not fit in the 8-bit field `state`")]
+fn test_to_bits_overflow_panics() {
+    Slot {
+        generation: 0,
+        index: 0,
+        state: 256,
+    }
+    .to_bits();
+}
+
+#[test]
+fn test_new_default_from() {
+    let value = Slot {
+        generation: 1,
+        index: 2,
+        state: 3,
+    };
+    assert_eq!(AtomicSlot::new(value).load(), value);
+    assert_eq!(AtomicSlot::from(value).load(), value);
+    assert_eq!(AtomicSlot::default().load(), Slot::default());
+}
+
+#[test]
+fn test_store_swap_compare_set() {
+    let pair = AtomicPair::new(Pair { low: 1, high: 2 });
+    pair.store(Pair { low: 3, high: 4 });
+    assert_eq!(pair.load(), Pair { low: 3, high: 4 });
+    assert_eq!(
+        pair.swap(Pair { low: 5, high: 6 }),
+        Pair { low: 3, high: 4 }
+    );
+    assert!(pair
+        .compare_set(Pair { low: 5, high: 6 }, Pair { low: 7, high: 8 })
+        .is_ok());
+    assert_eq!(
+        pair.compare_set(Pair { low: 5, high: 6 }, Pair { low: 0, high: 0 }),
+        Err(Pair { low: 7, high: 8 })
+    );
+}
+
+#[test]
+fn test_field_load_and_set() {
+    let slot = AtomicSlot::default();
+    slot.index().set(0xffff_ffff);
+    slot.state().set(7);
+    assert_eq!(slot.index().load(), 0xffff_ffff);
+    assert_eq!(slot.state().load(), 7);
+    assert_eq!(slot.generation().load(), 0);
+}
+
+#[test]
+#[should_panic(expected = "does not fit in the 16-bit field `generation`")]
+fn test_field_set_overflow_panics() {
+    AtomicSlot::default().generation().set(0x1_0000);
+}
+
+#[test]
+fn test_field_fetch_add_wraps_within_field() {
+    let slot = AtomicSlot::new(Slot {
+        generation: 0xffff,
+        index: 9,
+        state: 1,
+    });
+    assert_eq!(slot.generation().fetch_add(1), 0xffff);
+    assert_eq!(
+        slot.load(),
+        Slot {
+            generation: 0,
+            index: 9,
+            state: 1,
+        }
+    );
+    let whole = AtomicWhole::new(Whole { all: u64::MAX });
+    assert_eq!(whole.all().fetch_add(2), u64::MAX);
+    assert_eq!(whole.all().load(), 1);
+}
+
+#[test]
+fn test_field_compare_set() {
+    let slot = AtomicSlot::default();
+    assert!(slot.state().compare_set(0, 2).is_ok());
+    assert_eq!(slot.state().compare_set(0, 3), Err(2));
+    // Changes of the other fields do not matter
+    slot.index().set(5);
+    assert!(slot.state().compare_set(2, 3).is_ok());
+    assert_eq!(slot.load().index, 5);
+}
+
+#[test]
+#[should_panic(expected = "does not fit in the 8-bit field `state`")]
+fn test_field_compare_set_overflow_panics() {
+    let _ = AtomicSlot::default().state().compare_set(0, 300);
+}
+
+#[test]
+fn test_fetch_update() {
+    let slot = AtomicSlot::default();
+    let old = slot.fetch_update(|s| Slot {
+        generation: s.generation + 1,
+        state: 2,
+        ..s
+    });
+    assert_eq!(old, Slot::default());
+    assert_eq!(slot.load().generation, 1);
+    assert_eq!(slot.load().state, 2);
+}
+
+#[test]
+fn test_try_update() {
+    let slot = AtomicSlot::default();
+    let claim = |s: Slot| (s.state == 0).then_some(Slot { state: 1, ..s });
+    assert_eq!(slot.try_update(claim), Ok(Slot::default()));
+    assert_eq!(slot.try_update(claim).unwrap_err().state, 1);
+}
+
+#[test]
+fn test_debug() {
+    let pair = AtomicPair::new(Pair { low: 1, high: 2 });
+    assert_eq!(
+        format!("{:?}", pair),
+        "AtomicPair { value: Pair { low: 1, high: 2 } }"
+    );
+    assert_eq!(
+        format!("{:?}", pair.high()),
+        "BitField { name: \"high\", value: 2 }"
+    );
+}
+
+#[test]
+fn test_concurrent_field_updates_are_independent() {
+    let slot = Arc::new(AtomicSlot::default());
+    let mut handles = vec![];
+    for i in 0..4 {
+        let slot = slot.clone();
+        handles.push(thread::spawn(move || {
+            for _ in 0..1000 {
+                if i % 2 == 0 {
+                    slot.generation().fetch_add(1);
+                } else {
+                    slot.index().fetch_add(1);
+                }
+            }
+        }));
+    }
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(
+        slot.load(),
+        Slot {
+            generation: 2000,
+            index: 2000,
+            state: 0,
+        }
+    );
+}
@@ -11,6 +11,7 @@ use prism3_atomic::atomic::{
     Atomic,
     AtomicBool,
 };
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::thread;
 
@@ -448,3 +449,94 @@ fn test_concurrent_compare_and_set_weak() {
     // At least one thread should succeed
     assert!(success_count.load(std::sync::atomic::Ordering::Relaxed) >= 1);
 }
+
+#[test]
+fn test_load_store_with_ordering() {
+    let atomic = AtomicBool::new(false);
+    atomic.store_with_ordering(true, Ordering::Relaxed);
+    assert!(atomic.load_with_ordering(Ordering::Relaxed));
+    atomic.store_with_ordering(false, Ordering::SeqCst);
+    assert!(!atomic.load_with_ordering(Ordering::SeqCst));
+}
+
+#[test]
+fn test_swap_with_ordering() {
+    let atomic = AtomicBool::new(false);
+    assert!(!atomic.swap_with_ordering(true, Ordering::Relaxed));
+    assert!(atomic.load());
+}
+
+#[test]
+fn test_compare_set_with_ordering() {
+    let atomic = AtomicBool::new(false);
+    assert!(atomic
+        .compare_set_with_ordering(false, true, Ordering::SeqCst, Ordering::Relaxed)
+        .is_ok());
+    assert_eq!(
+        atomic.compare_set_with_ordering(false, true, Ordering::SeqCst, Ordering::Relaxed),
+        Err(true)
+    );
+    assert!(atomic.compare_and_exchange_with_ordering(
+        true,
+        false,
+        Ordering::Release,
+        Ordering::Relaxed,
+    ));
+    while atomic
+        .compare_set_weak_with_ordering(false, true, Ordering::AcqRel, Ordering::Acquire)
+        .is_err()
+    {}
+    while !atomic.compare_and_exchange_weak_with_ordering(
+        true,
+        false,
+        Ordering::SeqCst,
+        Ordering::SeqCst,
+    ) {}
+    assert!(!atomic.load());
+}
+
+#[test]
+fn test_logical_operations_with_ordering() {
+    let atomic = AtomicBool::new(false);
+    assert!(!atomic.fetch_set_with_ordering(Ordering::Relaxed));
+    assert!(atomic.fetch_clear_with_ordering(Ordering::Relaxed));
+    assert!(!atomic.fetch_not_with_ordering(Ordering::SeqCst));
+    assert!(atomic.fetch_and_with_ordering(false, Ordering::SeqCst));
+    assert!(!atomic.fetch_or_with_ordering(true, Ordering::AcqRel));
+    assert!(atomic.fetch_xor_with_ordering(true, Ordering::AcqRel));
+    assert!(!atomic.load());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid memory ordering for a store")]
+fn test_store_with_acquire_ordering_panics() {
+    let atomic = AtomicBool::new(false);
+    atomic.store_with_ordering(true, Ordering::Acquire);
+}
+
+#[test]
+fn test_update_and_get() {
+    let atomic = AtomicBool::new(false);
+    assert!(Atomic::update_and_get(&atomic, |x| !x));
+    assert!(!Atomic::update_and_get(&atomic, |x| !x));
+    assert!(!atomic.load());
+}
+
+#[test]
+fn test_try_update() {
+    let atomic = AtomicBool::new(false);
+    assert_eq!(
+        Atomic::try_update(&atomic, |x| (!x).then_some(true)),
+        Ok(false)
+    );
+    assert_eq!(
+        Atomic::try_update(&atomic, |x| (!x).then_some(true)),
+        Err(true)
+    );
+    assert_eq!(
+        Atomic::try_update_result(&atomic, |_| Err::<bool, _>(())),
+        Err(())
+    );
+    assert!(atomic.load());
+}
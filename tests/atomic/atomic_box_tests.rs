@@ -0,0 +1,193 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    AtomicBox,
+    OrderingPolicy,
+    PublishingPolicy,
+    SeqCst,
+};
+use std::sync::atomic::{
+    AtomicUsize as StdAtomicUsize,
+    Ordering,
+};
+use std::sync::Arc;
+use std::thread;
+
+/// Publishing policy claiming that relaxed orderings publish values.
+struct Relaxed;
+
+impl OrderingPolicy for Relaxed {
+    const LOAD: Ordering = Ordering::Relaxed;
+    const RMW: Ordering = Ordering::Relaxed;
+    const CAS_SUCCESS: Ordering = Ordering::Relaxed;
+    const CAS_FAILURE: Ordering = Ordering::Relaxed;
+}
+
+impl PublishingPolicy for Relaxed {}
+
+struct Counted(Arc<StdAtomicUsize>);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_new_and_default() {
+    let slot = AtomicBox::new(Some(Box::new(42)));
+    assert!(!slot.is_empty());
+    assert_eq!(slot.into_inner().as_deref(), Some(&42));
+    let empty: AtomicBox<i32> = AtomicBox::default();
+    assert!(empty.is_empty());
+    assert!(empty.into_inner().is_none());
+}
+
+#[test]
+fn test_from() {
+    let slot: AtomicBox<i32> = Box::new(1).into();
+    assert_eq!(slot.take().as_deref(), Some(&1));
+    let slot: AtomicBox<i32> = None.into();
+    assert!(slot.is_empty());
+}
+
+#[test]
+fn test_with_policy() {
+    let slot = AtomicBox::<_, SeqCst>::with_policy(Some(Box::new(3)));
+    assert_eq!(slot.take().as_deref(), Some(&3));
+}
+
+#[test]
+fn test_swap() {
+    let slot = AtomicBox::new(None);
+    assert!(slot.swap(Box::new(1)).is_none());
+    assert_eq!(slot.swap(Box::new(2)).as_deref(), Some(&1));
+    assert_eq!(slot.take().as_deref(), Some(&2));
+}
+
+#[test]
+fn test_replace() {
+    let slot = AtomicBox::new(Some(Box::new(1)));
+    assert_eq!(slot.replace(None).as_deref(), Some(&1));
+    assert!(slot.replace(Some(Box::new(2))).is_none());
+    assert!(!slot.is_empty());
+}
+
+#[test]
+fn test_take() {
+    let slot = AtomicBox::new(Some(Box::new(String::from("frame"))));
+    assert_eq!(slot.take().as_deref().map(String::as_str), Some("frame"));
+    assert!(slot.take().is_none());
+    assert!(slot.is_empty());
+}
+
+#[test]
+fn test_compare_set_null() {
+    let slot = AtomicBox::new(None);
+    assert!(slot.compare_set_null(Box::new(1)).is_ok());
+    let rejected = slot.compare_set_null(Box::new(2)).unwrap_err();
+    assert_eq!(*rejected, 2);
+    assert_eq!(slot.take().as_deref(), Some(&1));
+    assert!(slot.compare_set_null(Box::new(3)).is_ok());
+}
+
+#[test]
+fn test_get_mut() {
+    let mut slot = AtomicBox::new(Some(Box::new(1)));
+    *slot.get_mut().unwrap() += 1;
+    assert_eq!(slot.take().as_deref(), Some(&2));
+    assert!(slot.get_mut().is_none());
+}
+
+#[test]
+fn test_drop() {
+    let drops = Arc::new(StdAtomicUsize::new(0));
+    let slot = AtomicBox::new(Some(Box::new(Counted(drops.clone()))));
+    drop(slot.swap(Box::new(Counted(drops.clone()))));
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+    drop(slot.compare_set_null(Box::new(Counted(drops.clone()))));
+    assert_eq!(drops.load(Ordering::SeqCst), 2);
+    drop(slot);
+    assert_eq!(drops.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_into_inner_does_not_drop() {
+    let drops = Arc::new(StdAtomicUsize::new(0));
+    let slot = AtomicBox::new(Some(Box::new(Counted(drops.clone()))));
+    let value = slot.into_inner();
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+    drop(value);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_debug() {
+    let slot = AtomicBox::new(Some(Box::new(1)));
+    assert_eq!(format!("{:?}", slot), "AtomicBox { is_empty: false }");
+    slot.take();
+    assert_eq!(format!("{:?}", slot), "AtomicBox { is_empty: true }");
+}
+
+#[test]
+fn test_concurrent_mailbox() {
+    const MESSAGES: usize = 1_000;
+    let mailbox = Arc::new(AtomicBox::default());
+    let sender = {
+        let mailbox = mailbox.clone();
+        thread::spawn(move || {
+            for i in 0..MESSAGES {
+                let mut message = Box::new(vec![i; 4]);
+                loop {
+                    match mailbox.compare_set_null(message) {
+                        Ok(()) => break,
+                        Err(rejected) => {
+                            message = rejected;
+                            thread::yield_now();
+                        }
+                    }
+                }
+            }
+        })
+    };
+    let mut received = 0;
+    while received < MESSAGES {
+        match mailbox.take() {
+            Some(message) => {
+                assert_eq!(*message, vec![received; 4]);
+                received += 1;
+            }
+            None => thread::yield_now(),
+        }
+    }
+    sender.join().unwrap();
+    assert!(mailbox.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "memory ordering must be Acquire or SeqCst")]
+fn test_is_empty_rejects_relaxed_policy() {
+    let slot = AtomicBox::<i32, Relaxed>::with_policy(None);
+    slot.is_empty();
+}
+
+#[test]
+#[should_panic(expected = "memory ordering must be AcqRel or SeqCst")]
+fn test_replace_rejects_relaxed_policy() {
+    let slot = AtomicBox::<i32, Relaxed>::with_policy(None);
+    slot.swap(Box::new(1));
+}
+
+#[test]
+#[should_panic(expected = "memory ordering must be AcqRel or SeqCst")]
+fn test_compare_set_null_rejects_relaxed_policy() {
+    let slot = AtomicBox::<i32, Relaxed>::with_policy(None);
+    let _ = slot.compare_set_null(Box::new(1));
+}
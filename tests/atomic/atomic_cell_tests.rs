@@ -0,0 +1,363 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    Atomic,
+    AtomicCell,
+    NoUninit,
+    SeqCst,
+};
+use prism3_atomic::no_uninit;
+use std::marker::PhantomData;
+use std::mem;
+use std::sync::Arc;
+use std::thread;
+
+no_uninit! {
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Point {
+        x: i16,
+        y: i16,
+    }
+}
+
+no_uninit! {
+    /// A 3-byte value, stored in an `AtomicU32`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Rgb([u8; 3]);
+}
+
+no_uninit! {
+    /// A 24-byte value, stored under a lock.
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Triple {
+        a: u64,
+        b: u64,
+        c: u64,
+    }
+}
+
+no_uninit! {
+    /// Fields of different sizes, ordered so that no padding is needed.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Slot {
+        pub generation: u32,
+        index: u16,
+        pub(crate) flags: u8,
+        tag: u8,
+    }
+}
+
+/// Tells whether `T` implements `NoUninit` through autoref-based method
+/// resolution: the impl on `Probe<T>` is preferred when it applies, the
+/// fallback on `&Probe<T>` otherwise.
+struct Probe<T>(PhantomData<T>);
+
+trait IsNoUninit {
+    fn is_no_uninit(&self) -> bool {
+        true
+    }
+}
+
+impl<T: NoUninit> IsNoUninit for Probe<T> {}
+
+trait NotNoUninit {
+    fn is_no_uninit(&self) -> bool {
+        false
+    }
+}
+
+impl<T> NotNoUninit for &Probe<T> {}
+
+macro_rules! is_no_uninit {
+    ($type:ty) => {
+        (&Probe::<$type>(PhantomData)).is_no_uninit()
+    };
+}
+
+#[test]
+fn test_no_uninit() {
+    assert!(is_no_uninit!(u8));
+    assert!(is_no_uninit!(char));
+    assert!(is_no_uninit!((u32, u32)));
+    assert!(is_no_uninit!([(u16, u16); 3]));
+    assert!(is_no_uninit!(Option<std::num::NonZeroU64>));
+    assert!(is_no_uninit!(Point));
+    assert!(is_no_uninit!(Rgb));
+    assert!(is_no_uninit!(Slot));
+    // Padded or containing references: rejected by `AtomicCell`
+    assert!(!is_no_uninit!((u8, u16)));
+    assert!(!is_no_uninit!((u32, u8)));
+    assert!(!is_no_uninit!([(u8, u16); 2]));
+    assert!(!is_no_uninit!(Option<u32>));
+    assert!(!is_no_uninit!(&u32));
+}
+
+#[test]
+fn test_no_uninit_macro() {
+    assert_eq!(mem::size_of::<Slot>(), 8);
+    let slot = Slot {
+        generation: 1,
+        index: 2,
+        flags: 3,
+        tag: 4,
+    };
+    let cell = AtomicCell::new(slot);
+    assert!(AtomicCell::<Slot>::is_lock_free());
+    let old = cell.fetch_update(|s| Slot {
+        generation: s.generation + 1,
+        ..s
+    });
+    assert_eq!(old, slot);
+    assert_eq!(cell.load().generation, 2);
+    assert_eq!((cell.load().index, cell.load().tag), (2, 4));
+}
+
+#[test]
+fn test_is_lock_free() {
+    assert!(AtomicCell::<()>::is_lock_free());
+    assert!(AtomicCell::<bool>::is_lock_free());
+    assert!(AtomicCell::<char>::is_lock_free());
+    assert!(AtomicCell::<Point>::is_lock_free());
+    assert!(AtomicCell::<Rgb>::is_lock_free());
+    assert!(AtomicCell::<(u32, u32)>::is_lock_free());
+    assert!(AtomicCell::<[u8; 7]>::is_lock_free());
+    assert!(!AtomicCell::<u128>::is_lock_free());
+    assert!(!AtomicCell::<Triple>::is_lock_free());
+}
+
+#[test]
+fn test_new_and_load() {
+    assert_eq!(AtomicCell::new(()).load(), ());
+    assert!(AtomicCell::new(true).load());
+    assert_eq!(AtomicCell::new('é').load(), 'é');
+    assert_eq!(
+        AtomicCell::new(Point { x: -1, y: 2 }).load(),
+        Point { x: -1, y: 2 }
+    );
+    assert_eq!(AtomicCell::new(Rgb([1, 2, 3])).load(), Rgb([1, 2, 3]));
+    assert_eq!(AtomicCell::new((7u32, 8u32)).load(), (7, 8));
+    assert_eq!(AtomicCell::new([1u8, 2, 3, 4, 5]).load(), [1, 2, 3, 4, 5]);
+    assert_eq!(AtomicCell::new(u128::MAX).load(), u128::MAX);
+}
+
+#[test]
+fn test_default_and_from() {
+    assert_eq!(AtomicCell::<Point>::default().load(), Point::default());
+    assert_eq!(AtomicCell::<Triple>::default().load(), Triple::default());
+    assert_eq!(AtomicCell::from(1.5f64).load(), 1.5);
+}
+
+#[test]
+fn test_with_policy() {
+    let cell = AtomicCell::<Point, SeqCst>::with_policy(Point { x: 1, y: 1 });
+    cell.fetch_update(|p| Point { x: p.x * 10, ..p });
+    assert_eq!(cell.load(), Point { x: 10, y: 1 });
+}
+
+#[test]
+fn test_store_and_swap() {
+    let cell = AtomicCell::new(Rgb([0, 0, 0]));
+    cell.store(Rgb([255, 128, 0]));
+    assert_eq!(cell.load(), Rgb([255, 128, 0]));
+    assert_eq!(cell.swap(Rgb([1, 1, 1])), Rgb([255, 128, 0]));
+    assert_eq!(cell.load(), Rgb([1, 1, 1]));
+}
+
+#[test]
+fn test_store_and_swap_locked() {
+    let cell = AtomicCell::new(Triple::default());
+    let t = Triple { a: 1, b: 2, c: 3 };
+    cell.store(t);
+    assert_eq!(cell.load(), t);
+    assert_eq!(cell.swap(Triple::default()), t);
+    assert_eq!(cell.load(), Triple::default());
+}
+
+#[test]
+fn test_compare_set() {
+    let cell = AtomicCell::new((1u32, 2u32));
+    assert!(cell.compare_set((1, 2), (3, 4)).is_ok());
+    assert_eq!(cell.compare_set((1, 2), (5, 6)), Err((3, 4)));
+    assert_eq!(cell.load(), (3, 4));
+}
+
+#[test]
+fn test_compare_set_locked() {
+    let t = Triple { a: 1, b: 2, c: 3 };
+    let cell = AtomicCell::new(t);
+    assert!(cell.compare_set(t, Triple::default()).is_ok());
+    assert_eq!(cell.compare_set(t, t), Err(Triple::default()));
+    assert_eq!(cell.load(), Triple::default());
+}
+
+#[test]
+fn test_compare_set_compares_bytes() {
+    let cell = AtomicCell::new(0.0f32);
+    // -0.0 == 0.0, but their bytes differ
+    assert_eq!(cell.compare_set(-0.0, 1.0), Err(0.0));
+    assert!(cell.compare_set(0.0, f32::NAN).is_ok());
+    // NaN != NaN, but its bytes are equal to themselves
+    assert!(cell.compare_set(f32::NAN, 2.0).is_ok());
+    assert_eq!(cell.load(), 2.0);
+}
+
+#[test]
+fn test_compare_set_weak() {
+    let cell = AtomicCell::new('a');
+    let mut current = cell.load();
+    loop {
+        match cell.compare_set_weak(current, 'z') {
+            Ok(_) => break,
+            Err(actual) => current = actual,
+        }
+    }
+    assert_eq!(cell.load(), 'z');
+}
+
+#[test]
+fn test_compare_and_exchange() {
+    let cell = AtomicCell::new(Point { x: 1, y: 2 });
+    let p = Point { x: 3, y: 4 };
+    assert_eq!(
+        cell.compare_and_exchange(Point { x: 1, y: 2 }, p),
+        Point { x: 1, y: 2 }
+    );
+    assert_eq!(cell.compare_and_exchange(Point { x: 1, y: 2 }, p), p);
+    let mut current = cell.load();
+    loop {
+        let prev = cell.compare_and_exchange_weak(current, Point { x: 0, y: 0 });
+        if prev == current {
+            break;
+        }
+        current = prev;
+    }
+    assert_eq!(cell.load(), Point { x: 0, y: 0 });
+}
+
+#[test]
+fn test_fetch_update_and_update_and_get() {
+    let cell = AtomicCell::new((1u32, 10u32));
+    assert_eq!(cell.fetch_update(|(a, b)| (a + 1, b - 1)), (1, 10));
+    assert_eq!(cell.update_and_get(|(a, b)| (a * 2, b * 2)), (4, 18));
+    assert_eq!(cell.load(), (4, 18));
+}
+
+#[test]
+fn test_try_update() {
+    let cell = AtomicCell::new(Triple { a: 1, b: 0, c: 0 });
+    let dec = |t: Triple| {
+        Some(Triple {
+            a: t.a.checked_sub(1)?,
+            ..t
+        })
+    };
+    assert_eq!(cell.try_update(dec), Ok(Triple { a: 1, b: 0, c: 0 }));
+    assert_eq!(cell.try_update(dec), Err(Triple::default()));
+}
+
+#[test]
+fn test_try_update_result() {
+    let cell = AtomicCell::new('y');
+    let next = |c: char| {
+        char::from_u32(c as u32 + 1)
+            .filter(char::is_ascii_lowercase)
+            .ok_or("no next letter")
+    };
+    assert_eq!(cell.try_update_result(next), Ok('y'));
+    assert_eq!(cell.try_update_result(next), Err("no next letter"));
+    assert_eq!(cell.load(), 'z');
+}
+
+#[test]
+fn test_into_inner() {
+    assert_eq!(
+        AtomicCell::new(Point { x: 5, y: 6 }).into_inner(),
+        Point { x: 5, y: 6 }
+    );
+    assert_eq!(AtomicCell::new([3u64; 5]).into_inner(), [3; 5]);
+}
+
+#[test]
+fn test_debug() {
+    let cell = AtomicCell::new((1u8, 2u8));
+    assert_eq!(format!("{:?}", cell), "AtomicCell { value: (1, 2) }");
+}
+
+/// Increments a counter through the `Atomic` trait only.
+fn increment<A: Atomic<Value = (u32, u32)>>(atomic: &A) {
+    atomic.fetch_update(|(n, m)| (n + 1, m + 2));
+}
+
+#[test]
+fn test_atomic_trait() {
+    let cell = AtomicCell::new((0u32, 0u32));
+    increment(&cell);
+    increment(&cell);
+    assert_eq!(Atomic::load(&cell), (2, 4));
+    Atomic::store(&cell, (5, 5));
+    assert_eq!(Atomic::swap(&cell, (6, 6)), (5, 5));
+    assert!(Atomic::compare_set(&cell, (6, 6), (7, 7)).is_ok());
+    assert_eq!(Atomic::compare_exchange(&cell, (6, 6), (8, 8)), (7, 7));
+    assert_eq!(Atomic::update_and_get(&cell, |(a, b)| (a, b + 1)), (7, 8));
+    assert_eq!(Atomic::try_update(&cell, |_| None), Err((7, 8)));
+}
+
+/// Runs several threads incrementing both halves of a pair and checks that
+/// no update was lost and no torn value was observed.
+fn check_concurrent_pairs<T, F, G>(cell: Arc<AtomicCell<T>>, inc: F, halves: G)
+where
+    T: NoUninit + Send + 'static,
+    F: Fn(T) -> T + Copy + Send + 'static,
+    G: Fn(T) -> (u64, u64) + Copy + Send + 'static,
+{
+    let threads = 4;
+    let iterations = 1000;
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let cell = cell.clone();
+            thread::spawn(move || {
+                for _ in 0..iterations {
+                    let (a, b) = halves(cell.load());
+                    assert_eq!(a, b, "torn value");
+                    cell.fetch_update(inc);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(
+        halves(cell.load()),
+        (threads * iterations, threads * iterations)
+    );
+}
+
+#[test]
+fn test_concurrent_lock_free() {
+    check_concurrent_pairs(
+        Arc::new(AtomicCell::new((0u32, 0u32))),
+        |(a, b)| (a + 1, b + 1),
+        |(a, b)| (u64::from(a), u64::from(b)),
+    );
+}
+
+#[test]
+fn test_concurrent_locked() {
+    check_concurrent_pairs(
+        Arc::new(AtomicCell::new(Triple::default())),
+        |t| Triple {
+            a: t.a + 1,
+            b: t.b + 1,
+            c: t.c,
+        },
+        |t| (t.a, t.b),
+    );
+}
@@ -0,0 +1,236 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    AtomicCompensatedF64,
+    AtomicF64,
+};
+use std::sync::Arc;
+use std::thread;
+
+const NUM_THREADS: usize = 8;
+const VALUES_PER_THREAD: usize = 2000;
+
+/// Binary exponent of the smallest generated value, `2^-SCALE`.
+const SCALE: i32 = 40;
+
+/// Generates `n` values of widely varying magnitude and sign, each an
+/// integer multiple of `2^-SCALE`, from a deterministic LCG.
+fn generate_values(seed: u64, n: usize) -> Vec<f64> {
+    let mut state = seed;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        state >> 33
+    };
+    (0..n)
+        .map(|_| {
+            let mantissa = (next() % (1 << 20)) as f64;
+            let exponent = (next() % 60) as i32 - SCALE;
+            let sign = if next() % 2 == 0 { 1.0 } else { -1.0 };
+            sign * mantissa * 2f64.powi(exponent)
+        })
+        .collect()
+}
+
+/// Computes the exact sum of values generated by `generate_values`, rounded
+/// once to the nearest `f64`.
+fn exact_sum(values: &[f64]) -> f64 {
+    let scaled: i128 = values.iter().map(|x| (x * 2f64.powi(SCALE)) as i128).sum();
+    scaled as f64 * 2f64.powi(-SCALE)
+}
+
+/// Returns the error bound of compensated summation:
+/// `2 * eps * |s| + n * eps^2 * sum(|x|)`.
+fn error_bound(values: &[f64], exact: f64) -> f64 {
+    let eps = f64::EPSILON;
+    let abs_sum: f64 = values.iter().map(|x| x.abs()).sum();
+    2.0 * eps * exact.abs() + values.len() as f64 * eps * eps * abs_sum
+}
+
+#[test]
+fn test_new_and_default() {
+    let total = AtomicCompensatedF64::new();
+    assert_eq!(total.sum(), 0.0);
+    let total = AtomicCompensatedF64::default();
+    assert_eq!(total.sum(), 0.0);
+}
+
+#[test]
+fn test_add_and_sum() {
+    let total = AtomicCompensatedF64::new();
+    total.add(1.5);
+    total.add(2.5);
+    total.add(-1.0);
+    assert_eq!(total.sum(), 3.0);
+}
+
+#[test]
+fn test_repeated_small_additions() {
+    let total = AtomicCompensatedF64::new();
+    let naive = AtomicF64::new(0.0);
+    for _ in 0..1000 {
+        total.add(0.1);
+        naive.fetch_add(0.1);
+    }
+    assert_eq!(total.sum(), 100.0);
+    assert_ne!(naive.load(), 100.0);
+}
+
+#[test]
+fn test_cancellation() {
+    let total = AtomicCompensatedF64::new();
+    total.add(1e16);
+    total.add(1.0);
+    total.add(-1e16);
+    assert_eq!(total.sum(), 1.0);
+
+    let total = AtomicCompensatedF64::new();
+    total.add(1.0);
+    total.add(1e100);
+    total.add(1.0);
+    total.add(-1e100);
+    assert_eq!(total.sum(), 2.0);
+}
+
+#[test]
+fn test_error_bound_serial() {
+    let values = generate_values(42, 10_000);
+    let exact = exact_sum(&values);
+    let total = AtomicCompensatedF64::new();
+    for &x in &values {
+        total.add(x);
+    }
+    let error = (total.sum() - exact).abs();
+    assert!(
+        error <= error_bound(&values, exact),
+        "error {} exceeds bound {}",
+        error,
+        error_bound(&values, exact)
+    );
+}
+
+#[test]
+fn test_error_bound_concurrent() {
+    let total = Arc::new(AtomicCompensatedF64::new());
+    let mut all_values = vec![];
+    let mut handles = vec![];
+
+    for t in 0..NUM_THREADS {
+        let values = generate_values(t as u64 + 1, VALUES_PER_THREAD);
+        all_values.extend_from_slice(&values);
+        let total = total.clone();
+        handles.push(thread::spawn(move || {
+            for x in values {
+                total.add(x);
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let exact = exact_sum(&all_values);
+    let error = (total.sum() - exact).abs();
+    assert!(
+        error <= error_bound(&all_values, exact),
+        "error {} exceeds bound {}",
+        error,
+        error_bound(&all_values, exact)
+    );
+}
+
+#[test]
+fn test_reset() {
+    let total = AtomicCompensatedF64::new();
+    total.add(1e16);
+    total.add(1.0);
+    total.reset();
+    assert_eq!(total.sum(), 0.0);
+    total.add(2.0);
+    assert_eq!(total.sum(), 2.0);
+}
+
+#[test]
+fn test_sum_then_reset() {
+    let total = AtomicCompensatedF64::new();
+    for _ in 0..10 {
+        total.add(0.1);
+    }
+    assert_eq!(total.sum_then_reset(), 1.0);
+    assert_eq!(total.sum(), 0.0);
+}
+
+#[test]
+fn test_concurrent_sum_then_reset() {
+    let total = Arc::new(AtomicCompensatedF64::new());
+    let mut handles = vec![];
+
+    for _ in 0..NUM_THREADS {
+        let total = total.clone();
+        handles.push(thread::spawn(move || {
+            for _ in 0..VALUES_PER_THREAD {
+                total.add(0.5);
+            }
+        }));
+    }
+
+    let mut drained = 0.0;
+    for _ in 0..100 {
+        drained += total.sum_then_reset();
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    drained += total.sum_then_reset();
+
+    assert_eq!(drained, (NUM_THREADS * VALUES_PER_THREAD) as f64 * 0.5);
+}
+
+#[test]
+fn test_concurrent_reads_are_monotonic() {
+    // All additions are positive, so the sums observed by a reader must
+    // never decrease.
+    let total = Arc::new(AtomicCompensatedF64::new());
+    let writer = {
+        let total = total.clone();
+        thread::spawn(move || {
+            for _ in 0..VALUES_PER_THREAD {
+                total.add(0.25);
+                total.add(0.75);
+            }
+        })
+    };
+
+    let mut last = 0.0;
+    while !writer.is_finished() {
+        let sum = total.sum();
+        assert!(sum >= last);
+        last = sum;
+    }
+    writer.join().unwrap();
+    assert_eq!(total.sum(), VALUES_PER_THREAD as f64);
+}
+
+#[test]
+fn test_debug_display() {
+    let total = AtomicCompensatedF64::new();
+    total.add(1.5);
+    assert_eq!(format!("{:?}", total), "AtomicCompensatedF64 { sum: 1.5 }");
+    assert_eq!(format!("{}", total), "1.5");
+}
+
+#[test]
+fn test_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<AtomicCompensatedF64>();
+}
@@ -0,0 +1,242 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    AtomicEnum,
+    AtomicEnumValue,
+    SeqCst,
+};
+use prism3_atomic::atomic_enum;
+use std::sync::Arc;
+use std::thread;
+
+atomic_enum! {
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    enum State: u8 {
+        #[default]
+        Idle,
+        Running,
+        Stopping,
+        Stopped,
+    }
+}
+
+atomic_enum! {
+    /// An enum with explicit discriminants.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum Code: u16 {
+        Ok = 200,
+        NotFound = 404,
+        Internal = 500
+    }
+}
+
+atomic_enum! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Wide: u32 {
+        Small = 1,
+        Large = 0x1_0000,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Broken {
+    A,
+    B,
+}
+
+impl AtomicEnumValue for Broken {
+    type Repr = u8;
+
+    fn to_repr(self) -> u8 {
+        match self {
+            Broken::A => 0,
+            Broken::B => 1,
+        }
+    }
+
+    fn from_repr(repr: u8) -> Option<Self> {
+        // Deliberately fails to round-trip `B`
+        match repr {
+            0 => Some(Broken::A),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_macro_repr() {
+    assert_eq!(std::mem::size_of::<State>(), 1);
+    assert_eq!(std::mem::size_of::<Code>(), 2);
+    assert_eq!(std::mem::size_of::<Wide>(), 4);
+    assert_eq!(State::Stopping.to_repr(), 2);
+    assert_eq!(Code::NotFound.to_repr(), 404);
+    assert_eq!(Wide::Large.to_repr(), 0x1_0000);
+}
+
+#[test]
+fn test_macro_from_repr() {
+    for state in [State::Idle, State::Running, State::Stopping, State::Stopped] {
+        assert_eq!(State::from_repr(state.to_repr()), Some(state));
+    }
+    assert_eq!(State::from_repr(4), None);
+    assert_eq!(Code::from_repr(500), Some(Code::Internal));
+    assert_eq!(Code::from_repr(201), None);
+    assert_eq!(Wide::from_repr(0), None);
+}
+
+#[test]
+fn test_new() {
+    let state = AtomicEnum::new(State::Running);
+    assert_eq!(state.load(), State::Running);
+}
+
+#[test]
+fn test_default() {
+    let state = AtomicEnum::<State>::default();
+    assert_eq!(state.load(), State::Idle);
+}
+
+#[test]
+fn test_from() {
+    let code = AtomicEnum::from(Code::NotFound);
+    assert_eq!(code.load(), Code::NotFound);
+}
+
+#[test]
+fn test_with_policy() {
+    let state = AtomicEnum::<State, SeqCst>::with_policy(State::Idle);
+    assert!(state.transition(State::Idle, State::Running).is_ok());
+    assert_eq!(state.load(), State::Running);
+}
+
+#[test]
+fn test_store_and_swap() {
+    let code = AtomicEnum::new(Code::Ok);
+    code.store(Code::Internal);
+    assert_eq!(code.load(), Code::Internal);
+    assert_eq!(code.swap(Code::NotFound), Code::Internal);
+    assert_eq!(code.load(), Code::NotFound);
+}
+
+#[test]
+fn test_wide_repr() {
+    let wide = AtomicEnum::new(Wide::Small);
+    assert_eq!(wide.swap(Wide::Large), Wide::Small);
+    assert_eq!(wide.load(), Wide::Large);
+}
+
+#[test]
+fn test_compare_set() {
+    let state = AtomicEnum::new(State::Idle);
+    assert!(state.compare_set(State::Idle, State::Running).is_ok());
+    assert_eq!(
+        state.compare_set(State::Idle, State::Stopped),
+        Err(State::Running)
+    );
+    assert_eq!(state.load(), State::Running);
+}
+
+#[test]
+fn test_compare_set_weak() {
+    let state = AtomicEnum::new(State::Idle);
+    let mut current = state.load();
+    loop {
+        match state.compare_set_weak(current, State::Stopped) {
+            Ok(_) => break,
+            Err(actual) => current = actual,
+        }
+    }
+    assert_eq!(state.load(), State::Stopped);
+}
+
+#[test]
+fn test_compare_and_exchange() {
+    let state = AtomicEnum::new(State::Idle);
+    assert_eq!(
+        state.compare_and_exchange(State::Idle, State::Running),
+        State::Idle
+    );
+    assert_eq!(
+        state.compare_and_exchange(State::Idle, State::Stopped),
+        State::Running
+    );
+    assert_eq!(state.load(), State::Running);
+}
+
+#[test]
+fn test_transition() {
+    let state = AtomicEnum::new(State::Idle);
+    assert!(state.transition(State::Idle, State::Running).is_ok());
+    assert!(state.transition(State::Running, State::Stopping).is_ok());
+    assert_eq!(
+        state.transition(State::Running, State::Stopping),
+        Err(State::Stopping)
+    );
+    assert!(state.transition(State::Stopping, State::Stopped).is_ok());
+    assert_eq!(state.load(), State::Stopped);
+}
+
+#[test]
+fn test_fetch_update() {
+    let state = AtomicEnum::new(State::Idle);
+    let next = |state| match state {
+        State::Idle => State::Running,
+        State::Running => State::Stopping,
+        State::Stopping | State::Stopped => State::Stopped,
+    };
+    assert_eq!(state.fetch_update(next), State::Idle);
+    assert_eq!(state.fetch_update(next), State::Running);
+    assert_eq!(state.load(), State::Stopping);
+}
+
+#[test]
+fn test_try_update() {
+    let state = AtomicEnum::new(State::Running);
+    let stop = |state| match state {
+        State::Idle | State::Running => Some(State::Stopping),
+        State::Stopping | State::Stopped => None,
+    };
+    assert_eq!(state.try_update(stop), Ok(State::Running));
+    assert_eq!(state.try_update(stop), Err(State::Stopping));
+    assert_eq!(state.load(), State::Stopping);
+}
+
+#[test]
+fn test_debug() {
+    let state = AtomicEnum::new(State::Running);
+    assert_eq!(format!("{:?}", state), "AtomicEnum { value: Running }");
+}
+
+#[test]
+#[should_panic(expected = "does not represent any value")]
+fn test_non_round_tripping_value_panics() {
+    let broken = AtomicEnum::new(Broken::B);
+    broken.load();
+}
+
+#[test]
+fn test_concurrent_transition_has_single_winner() {
+    for _ in 0..100 {
+        let state = Arc::new(AtomicEnum::new(State::Idle));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let state = state.clone();
+                thread::spawn(move || state.transition(State::Idle, State::Running).is_ok())
+            })
+            .collect();
+        let winners = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|won| *won)
+            .count();
+        assert_eq!(winners, 1);
+        assert_eq!(state.load(), State::Running);
+    }
+}
@@ -0,0 +1,87 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::AtomicF16;
+
+test_atomic_half_float!(AtomicF16, atomic_f16_tests);
+
+#[test]
+fn test_to_bits() {
+    assert_eq!(AtomicF16::to_bits(0.0), 0x0000);
+    assert_eq!(AtomicF16::to_bits(-0.0), 0x8000);
+    assert_eq!(AtomicF16::to_bits(1.0), 0x3c00);
+    assert_eq!(AtomicF16::to_bits(-2.0), 0xc000);
+    assert_eq!(AtomicF16::to_bits(0.1), 0x2e66);
+    assert_eq!(AtomicF16::to_bits(1.0 / 3.0), 0x3555);
+    assert_eq!(AtomicF16::to_bits(65504.0), 0x7bff);
+    assert_eq!(AtomicF16::to_bits(f32::INFINITY), 0x7c00);
+    assert_eq!(AtomicF16::to_bits(f32::NEG_INFINITY), 0xfc00);
+}
+
+#[test]
+fn test_to_bits_ties_to_even() {
+    let ulp = 2f32.powi(-10);
+    assert_eq!(AtomicF16::to_bits(1.0 + ulp / 2.0), 0x3c00);
+    assert_eq!(AtomicF16::to_bits(1.0 + ulp * 1.5), 0x3c02);
+    assert_eq!(AtomicF16::to_bits(1.0 + ulp * 0.75), 0x3c01);
+}
+
+#[test]
+fn test_to_bits_overflow() {
+    // 65520 lies halfway between the largest finite value and 2^16
+    assert_eq!(AtomicF16::to_bits(65519.0), 0x7bff);
+    assert_eq!(AtomicF16::to_bits(65520.0), 0x7c00);
+    assert_eq!(AtomicF16::to_bits(1e10), 0x7c00);
+    assert_eq!(AtomicF16::to_bits(-f32::MAX), 0xfc00);
+}
+
+#[test]
+fn test_to_bits_subnormal() {
+    let min_subnormal = 2f32.powi(-24);
+    assert_eq!(AtomicF16::to_bits(2f32.powi(-14)), 0x0400);
+    assert_eq!(AtomicF16::to_bits(min_subnormal), 0x0001);
+    assert_eq!(AtomicF16::to_bits(-min_subnormal * 3.0), 0x8003);
+    assert_eq!(AtomicF16::to_bits(min_subnormal / 2.0), 0x0000);
+    assert_eq!(AtomicF16::to_bits(min_subnormal * 0.75), 0x0001);
+    assert_eq!(AtomicF16::to_bits(min_subnormal * 2.5), 0x0002);
+    assert_eq!(AtomicF16::to_bits(f32::MIN_POSITIVE), 0x0000);
+    // Rounding up the largest subnormal yields the smallest normal value
+    assert_eq!(
+        AtomicF16::to_bits(2f32.powi(-14) - min_subnormal / 4.0),
+        0x0400
+    );
+}
+
+#[test]
+fn test_to_bits_nan() {
+    assert_eq!(AtomicF16::to_bits(f32::NAN) & 0x7c00, 0x7c00);
+    assert_ne!(AtomicF16::to_bits(f32::NAN) & 0x03ff, 0);
+    // A payload only in the low bits must not turn into infinity
+    let nan = f32::from_bits(0x7f80_0001);
+    assert!(AtomicF16::from_bits(AtomicF16::to_bits(nan)).is_nan());
+}
+
+#[test]
+fn test_from_bits() {
+    assert_eq!(AtomicF16::from_bits(0x3c00), 1.0);
+    assert_eq!(AtomicF16::from_bits(0xc000), -2.0);
+    assert_eq!(AtomicF16::from_bits(0x7bff), 65504.0);
+    assert_eq!(AtomicF16::from_bits(0x0001), 2f32.powi(-24));
+    assert_eq!(AtomicF16::from_bits(0x7c00), f32::INFINITY);
+    assert!(AtomicF16::from_bits(0x8000).is_sign_negative());
+    assert!(AtomicF16::from_bits(0x7e00).is_nan());
+}
+
+#[test]
+fn test_round_trip_all_bits() {
+    for bits in 0..=u16::MAX {
+        let value = AtomicF16::from_bits(bits);
+        assert_eq!(AtomicF16::to_bits(value), bits, "bits {:#06x}", bits);
+    }
+}
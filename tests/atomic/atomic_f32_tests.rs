@@ -11,7 +11,9 @@ use prism3_atomic::atomic::{
     Atomic,
     AtomicF32,
     AtomicNumber,
+    NanPolicy,
 };
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::thread;
 
@@ -796,3 +798,450 @@ fn test_atomic_number_concurrent_operations() {
     let result = atomic.load();
     assert!((result - 2.5).abs() < EPSILON);
 }
+
+#[test]
+fn test_load_store_with_ordering() {
+    let atomic = AtomicF32::new(0.0);
+    atomic.store_with_ordering(1.5, Ordering::Relaxed);
+    assert_eq!(atomic.load_with_ordering(Ordering::Relaxed), 1.5);
+    atomic.store_with_ordering(2.5, Ordering::SeqCst);
+    assert_eq!(atomic.load_with_ordering(Ordering::SeqCst), 2.5);
+}
+
+#[test]
+fn test_swap_with_ordering() {
+    let atomic = AtomicF32::new(1.0);
+    assert_eq!(atomic.swap_with_ordering(2.0, Ordering::Relaxed), 1.0);
+    assert_eq!(atomic.load(), 2.0);
+}
+
+#[test]
+fn test_compare_set_with_ordering() {
+    let atomic = AtomicF32::new(1.0);
+    assert!(atomic
+        .compare_set_with_ordering(1.0, 2.0, Ordering::SeqCst, Ordering::Relaxed)
+        .is_ok());
+    assert_eq!(
+        atomic.compare_set_with_ordering(1.0, 3.0, Ordering::SeqCst, Ordering::Relaxed),
+        Err(2.0)
+    );
+    let prev =
+        atomic.compare_and_exchange_with_ordering(2.0, 3.0, Ordering::Release, Ordering::Relaxed);
+    assert_eq!(prev, 2.0);
+    let mut current = atomic.load();
+    while let Err(actual) =
+        atomic.compare_set_weak_with_ordering(current, 4.0, Ordering::AcqRel, Ordering::Acquire)
+    {
+        current = actual;
+    }
+    while atomic.compare_and_exchange_weak_with_ordering(
+        4.0,
+        5.0,
+        Ordering::SeqCst,
+        Ordering::SeqCst,
+    ) != 4.0
+    {}
+    assert_eq!(atomic.load(), 5.0);
+}
+
+#[test]
+fn test_arithmetic_with_ordering() {
+    let atomic = AtomicF32::new(10.0);
+    assert_eq!(
+        atomic.fetch_add_with_ordering(5.0, Ordering::SeqCst, Ordering::Relaxed),
+        10.0
+    );
+    assert_eq!(
+        atomic.fetch_sub_with_ordering(3.0, Ordering::SeqCst, Ordering::Relaxed),
+        15.0
+    );
+    assert_eq!(
+        atomic.fetch_mul_with_ordering(2.0, Ordering::AcqRel, Ordering::Acquire),
+        12.0
+    );
+    assert_eq!(
+        atomic.fetch_div_with_ordering(4.0, Ordering::AcqRel, Ordering::Acquire),
+        24.0
+    );
+    assert_eq!(atomic.load(), 6.0);
+    let old = atomic.fetch_update_with_ordering(Ordering::SeqCst, Ordering::SeqCst, |x| x + 0.5);
+    assert_eq!(old, 6.0);
+    assert_eq!(atomic.load(), 6.5);
+}
+
+#[test]
+fn test_concurrent_fetch_add_with_ordering() {
+    let atomic = Arc::new(AtomicF32::new(0.0));
+    let mut handles = vec![];
+
+    for _ in 0..10 {
+        let atomic = Arc::clone(&atomic);
+        let handle = thread::spawn(move || {
+            for _ in 0..100 {
+                atomic.fetch_add_with_ordering(1.0, Ordering::Relaxed, Ordering::Relaxed);
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(atomic.load(), 1000.0);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid memory ordering for a load")]
+fn test_load_with_release_ordering_panics() {
+    let atomic = AtomicF32::new(1.0);
+    atomic.load_with_ordering(Ordering::Release);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid failure ordering")]
+fn test_fetch_update_with_release_fetch_ordering_panics() {
+    let atomic = AtomicF32::new(1.0);
+    atomic.fetch_update_with_ordering(Ordering::AcqRel, Ordering::AcqRel, |x| x + 1.0);
+}
+
+#[test]
+fn test_add_sub_and_get() {
+    let atomic = AtomicF32::new(10.0);
+    assert_eq!(atomic.add_and_get(5.5), 15.5);
+    assert_eq!(atomic.sub_and_get(3.5), 12.0);
+    assert_eq!(atomic.load(), 12.0);
+}
+
+#[test]
+fn test_update_and_get() {
+    let atomic = AtomicF32::new(10.0);
+    assert_eq!(atomic.update_and_get(|x| x * 2.0), 20.0);
+    assert_eq!(atomic.load(), 20.0);
+}
+
+#[test]
+fn test_and_get_through_traits() {
+    let atomic = AtomicF32::new(1.0);
+    assert_eq!(Atomic::update_and_get(&atomic, |x| x + 1.0), 2.0);
+    assert_eq!(AtomicNumber::add_and_get(&atomic, 0.5), 2.5);
+    assert_eq!(AtomicNumber::sub_and_get(&atomic, 1.5), 1.0);
+    assert_eq!(Atomic::accumulate_and_get(&atomic, 3.0, |a, b| a * b), 3.0);
+    assert_eq!(AtomicNumber::inc_and_get(&atomic), 4.0);
+    assert_eq!(AtomicNumber::dec_and_get(&atomic), 3.0);
+    assert_eq!(AtomicNumber::max_and_get(&atomic, f32::NAN), 3.0);
+    assert_eq!(AtomicNumber::min_and_get(&atomic, -1.0), -1.0);
+}
+
+#[test]
+fn test_accumulate_and_get() {
+    let atomic = AtomicF32::new(10.0);
+    assert_eq!(atomic.accumulate_and_get(2.5, |a, b| a * b), 25.0);
+    assert_eq!(atomic.accumulate_and_get(5.0, |a, b| a - b), 20.0);
+    assert_eq!(atomic.load(), 20.0);
+}
+
+#[test]
+fn test_max_min_and_get() {
+    let atomic = AtomicF32::new(10.0);
+    assert_eq!(atomic.max_and_get(20.5), 20.5);
+    assert_eq!(atomic.max_and_get(15.0), 20.5);
+    assert_eq!(atomic.min_and_get(5.5), 5.5);
+    assert_eq!(atomic.min_and_get(8.0), 5.5);
+    assert_eq!(atomic.load(), 5.5);
+}
+
+#[test]
+fn test_max_min_and_get_ignore_nan() {
+    let atomic = AtomicF32::new(1.0);
+    assert_eq!(atomic.max_and_get(f32::NAN), 1.0);
+    assert_eq!(atomic.min_and_get(f32::NAN), 1.0);
+
+    let atomic = AtomicF32::new(f32::NAN);
+    assert_eq!(atomic.max_and_get(2.0), 2.0);
+    let atomic = AtomicF32::new(f32::NAN);
+    assert_eq!(atomic.min_and_get(-2.0), -2.0);
+
+    let atomic = AtomicF32::new(0.0);
+    assert!(atomic.min_and_get(-0.0).is_sign_negative());
+    assert!(atomic.max_and_get(0.0).is_sign_positive());
+}
+
+#[test]
+fn test_concurrent_add_and_get() {
+    let atomic = Arc::new(AtomicF32::new(0.0));
+    let mut handles = vec![];
+
+    for _ in 0..4 {
+        let atomic = Arc::clone(&atomic);
+        handles.push(thread::spawn(move || {
+            (0..25).map(|_| atomic.add_and_get(1.0)).collect::<Vec<_>>()
+        }));
+    }
+
+    let mut seen: Vec<f32> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+    seen.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let expected: Vec<f32> = (1..=100).map(|x| x as f32).collect();
+    assert_eq!(seen, expected);
+}
+
+#[test]
+fn test_try_update() {
+    let atomic = AtomicF32::new(10.0);
+    let halve = |x: f32| (x > 5.0).then(|| x / 2.0);
+    assert_eq!(atomic.try_update(halve), Ok(10.0));
+    assert_eq!(atomic.try_update(halve), Err(5.0));
+    assert_eq!(atomic.load(), 5.0);
+}
+
+#[test]
+fn test_try_update_result() {
+    let atomic = AtomicF32::new(1.0);
+    let checked_sqrt = |x: f32| {
+        if x >= 0.0 {
+            Ok(x.sqrt())
+        } else {
+            Err("negative")
+        }
+    };
+    assert_eq!(atomic.try_update_result(checked_sqrt), Ok(1.0));
+    atomic.store(-4.0);
+    assert_eq!(atomic.try_update_result(checked_sqrt), Err("negative"));
+    assert_eq!(atomic.load(), -4.0);
+    assert_eq!(Atomic::try_update(&atomic, |x| Some(-x)), Ok(-4.0));
+    assert_eq!(atomic.load(), 4.0);
+}
+
+#[test]
+fn test_fn_mut_closures() {
+    let atomic = AtomicF32::new(1.0);
+    let mut history = Vec::new();
+    atomic.fetch_update(|x| {
+        history.push(x);
+        x + 1.0
+    });
+    atomic.update_and_get(|x| {
+        history.push(x);
+        x * 2.0
+    });
+    assert_eq!(history, vec![1.0, 2.0]);
+    assert_eq!(atomic.load(), 4.0);
+}
+
+#[test]
+fn test_fetch_update_counted() {
+    let atomic = AtomicF32::new(10.0);
+    let mut calls = 0;
+    let (old, attempts) = atomic.fetch_update_counted(|x| {
+        calls += 1;
+        x * 2.0
+    });
+    assert_eq!(old, 10.0);
+    assert_eq!(attempts, calls);
+    assert_eq!(atomic.load(), 20.0);
+}
+
+#[test]
+fn test_fetch_max_min() {
+    let atomic = AtomicF32::new(10.0);
+    assert_eq!(atomic.fetch_max(20.5), 10.0);
+    assert_eq!(atomic.fetch_max(15.0), 20.5);
+    assert_eq!(atomic.load(), 20.5);
+    assert_eq!(atomic.fetch_min(-3.0), 20.5);
+    assert_eq!(atomic.fetch_min(1.0), -3.0);
+    assert_eq!(atomic.load(), -3.0);
+    assert_eq!(atomic.fetch_max(f32::INFINITY), -3.0);
+    assert_eq!(atomic.load(), f32::INFINITY);
+}
+
+#[test]
+fn test_fetch_max_min_signed_zero() {
+    let atomic = AtomicF32::new(-0.0);
+    atomic.fetch_max(0.0);
+    assert!(atomic.load().is_sign_positive());
+    atomic.fetch_min(-0.0);
+    assert!(atomic.load().is_sign_negative());
+    atomic.fetch_min(0.0);
+    assert!(atomic.load().is_sign_negative());
+}
+
+#[test]
+fn test_fetch_max_min_ignore_nan() {
+    let atomic = AtomicF32::new(1.0);
+    assert_eq!(atomic.fetch_max(f32::NAN), 1.0);
+    assert_eq!(atomic.fetch_min(f32::NAN), 1.0);
+    assert_eq!(atomic.load(), 1.0);
+
+    atomic.store(f32::NAN);
+    assert!(atomic.fetch_max(2.0).is_nan());
+    assert_eq!(atomic.load(), 2.0);
+
+    atomic.store(f32::NAN);
+    assert!(atomic.fetch_min(-2.0).is_nan());
+    assert_eq!(atomic.load(), -2.0);
+}
+
+#[test]
+fn test_fetch_max_min_propagate_nan() {
+    let atomic = AtomicF32::new(1.0);
+    let result = atomic.fetch_max_with_nan_policy(f32::NAN, NanPolicy::Propagate);
+    assert_eq!(result, Ok(1.0));
+    assert!(atomic.load().is_nan());
+
+    let result = atomic.fetch_min_with_nan_policy(-5.0, NanPolicy::Propagate);
+    assert!(result.unwrap().is_nan());
+    assert!(atomic.load().is_nan());
+}
+
+#[test]
+fn test_fetch_max_min_reject_nan() {
+    let atomic = AtomicF32::new(1.0);
+    let result = atomic.fetch_max_with_nan_policy(f32::NAN, NanPolicy::Reject);
+    assert_eq!(result, Err(1.0));
+    let result = atomic.fetch_min_with_nan_policy(f32::NAN, NanPolicy::Reject);
+    assert_eq!(result, Err(1.0));
+    assert_eq!(atomic.load(), 1.0);
+
+    assert_eq!(
+        atomic.fetch_max_with_nan_policy(3.0, NanPolicy::Reject),
+        Ok(1.0)
+    );
+    assert_eq!(
+        atomic.fetch_min_with_nan_policy(2.0, NanPolicy::Reject),
+        Ok(3.0)
+    );
+    assert_eq!(atomic.load(), 2.0);
+
+    atomic.store(f32::NAN);
+    let result = atomic.fetch_max_with_nan_policy(3.0, NanPolicy::Reject);
+    assert!(result.unwrap_err().is_nan());
+    assert!(atomic.load().is_nan());
+}
+
+#[test]
+fn test_nan_policy_default() {
+    assert_eq!(NanPolicy::default(), NanPolicy::Ignore);
+}
+
+#[test]
+fn test_concurrent_fetch_max() {
+    let atomic = Arc::new(AtomicF32::new(0.0));
+    let mut handles = vec![];
+
+    for t in 0..10 {
+        let atomic = atomic.clone();
+        handles.push(thread::spawn(move || {
+            for i in 0..100 {
+                atomic.fetch_max((t * 100 + i) as f32 * 0.5);
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(atomic.load(), 999.0 * 0.5);
+}
+
+#[test]
+fn test_compare_set_value() {
+    let atomic = AtomicF32::new(-0.0);
+    assert!(atomic.compare_set(0.0, 1.0).is_err());
+    assert!(atomic.compare_set_value(0.0, 1.0).is_ok());
+    assert_eq!(atomic.load(), 1.0);
+
+    assert_eq!(atomic.compare_set_value(2.0, 3.0), Err(1.0));
+    assert_eq!(atomic.load(), 1.0);
+
+    let nan = f32::from_bits(f32::NAN.to_bits() | 1);
+    atomic.store(nan);
+    assert!(atomic.compare_set(f32::NAN, 2.0).is_err());
+    assert!(atomic.compare_set_value(f32::NAN, 2.0).is_ok());
+    assert_eq!(atomic.load(), 2.0);
+}
+
+#[test]
+fn test_compare_and_exchange_value() {
+    let atomic = AtomicF32::new(0.0);
+    let prev = atomic.compare_and_exchange_value(-0.0, 1.0);
+    assert_eq!(prev.to_bits(), 0.0f32.to_bits());
+    assert_eq!(atomic.load(), 1.0);
+
+    assert_eq!(atomic.compare_and_exchange_value(5.0, 2.0), 1.0);
+    assert_eq!(atomic.load(), 1.0);
+
+    atomic.store(f32::NAN);
+    assert!(atomic.compare_and_exchange_value(f32::NAN, 2.0).is_nan());
+    assert_eq!(atomic.load(), 2.0);
+}
+
+#[test]
+fn test_compare_set_approx() {
+    let atomic = AtomicF32::new(1.0 + 1e-7);
+    assert!(atomic.compare_set(1.0, 2.0).is_err());
+    assert!(atomic.compare_set_approx(1.0, 2.0, 1e-6).is_ok());
+    assert_eq!(atomic.load(), 2.0);
+
+    assert_eq!(atomic.compare_set_approx(2.5, 3.0, 0.1), Err(2.0));
+    assert!(atomic.compare_set_approx(2.5, 3.0, 0.5).is_ok());
+    assert_eq!(atomic.load(), 3.0);
+
+    assert_eq!(atomic.compare_set_approx(3.1, 4.0, -1.0), Err(3.0));
+    assert_eq!(atomic.compare_set_approx(3.1, 4.0, f32::NAN), Err(3.0));
+    assert!(atomic.compare_set_approx(3.0, 4.0, -1.0).is_ok());
+    assert_eq!(atomic.load(), 4.0);
+}
+
+#[test]
+fn test_compare_set_approx_special_values() {
+    let atomic = AtomicF32::new(f32::INFINITY);
+    assert!(atomic.compare_set_approx(f32::INFINITY, 1.0, 0.0).is_ok());
+    assert_eq!(atomic.load(), 1.0);
+
+    atomic.store(f32::NAN);
+    let result = atomic.compare_set_approx(f32::NAN, 1.0, f32::INFINITY);
+    assert!(result.unwrap_err().is_nan());
+
+    atomic.store(-0.0);
+    assert!(atomic.compare_set_approx(0.0, 1.0, 0.0).is_ok());
+    assert_eq!(atomic.load(), 1.0);
+}
+
+#[test]
+fn test_compare_set_ulps() {
+    let next = f32::from_bits(1.0f32.to_bits() + 1);
+    let atomic = AtomicF32::new(next);
+    assert!(atomic.compare_set_ulps(1.0, 2.0, 0).is_err());
+    assert!(atomic.compare_set_ulps(1.0, 2.0, 1).is_ok());
+    assert_eq!(atomic.load(), 2.0);
+
+    let far = f32::from_bits(2.0f32.to_bits() + 10);
+    assert_eq!(atomic.compare_set_ulps(far, 3.0, 9), Err(2.0));
+    assert!(atomic.compare_set_ulps(far, 3.0, 10).is_ok());
+    assert_eq!(atomic.load(), 3.0);
+}
+
+#[test]
+fn test_compare_set_ulps_special_values() {
+    let atomic = AtomicF32::new(-0.0);
+    assert!(atomic.compare_set_ulps(0.0, 1.0, 0).is_ok());
+
+    // The smallest subnormals of either sign are two steps apart
+    let tiny = f32::from_bits(1);
+    atomic.store(-tiny);
+    assert!(atomic.compare_set_ulps(tiny, 1.0, 1).is_err());
+    assert!(atomic.compare_set_ulps(tiny, 1.0, 2).is_ok());
+
+    atomic.store(f32::MAX);
+    assert!(atomic.compare_set_ulps(f32::INFINITY, 1.0, 1).is_ok());
+
+    atomic.store(f32::NAN);
+    let result = atomic.compare_set_ulps(f32::NAN, 1.0, u32::MAX);
+    assert!(result.unwrap_err().is_nan());
+}
@@ -0,0 +1,108 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    AtomicI128,
+    RawAtomicI128,
+};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+
+test_atomic_integer!(AtomicI128, i128, atomic_i128_tests);
+
+#[test]
+fn test_wide_values() {
+    let atomic = AtomicI128::new(i128::MIN);
+    assert_eq!(atomic.load(), i128::MIN);
+    assert_eq!(atomic.swap(i128::MAX), i128::MIN);
+    assert!(atomic.compare_set(i128::MAX, -1).is_ok());
+    assert_eq!(atomic.load(), -1);
+}
+
+#[test]
+fn test_sign_across_halves() {
+    let atomic = AtomicI128::new(0);
+    assert_eq!(atomic.fetch_dec(), 0);
+    assert_eq!(atomic.load(), -1);
+    atomic.fetch_sub(i64::MAX as i128);
+    assert_eq!(atomic.load(), i64::MIN as i128);
+    atomic.fetch_sub(1);
+    assert_eq!(atomic.load(), i64::MIN as i128 - 1);
+}
+
+#[test]
+fn test_fetch_max_min_signed() {
+    let atomic = AtomicI128::new(-1);
+    assert_eq!(atomic.fetch_max(i128::MIN), -1);
+    assert_eq!(atomic.load(), -1);
+    assert_eq!(atomic.fetch_min(i128::MIN), -1);
+    assert_eq!(atomic.load(), i128::MIN);
+    assert_eq!(atomic.fetch_max(1 << 100), i128::MIN);
+    assert_eq!(atomic.load(), 1 << 100);
+}
+
+#[test]
+fn test_raw_fetch_max_min_signed() {
+    let raw = RawAtomicI128::new(-5);
+    assert_eq!(raw.fetch_max(-10, Ordering::AcqRel), -5);
+    assert_eq!(raw.fetch_min(-10, Ordering::AcqRel), -5);
+    assert_eq!(raw.load(Ordering::Acquire), -10);
+    assert_eq!(raw.into_inner(), -10);
+}
+
+#[test]
+fn test_raw_locked_operations_signed() {
+    let raw = RawAtomicI128::new(-5);
+    // SAFETY: the value is only accessed through the locked methods.
+    unsafe {
+        assert_eq!(raw.locked_compare_exchange(-5, i128::MIN), Ok(-5));
+        assert_eq!(raw.locked_compare_exchange(-5, 0), Err(i128::MIN));
+        assert_eq!(raw.locked_update(|x| x.max(-1)), i128::MIN);
+        assert_eq!(raw.locked_load(), -1);
+    }
+}
+
+#[test]
+fn test_large_nanosecond_counter() {
+    // More nanoseconds than fit in an i64
+    let elapsed = AtomicI128::new(i64::MAX as i128);
+    elapsed.fetch_add(1_000_000_000);
+    assert_eq!(elapsed.load(), i64::MAX as i128 + 1_000_000_000);
+}
+
+#[test]
+fn test_concurrent_add_and_sub() {
+    let atomic = Arc::new(AtomicI128::new(0));
+    let mut handles = vec![];
+
+    for t in 0..8 {
+        let atomic = atomic.clone();
+        handles.push(thread::spawn(move || {
+            for _ in 0..10_000 {
+                if t % 2 == 0 {
+                    atomic.fetch_add(1 << 70);
+                } else {
+                    atomic.fetch_sub((1 << 70) + 1);
+                }
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(atomic.load(), -40_000);
+}
+
+#[test]
+fn test_is_lock_free() {
+    assert_eq!(AtomicI128::is_lock_free(), RawAtomicI128::is_lock_free());
+}
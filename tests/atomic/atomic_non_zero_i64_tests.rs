@@ -0,0 +1,55 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+test_atomic_non_zero!(
+    AtomicNonZeroI64,
+    AtomicOptionNonZeroI64,
+    NonZeroI64,
+    i64,
+    atomic_non_zero_i64_tests
+);
+
+mod atomic_non_zero_i64_signed_tests {
+    use prism3_atomic::atomic::AtomicNonZeroI64;
+    use std::num::NonZeroI64;
+
+    fn nz(value: i64) -> NonZeroI64 {
+        NonZeroI64::new(value).unwrap()
+    }
+
+    #[test]
+    fn test_negative_values() {
+        let atomic = AtomicNonZeroI64::new(nz(-3));
+        assert_eq!(atomic.fetch_add(5), Ok(nz(-3)));
+        assert_eq!(atomic.load(), nz(2));
+        assert_eq!(atomic.fetch_sub(4), Ok(nz(2)));
+        assert_eq!(atomic.load(), nz(-2));
+        assert_eq!(atomic.fetch_max(nz(-5)), nz(-2));
+        assert_eq!(atomic.fetch_min(nz(-5)), nz(-2));
+        assert_eq!(atomic.load(), nz(-5));
+    }
+
+    #[test]
+    fn test_negative_delta_never_reaches_zero() {
+        let atomic = AtomicNonZeroI64::new(nz(1));
+        assert_eq!(atomic.fetch_add(-1), Err(nz(1)));
+        atomic.store(nz(-1));
+        assert_eq!(atomic.fetch_sub(-1), Err(nz(-1)));
+        assert_eq!(atomic.fetch_inc(), Err(nz(-1)));
+        assert_eq!(atomic.load(), nz(-1));
+    }
+
+    #[test]
+    fn test_fetch_dec_underflow() {
+        let atomic = AtomicNonZeroI64::new(NonZeroI64::MIN);
+        assert_eq!(atomic.fetch_dec(), Err(NonZeroI64::MIN));
+        assert_eq!(atomic.fetch_add(i64::MIN), Err(NonZeroI64::MIN));
+        assert_eq!(atomic.load(), NonZeroI64::MIN);
+    }
+}
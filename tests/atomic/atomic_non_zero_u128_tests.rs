@@ -0,0 +1,16 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+test_atomic_non_zero!(
+    AtomicNonZeroU128,
+    AtomicOptionNonZeroU128,
+    NonZeroU128,
+    u128,
+    atomic_non_zero_u128_tests
+);
@@ -0,0 +1,16 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+test_atomic_non_zero!(
+    AtomicNonZeroU16,
+    AtomicOptionNonZeroU16,
+    NonZeroU16,
+    u16,
+    atomic_non_zero_u16_tests
+);
@@ -0,0 +1,16 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+test_atomic_non_zero!(
+    AtomicNonZeroU32,
+    AtomicOptionNonZeroU32,
+    NonZeroU32,
+    u32,
+    atomic_non_zero_u32_tests
+);
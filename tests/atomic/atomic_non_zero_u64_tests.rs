@@ -0,0 +1,16 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+test_atomic_non_zero!(
+    AtomicNonZeroU64,
+    AtomicOptionNonZeroU64,
+    NonZeroU64,
+    u64,
+    atomic_non_zero_u64_tests
+);
@@ -0,0 +1,16 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+test_atomic_non_zero!(
+    AtomicNonZeroU8,
+    AtomicOptionNonZeroU8,
+    NonZeroU8,
+    u8,
+    atomic_non_zero_u8_tests
+);
@@ -0,0 +1,16 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+test_atomic_non_zero!(
+    AtomicNonZeroUsize,
+    AtomicOptionNonZeroUsize,
+    NonZeroUsize,
+    usize,
+    atomic_non_zero_usize_tests
+);
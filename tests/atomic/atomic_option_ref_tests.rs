@@ -0,0 +1,295 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    Atomic,
+    AtomicOptionRef,
+    SeqCst,
+    YieldBackoff,
+};
+use std::sync::atomic::{
+    AtomicUsize as StdAtomicUsize,
+    Ordering,
+};
+use std::sync::{
+    Arc,
+    Barrier,
+    Weak,
+};
+use std::thread;
+
+#[test]
+fn test_new_and_default() {
+    let atomic = AtomicOptionRef::new(Some(Arc::new(42)));
+    assert_eq!(atomic.load().as_deref(), Some(&42));
+    let empty: AtomicOptionRef<i32> = AtomicOptionRef::default();
+    assert!(empty.load().is_none());
+}
+
+#[test]
+fn test_from() {
+    let atomic: AtomicOptionRef<i32> = Arc::new(1).into();
+    assert_eq!(atomic.load().as_deref(), Some(&1));
+    let atomic: AtomicOptionRef<i32> = None.into();
+    assert!(atomic.load().is_none());
+}
+
+#[test]
+fn test_with_policy() {
+    let atomic = AtomicOptionRef::<_, SeqCst>::with_policy(Some(Arc::new(3)));
+    assert_eq!(atomic.take().as_deref(), Some(&3));
+    assert!(atomic.load().is_none());
+}
+
+#[test]
+fn test_store_and_swap() {
+    let atomic = AtomicOptionRef::new(None);
+    atomic.store(Some(Arc::new(1)));
+    assert_eq!(atomic.swap(None).as_deref(), Some(&1));
+    assert!(atomic.swap(Some(Arc::new(2))).is_none());
+    assert_eq!(atomic.load().as_deref(), Some(&2));
+}
+
+#[test]
+fn test_take() {
+    let value = Arc::new(String::from("message"));
+    let slot = AtomicOptionRef::new(Some(value.clone()));
+    let taken = slot.take().unwrap();
+    assert!(Arc::ptr_eq(&taken, &value));
+    assert!(slot.take().is_none());
+    drop(taken);
+    assert_eq!(Arc::strong_count(&value), 1);
+}
+
+#[test]
+fn test_compare_set() {
+    let first = Arc::new(1);
+    let atomic = AtomicOptionRef::new(None);
+    assert!(atomic.compare_set(None, Some(first.clone())).is_ok());
+
+    let actual = atomic.compare_set(None, Some(Arc::new(2))).unwrap_err();
+    assert!(Arc::ptr_eq(actual.as_ref().unwrap(), &first));
+
+    // An equal but distinct value does not match
+    let equal = Arc::new(1);
+    assert!(atomic.compare_set(Some(&equal), None).is_err());
+
+    assert!(atomic.compare_set(Some(&first), None).is_ok());
+    assert!(atomic.load().is_none());
+    assert_eq!(Arc::strong_count(&first), 2);
+}
+
+#[test]
+fn test_compare_set_weak() {
+    let atomic = AtomicOptionRef::new(None);
+    let mut current = atomic.load();
+    loop {
+        match atomic.compare_set_weak(current.as_ref(), Some(Arc::new(5))) {
+            Ok(_) => break,
+            Err(actual) => current = actual,
+        }
+    }
+    assert_eq!(atomic.load().as_deref(), Some(&5));
+}
+
+#[test]
+fn test_compare_and_exchange() {
+    let first = Arc::new(1);
+    let atomic = AtomicOptionRef::new(Some(first.clone()));
+    let prev = atomic.compare_and_exchange(None, Some(Arc::new(2)));
+    assert!(Arc::ptr_eq(prev.as_ref().unwrap(), &first));
+    let prev = atomic.compare_and_exchange(Some(&first), None);
+    assert!(Arc::ptr_eq(prev.as_ref().unwrap(), &first));
+    assert!(atomic.load().is_none());
+}
+
+#[test]
+fn test_get_or_init() {
+    let cache = AtomicOptionRef::new(None);
+    let value = cache.get_or_init(|| Arc::new(vec![1, 2, 3]));
+    let again = cache.get_or_init(|| panic!("already initialized"));
+    assert!(Arc::ptr_eq(&value, &again));
+}
+
+#[test]
+fn test_get_or_init_concurrent() {
+    const THREADS: usize = 8;
+    let cache = Arc::new(AtomicOptionRef::new(None));
+    let barrier = Arc::new(Barrier::new(THREADS));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|i| {
+            let cache = cache.clone();
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                cache.get_or_init(|| Arc::new(i))
+            })
+        })
+        .collect();
+    let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    let stored = cache.load().unwrap();
+    for result in results {
+        assert!(Arc::ptr_eq(&result, &stored));
+    }
+}
+
+#[test]
+fn test_fetch_update() {
+    let atomic = AtomicOptionRef::new(None);
+    let old = atomic.fetch_update(|x| Some(Arc::new(x.as_deref().map_or(1, |v| v + 1))));
+    assert!(old.is_none());
+    let old = atomic.fetch_update(|x| Some(Arc::new(x.as_deref().map_or(1, |v| v + 1))));
+    assert_eq!(old.as_deref(), Some(&1));
+    let old = atomic.fetch_update_with_backoff(YieldBackoff::default(), |_| None);
+    assert_eq!(old.as_deref(), Some(&2));
+    assert!(atomic.load().is_none());
+}
+
+#[test]
+fn test_update_and_get() {
+    let atomic = AtomicOptionRef::new(Some(Arc::new(3)));
+    let new = atomic.update_and_get(|x| x.as_deref().map(|v| Arc::new(v * 2)));
+    assert_eq!(new.as_deref(), Some(&6));
+    assert_eq!(atomic.load().as_deref(), Some(&6));
+}
+
+#[test]
+fn test_try_update() {
+    let slot = AtomicOptionRef::new(None);
+    let fill = |x: &Option<Arc<i32>>| x.is_none().then(|| Some(Arc::new(7)));
+    assert!(slot.try_update(fill).unwrap().is_none());
+    assert_eq!(slot.try_update(fill).unwrap_err().as_deref(), Some(&7));
+    let result: Result<_, &str> = slot.try_update_result(|_| Err("rejected"));
+    assert_eq!(result.unwrap_err(), "rejected");
+}
+
+#[test]
+fn test_atomic_trait() {
+    fn exchange<A: Atomic>(atomic: &A, current: A::Value, new: A::Value) -> A::Value {
+        atomic.compare_exchange(current, new)
+    }
+
+    let first = Arc::new(1);
+    let atomic = AtomicOptionRef::new(Some(first.clone()));
+    let witnessed = exchange(&atomic, None, Some(Arc::new(2)));
+    assert!(Arc::ptr_eq(witnessed.as_ref().unwrap(), &first));
+    let witnessed = exchange(&atomic, Some(first.clone()), None);
+    assert!(Arc::ptr_eq(witnessed.as_ref().unwrap(), &first));
+    assert!(Atomic::load(&atomic).is_none());
+}
+
+#[test]
+fn test_clone_and_debug() {
+    let atomic = AtomicOptionRef::new(Some(Arc::new(1)));
+    let cloned = atomic.clone();
+    atomic.store(None);
+    assert_eq!(cloned.load().as_deref(), Some(&1));
+    assert_eq!(format!("{:?}", atomic), "AtomicOptionRef { value: None }");
+    assert_eq!(
+        format!("{:?}", cloned),
+        "AtomicOptionRef { value: Some(1) }"
+    );
+}
+
+#[test]
+fn test_drop_releases_reference() {
+    struct Counted(Arc<StdAtomicUsize>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let drops = Arc::new(StdAtomicUsize::new(0));
+    let atomic = AtomicOptionRef::new(Some(Arc::new(Counted(drops.clone()))));
+    atomic.store(Some(Arc::new(Counted(drops.clone()))));
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+    drop(atomic);
+    assert_eq!(drops.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_failed_cas_drops_rejected_value_outside_critical_section() {
+    struct TakeOnDrop(Option<Weak<AtomicOptionRef<TakeOnDrop>>>);
+
+    impl Drop for TakeOnDrop {
+        fn drop(&mut self) {
+            if let Some(atomic) = self.0.take().and_then(|t| t.upgrade()) {
+                atomic.take();
+            }
+        }
+    }
+
+    let atomic = Arc::new(AtomicOptionRef::new(Some(Arc::new(TakeOnDrop(None)))));
+    let new = Arc::new(TakeOnDrop(Some(Arc::downgrade(&atomic))));
+    // Dropping the rejected value writes to the same atomic, which waits
+    // for a grace period and would deadlock if the CAS were still pinned.
+    let actual = atomic.compare_set(None, Some(new)).unwrap_err();
+    assert!(actual.is_some());
+    drop(actual);
+    assert!(atomic.load().is_none());
+}
+
+#[test]
+fn test_concurrent_handoff() {
+    const ITEMS: usize = 1_000;
+    let slot = Arc::new(AtomicOptionRef::new(None));
+    let producer = {
+        let slot = slot.clone();
+        thread::spawn(move || {
+            for i in 0..ITEMS {
+                let item = Arc::new(i);
+                while slot.compare_set(None, Some(item.clone())).is_err() {
+                    thread::yield_now();
+                }
+            }
+        })
+    };
+    let mut received = 0;
+    while received < ITEMS {
+        if let Some(item) = slot.take() {
+            assert_eq!(*item, received);
+            received += 1;
+        } else {
+            thread::yield_now();
+        }
+    }
+    producer.join().unwrap();
+    assert!(slot.load().is_none());
+}
+
+#[test]
+fn test_concurrent_load_and_store() {
+    let atomic = Arc::new(AtomicOptionRef::new(Some(Arc::new(0))));
+    let writer = {
+        let atomic = atomic.clone();
+        thread::spawn(move || {
+            for i in 0..2_000 {
+                atomic.store(if i % 2 == 0 { None } else { Some(Arc::new(i)) });
+            }
+        })
+    };
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let atomic = atomic.clone();
+            thread::spawn(move || {
+                for _ in 0..2_000 {
+                    if let Some(value) = atomic.load() {
+                        assert!(*value % 2 == 1 || *value == 0);
+                    }
+                }
+            })
+        })
+        .collect();
+    writer.join().unwrap();
+    for reader in readers {
+        reader.join().unwrap();
+    }
+}
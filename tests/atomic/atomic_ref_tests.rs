@@ -10,6 +10,11 @@
 use prism3_atomic::atomic::{
     Atomic,
     AtomicRef,
+    YieldBackoff,
+};
+use std::sync::atomic::{
+    AtomicUsize as StdAtomicUsize,
+    Ordering,
 };
 use std::sync::Arc;
 use std::thread;
@@ -937,3 +942,520 @@ fn test_direct_compare_and_exchange_weak_in_loop() {
     assert_eq!(atomic.load().value, 1);
     assert_eq!(atomic.load().name, "updated");
 }
+
+const RECLAIM_MAGIC: u64 = 0x5eed_cafe_f00d_beef;
+const RECLAIM_ITERATIONS: usize = if cfg!(miri) { 50 } else { 5_000 };
+
+/// Value that tracks how many instances are alive and whether it has been
+/// dropped, used to detect use-after-free and leaks in the stress tests.
+struct Tracked {
+    magic: u64,
+    value: usize,
+    live: Arc<StdAtomicUsize>,
+}
+
+impl Tracked {
+    fn new(value: usize, live: &Arc<StdAtomicUsize>) -> Arc<Self> {
+        live.fetch_add(1, Ordering::SeqCst);
+        Arc::new(Tracked {
+            magic: RECLAIM_MAGIC,
+            value,
+            live: live.clone(),
+        })
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        assert_eq!(self.magic, RECLAIM_MAGIC, "double drop detected");
+        self.magic = 0;
+        self.live.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_concurrent_load_store_reclamation() {
+    let live = Arc::new(StdAtomicUsize::new(0));
+    let atomic = Arc::new(AtomicRef::new(Tracked::new(0, &live)));
+    let mut handles = vec![];
+
+    for _ in 0..4 {
+        let atomic = atomic.clone();
+        handles.push(thread::spawn(move || {
+            for _ in 0..RECLAIM_ITERATIONS {
+                let current = atomic.load();
+                assert_eq!(current.magic, RECLAIM_MAGIC);
+            }
+        }));
+    }
+    for writer in 0..2 {
+        let atomic = atomic.clone();
+        let live = live.clone();
+        handles.push(thread::spawn(move || {
+            for i in 0..RECLAIM_ITERATIONS {
+                atomic.store(Tracked::new(writer * RECLAIM_ITERATIONS + i, &live));
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(live.load(Ordering::SeqCst), 1);
+    drop(atomic);
+    assert_eq!(live.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_concurrent_swap_and_cas_reclamation() {
+    let live = Arc::new(StdAtomicUsize::new(0));
+    let atomic = Arc::new(AtomicRef::new(Tracked::new(0, &live)));
+    let mut handles = vec![];
+
+    for _ in 0..2 {
+        let atomic = atomic.clone();
+        handles.push(thread::spawn(move || {
+            for _ in 0..RECLAIM_ITERATIONS {
+                let current = atomic.load();
+                assert_eq!(current.magic, RECLAIM_MAGIC);
+            }
+        }));
+    }
+    {
+        let atomic = atomic.clone();
+        let live = live.clone();
+        handles.push(thread::spawn(move || {
+            for i in 0..RECLAIM_ITERATIONS {
+                let old = atomic.swap(Tracked::new(i, &live));
+                assert_eq!(old.magic, RECLAIM_MAGIC);
+            }
+        }));
+    }
+    for _ in 0..2 {
+        let atomic = atomic.clone();
+        let live = live.clone();
+        handles.push(thread::spawn(move || {
+            for _ in 0..RECLAIM_ITERATIONS {
+                let current = atomic.load();
+                let next = Tracked::new(current.value + 1, &live);
+                if let Err(actual) = atomic.compare_set_weak(&current, next) {
+                    assert_eq!(actual.magic, RECLAIM_MAGIC);
+                }
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(live.load(Ordering::SeqCst), 1);
+    drop(atomic);
+    assert_eq!(live.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_concurrent_fetch_update_reclamation() {
+    let live = Arc::new(StdAtomicUsize::new(0));
+    let atomic = Arc::new(AtomicRef::new(Tracked::new(0, &live)));
+    let mut handles = vec![];
+
+    for _ in 0..4 {
+        let atomic = atomic.clone();
+        let live = live.clone();
+        handles.push(thread::spawn(move || {
+            for _ in 0..RECLAIM_ITERATIONS / 10 {
+                atomic.fetch_update(|current| Tracked::new(current.value + 1, &live));
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(atomic.load().value, 4 * (RECLAIM_ITERATIONS / 10));
+    drop(atomic);
+    assert_eq!(live.load(Ordering::SeqCst), 0);
+}
+
+/// Value whose destructor stores a new value into the atomic holding it.
+struct StoreOnDrop {
+    target: Option<std::sync::Weak<AtomicRef<StoreOnDrop>>>,
+}
+
+impl Drop for StoreOnDrop {
+    fn drop(&mut self) {
+        if let Some(atomic) = self.target.take().and_then(|t| t.upgrade()) {
+            atomic.store(Arc::new(StoreOnDrop { target: None }));
+        }
+    }
+}
+
+#[test]
+fn test_failed_cas_drops_rejected_value_outside_critical_section() {
+    let atomic = Arc::new(AtomicRef::new(Arc::new(StoreOnDrop { target: None })));
+    let current = atomic.load();
+    let stale = Arc::new(StoreOnDrop { target: None });
+    let new = Arc::new(StoreOnDrop {
+        target: Some(Arc::downgrade(&atomic)),
+    });
+    // Dropping the rejected value stores into the same atomic, which waits
+    // for a grace period and would deadlock if the CAS were still pinned.
+    let actual = atomic.compare_set(&stale, new).unwrap_err();
+    assert!(Arc::ptr_eq(&actual, &current));
+    assert!(!Arc::ptr_eq(&atomic.load(), &current));
+}
+
+#[test]
+fn test_load_store_with_ordering() {
+    let atomic = AtomicRef::new(Arc::new(1));
+    atomic.store_with_ordering(Arc::new(2), Ordering::Release);
+    assert_eq!(*atomic.load_with_ordering(Ordering::Acquire), 2);
+    atomic.store_with_ordering(Arc::new(3), Ordering::SeqCst);
+    assert_eq!(*atomic.load_with_ordering(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_swap_with_ordering() {
+    let atomic = AtomicRef::new(Arc::new(1));
+    let old = atomic.swap_with_ordering(Arc::new(2), Ordering::SeqCst);
+    assert_eq!(*old, 1);
+    assert_eq!(*atomic.load(), 2);
+}
+
+#[test]
+fn test_compare_set_with_ordering() {
+    let atomic = AtomicRef::new(Arc::new(1));
+    let current = atomic.load();
+    let stale = Arc::new(1);
+
+    let result =
+        atomic.compare_set_with_ordering(&stale, Arc::new(2), Ordering::SeqCst, Ordering::SeqCst);
+    assert!(Arc::ptr_eq(&result.unwrap_err(), &current));
+    assert!(atomic
+        .compare_set_with_ordering(&current, Arc::new(2), Ordering::SeqCst, Ordering::Acquire)
+        .is_ok());
+
+    let current = atomic.load();
+    let prev = atomic.compare_and_exchange_with_ordering(
+        &current,
+        Arc::new(3),
+        Ordering::AcqRel,
+        Ordering::SeqCst,
+    );
+    assert!(Arc::ptr_eq(&prev, &current));
+
+    let mut current = atomic.load();
+    while let Err(actual) = atomic.compare_set_weak_with_ordering(
+        &current,
+        Arc::new(4),
+        Ordering::AcqRel,
+        Ordering::Acquire,
+    ) {
+        current = actual;
+    }
+    let mut current = atomic.load();
+    loop {
+        let prev = atomic.compare_and_exchange_weak_with_ordering(
+            &current,
+            Arc::new(5),
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        );
+        if Arc::ptr_eq(&prev, &current) {
+            break;
+        }
+        current = prev;
+    }
+    assert_eq!(*atomic.load(), 5);
+}
+
+#[test]
+fn test_fetch_update_with_ordering() {
+    let atomic = AtomicRef::new(Arc::new(10));
+    let old = atomic
+        .fetch_update_with_ordering(Ordering::SeqCst, Ordering::SeqCst, |x| Arc::new(**x * 2));
+    assert_eq!(*old, 10);
+    assert_eq!(*atomic.load(), 20);
+}
+
+#[test]
+#[should_panic(expected = "memory ordering must be Acquire or SeqCst")]
+fn test_load_with_relaxed_ordering_panics() {
+    let atomic = AtomicRef::new(Arc::new(1));
+    atomic.load_with_ordering(Ordering::Relaxed);
+}
+
+#[test]
+#[should_panic(expected = "memory ordering must be Release or SeqCst")]
+fn test_store_with_relaxed_ordering_panics() {
+    let atomic = AtomicRef::new(Arc::new(1));
+    atomic.store_with_ordering(Arc::new(2), Ordering::Relaxed);
+}
+
+#[test]
+#[should_panic(expected = "memory ordering must be AcqRel or SeqCst")]
+fn test_compare_set_with_release_success_ordering_panics() {
+    let atomic = AtomicRef::new(Arc::new(1));
+    let current = atomic.load();
+    let _ = atomic.compare_set_with_ordering(
+        &current,
+        Arc::new(2),
+        Ordering::Release,
+        Ordering::Acquire,
+    );
+}
+
+#[test]
+fn test_update_and_get() {
+    let atomic = AtomicRef::new(Arc::new(10));
+    let new = atomic.update_and_get(|x| Arc::new(**x * 2));
+    assert_eq!(*new, 20);
+    assert!(Arc::ptr_eq(&new, &atomic.load()));
+
+    let new = Atomic::update_and_get(&atomic, |x| Arc::new(*x + 1));
+    assert_eq!(*new, 21);
+    assert!(Arc::ptr_eq(&new, &atomic.load()));
+}
+
+#[test]
+fn test_accumulate_and_get() {
+    let atomic = AtomicRef::new(Arc::new(10));
+    let new = atomic.accumulate_and_get(Arc::new(5), |a, b| Arc::new(**a + **b));
+    assert_eq!(*new, 15);
+    assert!(Arc::ptr_eq(&new, &atomic.load()));
+
+    let new = Atomic::accumulate_and_get(&atomic, Arc::new(2), |a, b| Arc::new(*a * *b));
+    assert_eq!(*new, 30);
+    assert!(Arc::ptr_eq(&new, &atomic.load()));
+}
+
+#[test]
+fn test_concurrent_update_and_get() {
+    let atomic = Arc::new(AtomicRef::new(Arc::new(0)));
+    let mut handles = vec![];
+
+    for _ in 0..4 {
+        let atomic = Arc::clone(&atomic);
+        handles.push(thread::spawn(move || {
+            (0..25)
+                .map(|_| *atomic.update_and_get(|x| Arc::new(**x + 1)))
+                .collect::<Vec<_>>()
+        }));
+    }
+
+    let mut seen: Vec<i32> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+    seen.sort();
+    assert_eq!(seen, (1..=100).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_try_update() {
+    let atomic = AtomicRef::new(Arc::new(10));
+    let double = |x: &Arc<i32>| (**x < 20).then(|| Arc::new(**x * 2));
+    assert_eq!(*atomic.try_update(double).unwrap(), 10);
+
+    let current = atomic.try_update(double).unwrap_err();
+    assert_eq!(*current, 20);
+    assert!(Arc::ptr_eq(&current, &atomic.load()));
+}
+
+#[test]
+fn test_try_update_result() {
+    let atomic = AtomicRef::new(Arc::new(String::from("a")));
+    let old = atomic.try_update_result(|x| Ok::<_, ()>(Arc::new(format!("{}b", x))));
+    assert_eq!(*old.unwrap(), "a");
+    let result = atomic.try_update_result(|_| Err("busy"));
+    assert_eq!(result.unwrap_err(), "busy");
+    assert_eq!(*atomic.load(), "ab");
+
+    let result = Atomic::try_update(&atomic, |_| None);
+    assert_eq!(*result.unwrap_err(), "ab");
+}
+
+#[test]
+fn test_fn_mut_closures() {
+    let atomic = AtomicRef::new(Arc::new(1));
+    let mut seen = Vec::new();
+    atomic.fetch_update(|x| {
+        seen.push(**x);
+        Arc::new(**x + 1)
+    });
+    atomic.update_and_get(|x| {
+        seen.push(**x);
+        Arc::new(**x + 1)
+    });
+    assert_eq!(seen, vec![1, 2]);
+    assert_eq!(*atomic.load(), 3);
+}
+
+#[test]
+fn test_fetch_update_counted() {
+    let atomic = AtomicRef::new(Arc::new(10));
+    let mut calls = 0;
+    let (old, attempts) = atomic.fetch_update_counted(|x| {
+        calls += 1;
+        Arc::new(**x * 2)
+    });
+    assert_eq!(*old, 10);
+    assert_eq!(attempts, calls);
+    assert_eq!(*atomic.load(), 20);
+}
+
+#[test]
+fn test_compare_set_by_value() {
+    let atomic = AtomicRef::new(Arc::new(TestData {
+        value: 1,
+        name: "config".to_string(),
+    }));
+    let rebuilt = TestData {
+        value: 1,
+        name: "config".to_string(),
+    };
+    let new = Arc::new(TestData {
+        value: 2,
+        name: "config".to_string(),
+    });
+    assert!(atomic.compare_set_by_value(&rebuilt, new.clone()).is_ok());
+    assert!(Arc::ptr_eq(&atomic.load(), &new));
+
+    let actual = atomic
+        .compare_set_by_value(&rebuilt, Arc::new(rebuilt.clone()))
+        .unwrap_err();
+    assert!(Arc::ptr_eq(&actual, &new));
+    assert_eq!(atomic.load().value, 2);
+}
+
+#[test]
+fn test_compare_set_by() {
+    let atomic = AtomicRef::new(Arc::new(5));
+    let mut calls = 0;
+    assert!(atomic
+        .compare_set_by(
+            |x| {
+                calls += 1;
+                *x < 10
+            },
+            Arc::new(10),
+        )
+        .is_ok());
+    assert_eq!(calls, 1);
+    assert_eq!(
+        *atomic.compare_set_by(|x| *x < 10, Arc::new(0)).unwrap_err(),
+        10
+    );
+    assert_eq!(*atomic.load(), 10);
+}
+
+#[test]
+fn test_compare_set_by_value_concurrent() {
+    // Each round stores a freshly allocated 0, which must still match by
+    // value although it is never the `Arc` the other threads loaded.
+    let atomic = Arc::new(AtomicRef::new(Arc::new(0)));
+    let successes = Arc::new(StdAtomicUsize::new(0));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let atomic = atomic.clone();
+            let successes = successes.clone();
+            thread::spawn(move || {
+                for _ in 0..200 {
+                    atomic.store(Arc::new(0));
+                    if atomic.compare_set_by_value(&0, Arc::new(1)).is_ok() {
+                        successes.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert!(successes.load(Ordering::Relaxed) > 0);
+    assert!(*atomic.load() <= 1);
+}
+
+#[test]
+fn test_trait_compare_exchange_returns_witnessed_value() {
+    fn exchange<A: Atomic<Value = Arc<i32>>>(
+        atomic: &A,
+        current: Arc<i32>,
+        new: Arc<i32>,
+    ) -> Arc<i32> {
+        atomic.compare_exchange(current, new)
+    }
+
+    let stored = Arc::new(42);
+    let atomic = AtomicRef::new(stored.clone());
+    let witnessed = exchange(&atomic, Arc::new(42), Arc::new(100));
+    assert!(Arc::ptr_eq(&witnessed, &stored));
+    assert!(Arc::ptr_eq(&atomic.load(), &stored));
+
+    let witnessed = Atomic::compare_exchange_weak(&atomic, Arc::new(0), Arc::new(1));
+    assert!(Arc::ptr_eq(&witnessed, &stored));
+}
+
+#[test]
+fn test_rcu() {
+    let atomic = AtomicRef::new(Arc::new(TestData {
+        value: 1,
+        name: "config".to_string(),
+    }));
+    let update = atomic.rcu(|data| data.value += 1);
+    assert_eq!(update.old().value, 1);
+    assert_eq!(update.updated().value, 2);
+    assert_eq!(update.name, "config");
+    assert!(Arc::ptr_eq(update.updated(), &atomic.load()));
+
+    let (old, new) = update.into_parts();
+    assert_eq!(old.value, 1);
+    assert_eq!(new.value, 2);
+}
+
+#[test]
+fn test_rcu_keeps_old_value_for_readers() {
+    let atomic = AtomicRef::new(Arc::new(vec![1, 2, 3]));
+    let reader = atomic.load();
+    atomic.rcu(|v| v.clear());
+    assert_eq!(*reader, vec![1, 2, 3]);
+    assert!(atomic.load().is_empty());
+}
+
+#[test]
+fn test_rcu_with_backoff() {
+    let atomic = AtomicRef::new(Arc::new(10));
+    let update = atomic.rcu_with_backoff(YieldBackoff::default(), |x| *x *= 3);
+    assert_eq!(**update.old(), 10);
+    assert_eq!(*update, 30);
+    assert_eq!(*atomic.load(), 30);
+}
+
+#[test]
+fn test_rcu_concurrent() {
+    const THREADS: usize = 4;
+    const ROUNDS: usize = 500;
+    let atomic = Arc::new(AtomicRef::new(Arc::new(TestData {
+        value: 0,
+        name: "counter".to_string(),
+    })));
+    let handles: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let atomic = atomic.clone();
+            thread::spawn(move || {
+                for _ in 0..ROUNDS {
+                    let update = atomic.rcu(|data| data.value += 1);
+                    assert_eq!(update.updated().value, update.old().value + 1);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(atomic.load().value, (THREADS * ROUNDS) as i32);
+    assert_eq!(atomic.load().name, "counter");
+}
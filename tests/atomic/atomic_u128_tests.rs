@@ -0,0 +1,253 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    AtomicU128,
+    RawAtomicU128,
+};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+
+test_atomic_integer!(AtomicU128, u128, atomic_u128_tests);
+
+/// A value with distinct bits in both 64-bit halves.
+const WIDE: u128 = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210;
+
+#[test]
+fn test_wide_values() {
+    let atomic = AtomicU128::new(WIDE);
+    assert_eq!(atomic.load(), WIDE);
+    assert_eq!(atomic.swap(u128::MAX), WIDE);
+    assert_eq!(atomic.load(), u128::MAX);
+    assert!(atomic.compare_set(u128::MAX, WIDE).is_ok());
+    assert_eq!(atomic.compare_set(u128::MAX, 0), Err(WIDE));
+}
+
+#[test]
+fn test_compare_set_differs_in_one_half() {
+    let atomic = AtomicU128::new(WIDE);
+    assert!(atomic.compare_set(WIDE ^ 1, 0).is_err());
+    assert!(atomic.compare_set(WIDE ^ (1 << 127), 0).is_err());
+    assert_eq!(atomic.load(), WIDE);
+}
+
+#[test]
+fn test_carry_across_halves() {
+    let atomic = AtomicU128::new(u64::MAX as u128);
+    assert_eq!(atomic.fetch_inc(), u64::MAX as u128);
+    assert_eq!(atomic.load(), 1 << 64);
+    assert_eq!(atomic.fetch_dec(), 1 << 64);
+    assert_eq!(atomic.load(), u64::MAX as u128);
+}
+
+#[test]
+fn test_wrapping_at_max() {
+    let atomic = AtomicU128::new(u128::MAX);
+    atomic.fetch_inc();
+    assert_eq!(atomic.load(), 0);
+    atomic.fetch_dec();
+    assert_eq!(atomic.load(), u128::MAX);
+}
+
+#[test]
+fn test_bit_operations_on_both_halves() {
+    let atomic = AtomicU128::new(WIDE);
+    assert_eq!(atomic.fetch_and(u64::MAX as u128), WIDE);
+    assert_eq!(atomic.load(), WIDE & u64::MAX as u128);
+    atomic.fetch_or(1 << 100);
+    assert_eq!(atomic.load(), (WIDE & u64::MAX as u128) | (1 << 100));
+    atomic.fetch_xor(u128::MAX);
+    assert_eq!(atomic.load(), !((WIDE & u64::MAX as u128) | (1 << 100)));
+}
+
+#[test]
+fn test_fetch_max_min_wide() {
+    let atomic = AtomicU128::new(1 << 64);
+    assert_eq!(atomic.fetch_max(u64::MAX as u128), 1 << 64);
+    assert_eq!(atomic.load(), 1 << 64);
+    assert_eq!(atomic.fetch_min(u64::MAX as u128), 1 << 64);
+    assert_eq!(atomic.load(), u64::MAX as u128);
+}
+
+#[test]
+fn test_packed_version_and_pointer() {
+    // High half: version, low half: pointer-sized payload
+    let pack = |version: u64, payload: u64| (u128::from(version) << 64) | u128::from(payload);
+    let atomic = AtomicU128::new(pack(0, 42));
+    let current = atomic.load();
+    assert!(atomic
+        .compare_set(current, pack((current >> 64) as u64 + 1, 43))
+        .is_ok());
+    // A stale tag with the same payload is rejected
+    assert!(atomic.compare_set(pack(0, 43), pack(1, 44)).is_err());
+    assert_eq!(atomic.load(), pack(1, 43));
+}
+
+#[test]
+fn test_concurrent_carries() {
+    // Each increment of the high half is observed through carries of the low
+    // half, so a torn read or write would lose increments.
+    const THREADS: usize = 8;
+    const INCREMENTS: u128 = 10_000;
+    let start = u64::MAX as u128 - INCREMENTS * THREADS as u128 / 2;
+    let atomic = Arc::new(AtomicU128::new(start));
+    let mut handles = vec![];
+
+    for _ in 0..THREADS {
+        let atomic = atomic.clone();
+        handles.push(thread::spawn(move || {
+            for _ in 0..INCREMENTS {
+                atomic.fetch_add(1);
+            }
+        }));
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(atomic.load(), start + INCREMENTS * THREADS as u128);
+}
+
+#[test]
+fn test_concurrent_reads_are_not_torn() {
+    // The writer alternates between two values differing in both halves; a
+    // torn read would observe a mix of the two.
+    let a = WIDE;
+    let b = !WIDE;
+    let atomic = Arc::new(AtomicU128::new(a));
+    let writer = {
+        let atomic = atomic.clone();
+        thread::spawn(move || {
+            for i in 0..100_000 {
+                atomic.store(if i % 2 == 0 { b } else { a });
+            }
+        })
+    };
+
+    while !writer.is_finished() {
+        let value = atomic.load();
+        assert!(value == a || value == b, "torn read: {:#x}", value);
+    }
+    writer.join().unwrap();
+}
+
+#[test]
+fn test_is_lock_free() {
+    assert_eq!(AtomicU128::is_lock_free(), RawAtomicU128::is_lock_free());
+    #[cfg(target_arch = "x86_64")]
+    assert_eq!(
+        AtomicU128::is_lock_free(),
+        std::is_x86_feature_detected!("cmpxchg16b")
+    );
+}
+
+#[test]
+fn test_raw_operations() {
+    let raw = RawAtomicU128::new(WIDE);
+    assert_eq!(raw.load(Ordering::SeqCst), WIDE);
+    raw.store(1, Ordering::Release);
+    assert_eq!(raw.fetch_add(u64::MAX as u128, Ordering::Relaxed), 1);
+    assert_eq!(raw.load(Ordering::Acquire), 1 << 64);
+    assert_eq!(raw.fetch_sub(1, Ordering::AcqRel), 1 << 64);
+    assert_eq!(raw.fetch_nand(0, Ordering::SeqCst), u64::MAX as u128);
+    assert_eq!(raw.load(Ordering::Relaxed), u128::MAX);
+    assert_eq!(
+        raw.compare_exchange(u128::MAX, 5, Ordering::AcqRel, Ordering::Acquire),
+        Ok(u128::MAX)
+    );
+    assert_eq!(
+        raw.compare_exchange_weak(4, 6, Ordering::AcqRel, Ordering::Acquire),
+        Err(5)
+    );
+    assert_eq!(
+        raw.fetch_update(Ordering::AcqRel, Ordering::Acquire, |x| Some(x * 2)),
+        Ok(5)
+    );
+    assert_eq!(
+        raw.fetch_update(Ordering::AcqRel, Ordering::Acquire, |_| None),
+        Err(10)
+    );
+    assert_eq!(raw.into_inner(), 10);
+}
+
+#[test]
+fn test_raw_locked_operations() {
+    let raw = RawAtomicU128::new(u128::MAX - 1);
+    // SAFETY: the value is only accessed through the locked methods.
+    unsafe {
+        assert_eq!(raw.locked_load(), u128::MAX - 1);
+        assert_eq!(
+            raw.locked_compare_exchange(u128::MAX - 1, WIDE),
+            Ok(u128::MAX - 1)
+        );
+        assert_eq!(raw.locked_compare_exchange(u128::MAX - 1, 0), Err(WIDE));
+        assert_eq!(raw.locked_update(|x| x.wrapping_add(1 << 64)), WIDE);
+        assert_eq!(raw.locked_load(), WIDE.wrapping_add(1 << 64));
+    }
+}
+
+#[test]
+fn test_raw_locked_concurrent_update() {
+    let raw = Arc::new(RawAtomicU128::new(0));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let raw = Arc::clone(&raw);
+            thread::spawn(move || {
+                for _ in 0..1000 {
+                    // SAFETY: the value is only accessed through the locked
+                    // methods.
+                    unsafe { raw.locked_update(|x| x + (1 << 64)) };
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    // SAFETY: the value is only accessed through the locked methods.
+    assert_eq!(unsafe { raw.locked_load() }, 4000 << 64);
+}
+
+#[test]
+fn test_raw_get_mut_and_traits() {
+    let mut raw = RawAtomicU128::from(WIDE);
+    *raw.get_mut() += 1;
+    assert_eq!(raw.load(Ordering::Relaxed), WIDE + 1);
+    assert_eq!(format!("{:?}", raw), format!("{:?}", WIDE + 1));
+    assert_eq!(RawAtomicU128::default().into_inner(), 0);
+    assert_eq!(std::mem::align_of::<RawAtomicU128>(), 16);
+    assert_eq!(std::mem::size_of::<AtomicU128>(), 16);
+}
+
+#[test]
+#[should_panic(expected = "release load")]
+fn test_raw_load_with_release_panics() {
+    RawAtomicU128::new(0).load(Ordering::Release);
+}
+
+#[test]
+#[should_panic(expected = "acquire store")]
+fn test_raw_store_with_acquire_panics() {
+    RawAtomicU128::new(0).store(1, Ordering::Acquire);
+}
+
+#[test]
+#[should_panic(expected = "failure ordering")]
+fn test_raw_compare_exchange_with_release_failure_panics() {
+    let _ = RawAtomicU128::new(0).compare_exchange(0, 1, Ordering::AcqRel, Ordering::Release);
+}
+
+#[test]
+fn test_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<AtomicU128>();
+    assert_send_sync::<RawAtomicU128>();
+}
@@ -0,0 +1,221 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    AtomicWeak,
+    OrderingPolicy,
+    PublishingPolicy,
+    SeqCst,
+};
+use std::sync::atomic::{
+    AtomicUsize as StdAtomicUsize,
+    Ordering,
+};
+use std::sync::{
+    Arc,
+    Weak,
+};
+use std::thread;
+
+/// Publishing policy with a load too weak to publish the target.
+struct RelaxedLoad;
+
+impl OrderingPolicy for RelaxedLoad {
+    const LOAD: Ordering = Ordering::Relaxed;
+}
+
+impl PublishingPolicy for RelaxedLoad {}
+
+#[test]
+fn test_new_and_upgrade() {
+    let target = Arc::new(42);
+    let slot = AtomicWeak::new(Arc::downgrade(&target));
+    assert_eq!(slot.upgrade().as_deref(), Some(&42));
+    assert_eq!(Arc::strong_count(&target), 1);
+    assert_eq!(Arc::weak_count(&target), 1);
+}
+
+#[test]
+fn test_does_not_keep_target_alive() {
+    let target = Arc::new(String::from("observer"));
+    let slot = AtomicWeak::new(Arc::downgrade(&target));
+    drop(target);
+    assert!(slot.upgrade().is_none());
+    assert!(slot.load().upgrade().is_none());
+}
+
+#[test]
+fn test_default_and_from() {
+    let empty: AtomicWeak<i32> = AtomicWeak::default();
+    assert!(empty.upgrade().is_none());
+    let target = Arc::new(1);
+    let slot: AtomicWeak<i32> = (&target).into();
+    assert_eq!(slot.upgrade().as_deref(), Some(&1));
+    let slot: AtomicWeak<i32> = Arc::downgrade(&target).into();
+    assert_eq!(slot.upgrade().as_deref(), Some(&1));
+}
+
+#[test]
+fn test_with_policy() {
+    let target = Arc::new(3);
+    let slot = AtomicWeak::<_, SeqCst>::with_policy(Arc::downgrade(&target));
+    assert_eq!(slot.upgrade().as_deref(), Some(&3));
+}
+
+#[test]
+fn test_load() {
+    let target = Arc::new(1);
+    let slot = AtomicWeak::new(Arc::downgrade(&target));
+    let weak = slot.load();
+    assert!(Weak::ptr_eq(&weak, &Arc::downgrade(&target)));
+    assert_eq!(Arc::weak_count(&target), 2);
+    drop(weak);
+    assert_eq!(Arc::weak_count(&target), 1);
+}
+
+#[test]
+fn test_store_and_swap() {
+    let first = Arc::new(1);
+    let second = Arc::new(2);
+    let slot = AtomicWeak::new(Arc::downgrade(&first));
+    slot.store(Arc::downgrade(&second));
+    assert_eq!(Arc::weak_count(&first), 0);
+    assert_eq!(slot.upgrade().as_deref(), Some(&2));
+    let old = slot.swap(Weak::new());
+    assert!(Weak::ptr_eq(&old, &Arc::downgrade(&second)));
+    assert!(slot.upgrade().is_none());
+}
+
+#[test]
+fn test_compare_set() {
+    let first = Arc::new(1);
+    let second = Arc::new(2);
+    let slot = AtomicWeak::new(Arc::downgrade(&first));
+
+    let actual = slot
+        .compare_set(&Arc::downgrade(&second), Weak::new())
+        .unwrap_err();
+    assert!(Weak::ptr_eq(&actual, &Arc::downgrade(&first)));
+    drop(actual);
+
+    assert!(slot
+        .compare_set(&Arc::downgrade(&first), Arc::downgrade(&second))
+        .is_ok());
+    assert_eq!(slot.upgrade().as_deref(), Some(&2));
+    assert_eq!(Arc::weak_count(&first), 0);
+    assert_eq!(Arc::weak_count(&second), 1);
+}
+
+#[test]
+fn test_compare_set_empty() {
+    let target = Arc::new(1);
+    let slot = AtomicWeak::default();
+    assert!(slot
+        .compare_set(&Weak::new(), Arc::downgrade(&target))
+        .is_ok());
+    assert!(slot.compare_set(&Weak::new(), Weak::new()).is_err());
+}
+
+#[test]
+fn test_compare_set_dropped_target() {
+    let target = Arc::new(1);
+    let weak = Arc::downgrade(&target);
+    let slot = AtomicWeak::new(weak.clone());
+    drop(target);
+    // The allocation stays identified by the pointer after the drop
+    assert!(slot.compare_set(&weak, Weak::new()).is_ok());
+}
+
+#[test]
+fn test_compare_set_weak_and_exchange() {
+    let first = Arc::new(1);
+    let second = Arc::new(2);
+    let slot = AtomicWeak::new(Arc::downgrade(&first));
+    let mut current = slot.load();
+    loop {
+        match slot.compare_set_weak(&current, Arc::downgrade(&second)) {
+            Ok(_) => break,
+            Err(actual) => current = actual,
+        }
+    }
+    let prev = slot.compare_and_exchange(&Arc::downgrade(&first), Weak::new());
+    assert!(Weak::ptr_eq(&prev, &Arc::downgrade(&second)));
+    assert_eq!(slot.upgrade().as_deref(), Some(&2));
+}
+
+#[test]
+fn test_clone_and_debug() {
+    let target = Arc::new(1);
+    let slot = AtomicWeak::new(Arc::downgrade(&target));
+    let cloned = slot.clone();
+    assert_eq!(Arc::weak_count(&target), 2);
+    assert_eq!(format!("{:?}", cloned), "AtomicWeak { value: Some(1) }");
+    drop(target);
+    assert_eq!(format!("{:?}", slot), "AtomicWeak { value: None }");
+}
+
+#[test]
+fn test_drop_releases_weak_count() {
+    let target = Arc::new(1);
+    let slot = AtomicWeak::new(Arc::downgrade(&target));
+    drop(slot);
+    assert_eq!(Arc::weak_count(&target), 0);
+}
+
+#[test]
+fn test_concurrent_load_and_store() {
+    struct Counted(Arc<StdAtomicUsize>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    const ROUNDS: usize = 2_000;
+    let drops = Arc::new(StdAtomicUsize::new(0));
+    let slot = Arc::new(AtomicWeak::default());
+    let writer = {
+        let slot = slot.clone();
+        let drops = drops.clone();
+        thread::spawn(move || {
+            for _ in 0..ROUNDS {
+                // The target only lives for one round
+                let target = Arc::new(Counted(drops.clone()));
+                slot.store(Arc::downgrade(&target));
+            }
+            slot.store(Weak::new());
+        })
+    };
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let slot = slot.clone();
+            thread::spawn(move || {
+                for _ in 0..ROUNDS {
+                    drop(slot.upgrade());
+                    drop(slot.load().upgrade());
+                }
+            })
+        })
+        .collect();
+    writer.join().unwrap();
+    for reader in readers {
+        reader.join().unwrap();
+    }
+    assert_eq!(drops.load(Ordering::SeqCst), ROUNDS);
+    assert!(slot.upgrade().is_none());
+}
+
+#[test]
+#[should_panic(expected = "memory ordering must be Acquire or SeqCst")]
+fn test_upgrade_rejects_relaxed_load() {
+    let target = Arc::new(1);
+    let slot = AtomicWeak::<_, RelaxedLoad>::with_policy(Arc::downgrade(&target));
+    let _ = slot.upgrade();
+}
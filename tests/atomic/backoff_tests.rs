@@ -0,0 +1,228 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    AtomicF64,
+    AtomicI64,
+    AtomicRef,
+    AtomicU32,
+    Backoff,
+    BackoffStrategy,
+    DefaultOrdering,
+    ExponentialBackoff,
+    NoBackoff,
+    OrderingPolicy,
+    SpinBackoff,
+    YieldBackoff,
+};
+use std::sync::Arc;
+use std::thread;
+
+const NUM_THREADS: usize = 8;
+const ITERATIONS_PER_THREAD: usize = 500;
+
+/// Policy whose CAS loops yield after two failures.
+struct YieldingPolicy;
+
+impl OrderingPolicy for YieldingPolicy {
+    const BACKOFF: BackoffStrategy = BackoffStrategy::Yield(YieldBackoff::new(2));
+}
+
+/// Policy whose CAS loops emit a single spin-loop hint per failure.
+struct SpinningPolicy;
+
+impl OrderingPolicy for SpinningPolicy {
+    const BACKOFF: BackoffStrategy =
+        BackoffStrategy::Exponential(ExponentialBackoff::with_max_shift(0));
+}
+
+/// Backoff that counts the failed attempts of a CAS loop.
+#[derive(Default)]
+struct CountingBackoff {
+    failures: usize,
+}
+
+impl Backoff for CountingBackoff {
+    fn backoff(&mut self) {
+        self.failures += 1;
+    }
+}
+
+#[test]
+fn test_builtin_strategies_do_not_block() {
+    let mut no = NoBackoff;
+    let mut spin = SpinBackoff;
+    let mut exponential = ExponentialBackoff::new();
+    let mut yielding = YieldBackoff::new(2);
+    for _ in 0..20 {
+        no.backoff();
+        spin.backoff();
+        exponential.backoff();
+        yielding.backoff();
+    }
+}
+
+#[test]
+fn test_default_strategies() {
+    assert_eq!(ExponentialBackoff::default(), ExponentialBackoff::new());
+    assert_eq!(
+        YieldBackoff::default(),
+        YieldBackoff::new(YieldBackoff::DEFAULT_SPIN_LIMIT)
+    );
+    assert_eq!(
+        ExponentialBackoff::with_max_shift(100),
+        ExponentialBackoff::with_max_shift(16)
+    );
+}
+
+#[test]
+fn test_backoff_strategy() {
+    assert_eq!(BackoffStrategy::default(), BackoffStrategy::DEFAULT);
+    assert_eq!(
+        BackoffStrategy::DEFAULT,
+        BackoffStrategy::Exponential(ExponentialBackoff::new())
+    );
+    assert_eq!(DefaultOrdering::BACKOFF, BackoffStrategy::DEFAULT);
+    assert_eq!(
+        YieldingPolicy::BACKOFF,
+        BackoffStrategy::Yield(YieldBackoff::new(2))
+    );
+
+    let mut strategies = [
+        BackoffStrategy::Exponential(ExponentialBackoff::with_max_shift(2)),
+        BackoffStrategy::Yield(YieldBackoff::new(1)),
+    ];
+    for _ in 0..20 {
+        for strategy in &mut strategies {
+            strategy.backoff();
+        }
+    }
+    // The wrapped strategies keep their own state
+    assert_ne!(
+        strategies[0],
+        BackoffStrategy::Exponential(ExponentialBackoff::with_max_shift(2))
+    );
+}
+
+#[test]
+fn test_concurrent_updates_with_policy_backoff() {
+    let float = Arc::new(AtomicF64::<YieldingPolicy>::with_policy(0.0));
+    let integer = Arc::new(AtomicI64::<SpinningPolicy>::with_policy(1));
+    let mut handles = vec![];
+
+    for _ in 0..NUM_THREADS {
+        let float = float.clone();
+        let integer = integer.clone();
+        let handle = thread::spawn(move || {
+            for _ in 0..ITERATIONS_PER_THREAD {
+                float.fetch_add(1.0);
+                integer.fetch_mul(1);
+                integer.fetch_update(|x| x + 1);
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let total = NUM_THREADS * ITERATIONS_PER_THREAD;
+    assert_eq!(float.load(), total as f64);
+    assert_eq!(integer.load(), total as i64 + 1);
+}
+
+#[test]
+fn test_uncontended_update_never_backs_off() {
+    let atomic = AtomicI64::new(10);
+    let mut backoff = CountingBackoff::default();
+    let old = atomic.fetch_update_with_backoff(&mut backoff, |x| x + 1);
+    assert_eq!(old, 10);
+    assert_eq!(atomic.load(), 11);
+    assert!(backoff.failures <= 1);
+}
+
+#[test]
+fn test_backoff_called_on_conflict() {
+    let atomic = AtomicI64::new(0);
+    let mut backoff = CountingBackoff::default();
+    let mut first = true;
+    atomic.fetch_update_with_backoff(&mut backoff, |x| {
+        if first {
+            first = false;
+            // Simulate a concurrent writer
+            atomic.store(100);
+        }
+        x + 1
+    });
+    assert!(backoff.failures >= 1);
+    assert_eq!(atomic.load(), 101);
+}
+
+#[test]
+fn test_try_update_with_backoff() {
+    let atomic = AtomicU32::new(1);
+    let take = |x: u32| x.checked_sub(1);
+    assert_eq!(atomic.try_update_with_backoff(SpinBackoff, take), Ok(1));
+    assert_eq!(atomic.try_update_with_backoff(SpinBackoff, take), Err(0));
+
+    let atomic = AtomicF64::new(2.0);
+    let halve = |x: f64| (x > 1.0).then(|| x / 2.0);
+    assert_eq!(atomic.try_update_with_backoff(NoBackoff, halve), Ok(2.0));
+    assert_eq!(atomic.try_update_with_backoff(NoBackoff, halve), Err(1.0));
+
+    let atomic = AtomicRef::new(Arc::new(1));
+    let result = atomic.try_update_with_backoff(YieldBackoff::default(), |_| None);
+    assert_eq!(*result.unwrap_err(), 1);
+}
+
+#[test]
+fn test_concurrent_float_update_with_yield_backoff() {
+    let atomic = Arc::new(AtomicF64::new(0.0));
+    let mut handles = vec![];
+
+    for _ in 0..NUM_THREADS {
+        let atomic = atomic.clone();
+        let handle = thread::spawn(move || {
+            for _ in 0..ITERATIONS_PER_THREAD {
+                atomic.fetch_update_with_backoff(YieldBackoff::new(4), |x| x + 1.0);
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(atomic.load(), (NUM_THREADS * ITERATIONS_PER_THREAD) as f64);
+}
+
+#[test]
+fn test_concurrent_ref_update_with_exponential_backoff() {
+    let atomic = Arc::new(AtomicRef::new(Arc::new(0usize)));
+    let mut handles = vec![];
+
+    for _ in 0..NUM_THREADS {
+        let atomic = atomic.clone();
+        let handle = thread::spawn(move || {
+            for _ in 0..ITERATIONS_PER_THREAD {
+                let backoff = ExponentialBackoff::with_max_shift(3);
+                atomic.fetch_update_with_backoff(backoff, |x| Arc::new(**x + 1));
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(*atomic.load(), NUM_THREADS * ITERATIONS_PER_THREAD);
+}
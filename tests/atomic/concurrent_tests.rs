@@ -445,3 +445,40 @@ fn test_memory_ordering_visibility() {
     let result = reader.join().unwrap();
     assert_eq!(result, 42);
 }
+
+// Test a semaphore built on bounded counters
+#[test]
+fn test_bounded_semaphore() {
+    const PERMITS: usize = 3;
+    let permits = Arc::new(AtomicUsize::new(PERMITS));
+    let in_use = Arc::new(AtomicUsize::new(0));
+    let mut handles = vec![];
+
+    for _ in 0..NUM_THREADS {
+        let permits = permits.clone();
+        let in_use = in_use.clone();
+        let handle = thread::spawn(move || {
+            for _ in 0..100 {
+                // Try to acquire a permit
+                while permits.fetch_sub_bounded(1, 0).is_err() {
+                    thread::yield_now();
+                }
+
+                let holders = in_use.fetch_inc() + 1;
+                assert!(holders <= PERMITS);
+                in_use.fetch_dec();
+
+                // Release the permit
+                permits.fetch_add_bounded(1, PERMITS).unwrap();
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(permits.load(), PERMITS);
+    assert_eq!(in_use.load(), 0);
+}
@@ -0,0 +1,463 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+/// Macro to generate the tests shared by the 16-bit atomic floating point
+/// types.
+///
+/// Unless a test is about rounding, the values used are exactly
+/// representable in both binary16 and bfloat16.
+#[macro_export]
+macro_rules! test_atomic_half_float {
+    ($atomic_type:ident, $test_mod:ident) => {
+        mod $test_mod {
+            use prism3_atomic::atomic::{
+                $atomic_type,
+                Atomic,
+                AtomicNumber,
+                NanPolicy,
+                SeqCst,
+            };
+            use std::sync::atomic::Ordering;
+            use std::sync::Arc;
+            use std::thread;
+
+            /// Returns the value next to `value` in the direction of
+            /// positive infinity, for positive finite `value`.
+            fn next_up(value: f32) -> f32 {
+                <$atomic_type>::from_bits(<$atomic_type>::to_bits(value) + 1)
+            }
+
+            #[test]
+            fn test_new() {
+                let atomic = <$atomic_type>::new(2.5);
+                assert_eq!(atomic.load(), 2.5);
+            }
+
+            #[test]
+            fn test_default() {
+                let atomic = <$atomic_type>::default();
+                assert_eq!(atomic.load(), 0.0);
+                assert!(atomic.load().is_sign_positive());
+            }
+
+            #[test]
+            fn test_from() {
+                let atomic = <$atomic_type>::from(-1.5);
+                assert_eq!(atomic.load(), -1.5);
+            }
+
+            #[test]
+            fn test_with_policy() {
+                let atomic = <$atomic_type<SeqCst>>::with_policy(1.5);
+                assert_eq!(atomic.load(), 1.5);
+                assert_eq!(atomic.fetch_add(0.5), 1.5);
+                assert_eq!(atomic.load(), 2.0);
+            }
+
+            #[test]
+            fn test_store_rounds() {
+                let atomic = <$atomic_type>::new(0.0);
+                atomic.store(0.1);
+                let rounded = <$atomic_type>::from_bits(<$atomic_type>::to_bits(0.1));
+                assert_eq!(atomic.load(), rounded);
+                assert_ne!(atomic.load(), 0.1);
+            }
+
+            #[test]
+            fn test_store_overflow() {
+                let atomic = <$atomic_type>::new(0.0);
+                atomic.store(f32::MAX);
+                assert_eq!(atomic.load(), f32::INFINITY);
+                atomic.store(-f32::MAX);
+                assert_eq!(atomic.load(), f32::NEG_INFINITY);
+            }
+
+            #[test]
+            fn test_signed_zero() {
+                let atomic = <$atomic_type>::new(-0.0);
+                assert_eq!(atomic.load(), 0.0);
+                assert!(atomic.load().is_sign_negative());
+            }
+
+            #[test]
+            fn test_nan() {
+                let atomic = <$atomic_type>::new(f32::NAN);
+                assert!(atomic.load().is_nan());
+            }
+
+            #[test]
+            fn test_swap() {
+                let atomic = <$atomic_type>::new(1.0);
+                assert_eq!(atomic.swap(2.0), 1.0);
+                assert_eq!(atomic.load(), 2.0);
+            }
+
+            #[test]
+            fn test_compare_set() {
+                let atomic = <$atomic_type>::new(1.0);
+                assert!(atomic.compare_set(1.0, 2.0).is_ok());
+                assert_eq!(atomic.load(), 2.0);
+                assert_eq!(atomic.compare_set(1.0, 3.0), Err(2.0));
+                assert_eq!(atomic.load(), 2.0);
+            }
+
+            #[test]
+            fn test_compare_set_rounds_current() {
+                // `current` is rounded before the bits are compared, so the
+                // same inexact literal matches the stored value
+                let atomic = <$atomic_type>::new(0.1);
+                assert!(atomic.compare_set(0.1, 0.5).is_ok());
+                assert_eq!(atomic.load(), 0.5);
+            }
+
+            #[test]
+            fn test_compare_and_exchange() {
+                let atomic = <$atomic_type>::new(1.0);
+                assert_eq!(atomic.compare_and_exchange(1.0, 2.0), 1.0);
+                assert_eq!(atomic.compare_and_exchange(1.0, 3.0), 2.0);
+                assert_eq!(atomic.load(), 2.0);
+            }
+
+            #[test]
+            fn test_compare_set_weak_in_loop() {
+                let atomic = <$atomic_type>::new(1.0);
+                let mut current = atomic.load();
+                loop {
+                    match atomic.compare_set_weak(current, current * 2.0) {
+                        Ok(()) => break,
+                        Err(actual) => current = actual,
+                    }
+                }
+                assert_eq!(atomic.load(), 2.0);
+            }
+
+            #[test]
+            fn test_compare_and_exchange_weak() {
+                let atomic = <$atomic_type>::new(1.0);
+                let mut current = atomic.load();
+                loop {
+                    let prev = atomic.compare_and_exchange_weak(current, 4.0);
+                    if prev == current {
+                        break;
+                    }
+                    current = prev;
+                }
+                assert_eq!(atomic.load(), 4.0);
+            }
+
+            #[test]
+            fn test_arithmetic() {
+                let atomic = <$atomic_type>::new(10.0);
+                assert_eq!(atomic.fetch_add(5.5), 10.0);
+                assert_eq!(atomic.fetch_sub(3.5), 15.5);
+                assert_eq!(atomic.fetch_mul(2.0), 12.0);
+                assert_eq!(atomic.fetch_div(4.0), 24.0);
+                assert_eq!(atomic.load(), 6.0);
+            }
+
+            #[test]
+            fn test_and_get() {
+                let atomic = <$atomic_type>::new(10.0);
+                assert_eq!(atomic.add_and_get(5.5), 15.5);
+                assert_eq!(atomic.sub_and_get(3.5), 12.0);
+                assert_eq!(atomic.update_and_get(|x| x / 3.0), 4.0);
+            }
+
+            #[test]
+            fn test_accumulate_max_min_and_get() {
+                let atomic = <$atomic_type>::new(10.0);
+                assert_eq!(atomic.accumulate_and_get(2.5, |a, b| a * b), 25.0);
+                assert_eq!(atomic.max_and_get(30.5), 30.5);
+                assert_eq!(atomic.max_and_get(f32::NAN), 30.5);
+                assert_eq!(atomic.min_and_get(5.5), 5.5);
+                assert_eq!(atomic.min_and_get(8.0), 5.5);
+
+                // The returned value is the stored, rounded value
+                let atomic = <$atomic_type>::new(1.0);
+                let max = atomic.max_and_get(1000.1);
+                assert_eq!(max, atomic.load());
+                assert_ne!(max, 1000.1);
+            }
+
+            #[test]
+            fn test_and_get_returns_stored_value() {
+                let atomic = <$atomic_type>::new(1.0);
+                let sum = atomic.add_and_get(0.1);
+                assert_eq!(sum, atomic.load());
+                assert_ne!(sum, 1.1);
+                let updated = atomic.update_and_get(|_| 0.3);
+                assert_eq!(updated, atomic.load());
+            }
+
+            #[test]
+            fn test_arithmetic_rounds_once() {
+                // 1 + ulp/2 is a tie that rounds to even, i.e. back to 1
+                let atomic = <$atomic_type>::new(1.0);
+                let half_ulp = (next_up(1.0) - 1.0) / 2.0;
+                atomic.fetch_add(half_ulp);
+                assert_eq!(atomic.load(), 1.0);
+                atomic.fetch_add(half_ulp * 1.5);
+                assert_eq!(atomic.load(), next_up(1.0));
+            }
+
+            #[test]
+            fn test_div_by_zero() {
+                let atomic = <$atomic_type>::new(1.0);
+                atomic.fetch_div(0.0);
+                assert_eq!(atomic.load(), f32::INFINITY);
+            }
+
+            #[test]
+            fn test_fetch_update() {
+                let atomic = <$atomic_type>::new(3.0);
+                assert_eq!(atomic.fetch_update(|x| x * 2.0 + 1.0), 3.0);
+                assert_eq!(atomic.load(), 7.0);
+            }
+
+            #[test]
+            fn test_fetch_update_terminates_on_nan_payload() {
+                // The loop compares the bits of the witnessed value, so it
+                // must terminate even if the stored value is a NaN
+                let atomic = <$atomic_type>::new(-f32::NAN);
+                assert!(atomic.fetch_update(|_| 1.0).is_nan());
+                assert_eq!(atomic.load(), 1.0);
+            }
+
+            #[test]
+            fn test_try_update() {
+                let atomic = <$atomic_type>::new(2.0);
+                let result = atomic.try_update(|x| (x > 1.0).then_some(x / 2.0));
+                assert_eq!(result, Ok(2.0));
+                let result = atomic.try_update(|x| (x > 1.0).then_some(x / 2.0));
+                assert_eq!(result, Err(1.0));
+                assert_eq!(atomic.load(), 1.0);
+            }
+
+            #[test]
+            fn test_fetch_update_counted() {
+                let atomic = <$atomic_type>::new(1.0);
+                let (prev, attempts) = atomic.fetch_update_counted(|x| x + 1.0);
+                assert_eq!(prev, 1.0);
+                assert_eq!(attempts, 1);
+                assert_eq!(atomic.load(), 2.0);
+            }
+
+            #[test]
+            fn test_fetch_max_min() {
+                let atomic = <$atomic_type>::new(10.0);
+                assert_eq!(atomic.fetch_max(20.5), 10.0);
+                assert_eq!(atomic.fetch_max(15.0), 20.5);
+                assert_eq!(atomic.fetch_min(5.5), 20.5);
+                assert_eq!(atomic.fetch_min(8.0), 5.5);
+                assert_eq!(atomic.load(), 5.5);
+            }
+
+            #[test]
+            fn test_fetch_max_min_signed_zero() {
+                let atomic = <$atomic_type>::new(-0.0);
+                atomic.fetch_max(0.0);
+                assert!(atomic.load().is_sign_positive());
+                atomic.fetch_min(-0.0);
+                assert!(atomic.load().is_sign_negative());
+            }
+
+            #[test]
+            fn test_fetch_max_min_nan_policies() {
+                let atomic = <$atomic_type>::new(1.0);
+                assert_eq!(atomic.fetch_max(f32::NAN), 1.0);
+                assert_eq!(atomic.load(), 1.0);
+
+                let result = atomic.fetch_min_with_nan_policy(f32::NAN, NanPolicy::Reject);
+                assert_eq!(result, Err(1.0));
+                assert_eq!(atomic.load(), 1.0);
+
+                let result = atomic.fetch_max_with_nan_policy(f32::NAN, NanPolicy::Propagate);
+                assert_eq!(result, Ok(1.0));
+                assert!(atomic.load().is_nan());
+
+                // A stored NaN is replaced under `Ignore`
+                assert!(atomic.fetch_min(2.0).is_nan());
+                assert_eq!(atomic.load(), 2.0);
+            }
+
+            #[test]
+            fn test_compare_set_value() {
+                let atomic = <$atomic_type>::new(-0.0);
+                assert!(atomic.compare_set(0.0, 1.0).is_err());
+                assert!(atomic.compare_set_value(0.0, 1.0).is_ok());
+                assert_eq!(atomic.load(), 1.0);
+
+                atomic.store(f32::NAN);
+                assert!(atomic.compare_set_value(-f32::NAN, 2.0).is_ok());
+                assert_eq!(atomic.load(), 2.0);
+
+                assert_eq!(atomic.compare_set_value(3.0, 4.0), Err(2.0));
+            }
+
+            #[test]
+            fn test_compare_set_value_rounds_current() {
+                let atomic = <$atomic_type>::new(0.1);
+                assert!(atomic.compare_set_value(0.1, 0.5).is_ok());
+                assert_eq!(atomic.load(), 0.5);
+            }
+
+            #[test]
+            fn test_compare_and_exchange_value() {
+                let atomic = <$atomic_type>::new(0.0);
+                assert_eq!(atomic.compare_and_exchange_value(-0.0, 1.0), 0.0);
+                assert_eq!(atomic.compare_and_exchange_value(0.0, 2.0), 1.0);
+                assert_eq!(atomic.load(), 1.0);
+            }
+
+            #[test]
+            fn test_compare_set_approx() {
+                let atomic = <$atomic_type>::new(0.75);
+                assert!(atomic.compare_set(0.8, 0.5).is_err());
+                assert_eq!(atomic.compare_set_approx(0.8, 0.5, 0.01), Err(0.75));
+                assert!(atomic.compare_set_approx(0.8, 0.5, 0.1).is_ok());
+                assert_eq!(atomic.load(), 0.5);
+
+                atomic.store(f32::INFINITY);
+                assert!(atomic.compare_set_approx(f32::INFINITY, 1.0, -1.0).is_ok());
+                atomic.store(f32::NAN);
+                assert!(atomic.compare_set_approx(f32::NAN, 1.0, 1.0).is_err());
+            }
+
+            #[test]
+            fn test_compare_set_ulps() {
+                let atomic = <$atomic_type>::new(next_up(next_up(1.0)));
+                assert!(atomic.compare_set_ulps(1.0, 0.5, 1).is_err());
+                assert!(atomic.compare_set_ulps(1.0, 0.5, 2).is_ok());
+                assert_eq!(atomic.load(), 0.5);
+            }
+
+            #[test]
+            fn test_compare_set_ulps_special_values() {
+                let atomic = <$atomic_type>::new(-0.0);
+                assert!(atomic.compare_set_ulps(0.0, 1.0, 0).is_ok());
+
+                // The largest finite value is 1 ULP from infinity
+                let max = <$atomic_type>::from_bits(<$atomic_type>::to_bits(f32::INFINITY) - 1);
+                atomic.store(f32::INFINITY);
+                assert!(atomic.compare_set_ulps(max, 1.0, 1).is_ok());
+
+                atomic.store(f32::NAN);
+                assert!(atomic.compare_set_ulps(f32::NAN, 1.0, 4).is_err());
+            }
+
+            #[test]
+            fn test_load_store_with_ordering() {
+                let atomic = <$atomic_type>::new(0.0);
+                atomic.store_with_ordering(1.5, Ordering::Relaxed);
+                assert_eq!(atomic.load_with_ordering(Ordering::Relaxed), 1.5);
+                assert_eq!(atomic.swap_with_ordering(2.5, Ordering::SeqCst), 1.5);
+                assert_eq!(atomic.load_with_ordering(Ordering::SeqCst), 2.5);
+            }
+
+            #[test]
+            fn test_inner() {
+                let atomic = <$atomic_type>::new(1.0);
+                let bits = atomic.inner().load(Ordering::Relaxed);
+                assert_eq!(bits, <$atomic_type>::to_bits(1.0));
+                atomic
+                    .inner()
+                    .store(<$atomic_type>::to_bits(-2.0), Ordering::Relaxed);
+                assert_eq!(atomic.load(), -2.0);
+            }
+
+            #[test]
+            fn test_trait_atomic() {
+                fn exchange<T: Atomic<Value = f32>>(atomic: &T) -> f32 {
+                    atomic.store(1.0);
+                    let _ = atomic.compare_set(1.0, 2.0);
+                    atomic.fetch_update(|x| x + 1.0);
+                    atomic.swap(4.0)
+                }
+
+                let atomic = <$atomic_type>::new(0.0);
+                assert_eq!(exchange(&atomic), 3.0);
+                assert_eq!(Atomic::load(&atomic), 4.0);
+            }
+
+            #[test]
+            fn test_trait_atomic_number() {
+                fn accumulate<T: AtomicNumber<Value = f32>>(atomic: &T) {
+                    atomic.fetch_add(4.0);
+                    atomic.fetch_sub(1.0);
+                    atomic.fetch_mul(3.0);
+                    atomic.fetch_div(2.0);
+                }
+
+                let atomic = <$atomic_type>::new(1.0);
+                accumulate(&atomic);
+                assert_eq!(atomic.load(), 6.0);
+                assert_eq!(AtomicNumber::inc_and_get(&atomic), 7.0);
+                assert_eq!(AtomicNumber::dec_and_get(&atomic), 6.0);
+            }
+
+            #[test]
+            fn test_debug_display() {
+                let atomic = <$atomic_type>::new(1.5);
+                assert_eq!(
+                    format!("{:?}", atomic),
+                    concat!(stringify!($atomic_type), " { value: 1.5 }")
+                );
+                assert_eq!(format!("{}", atomic), "1.5");
+            }
+
+            #[test]
+            fn test_concurrent_add() {
+                let atomic = Arc::new(<$atomic_type>::new(0.0));
+                let mut handles = vec![];
+
+                for _ in 0..8 {
+                    let atomic = atomic.clone();
+                    handles.push(thread::spawn(move || {
+                        for _ in 0..16 {
+                            atomic.fetch_add(1.0);
+                        }
+                    }));
+                }
+
+                for handle in handles {
+                    handle.join().unwrap();
+                }
+
+                assert_eq!(atomic.load(), 128.0);
+            }
+
+            #[test]
+            fn test_concurrent_fetch_max() {
+                let atomic = Arc::new(<$atomic_type>::new(0.0));
+                let mut handles = vec![];
+
+                for t in 0..8 {
+                    let atomic = atomic.clone();
+                    handles.push(thread::spawn(move || {
+                        for i in 0..32 {
+                            atomic.fetch_max((i * 8 + t) as f32);
+                        }
+                    }));
+                }
+
+                for handle in handles {
+                    handle.join().unwrap();
+                }
+
+                assert_eq!(atomic.load(), 255.0);
+            }
+
+            #[test]
+            fn test_send_sync() {
+                fn assert_send_sync<T: Send + Sync>() {}
+                assert_send_sync::<$atomic_type>();
+            }
+        }
+    };
+}
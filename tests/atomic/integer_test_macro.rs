@@ -880,6 +880,465 @@ macro_rules! test_atomic_integer {
                 assert_eq!(result.unwrap(), 10);
                 assert_eq!(atomic.load(), 15);
             }
+
+            #[test]
+            fn test_load_store_with_ordering() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(0);
+                atomic.store_with_ordering(42, Ordering::Relaxed);
+                assert_eq!(atomic.load_with_ordering(Ordering::Relaxed), 42);
+                atomic.store_with_ordering(10, Ordering::SeqCst);
+                assert_eq!(atomic.load_with_ordering(Ordering::SeqCst), 10);
+            }
+
+            #[test]
+            fn test_swap_with_ordering() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.swap_with_ordering(20, Ordering::Relaxed), 10);
+                assert_eq!(atomic.load(), 20);
+            }
+
+            #[test]
+            fn test_compare_set_with_ordering() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(10);
+                assert!(atomic
+                    .compare_set_with_ordering(10, 20, Ordering::SeqCst, Ordering::Relaxed)
+                    .is_ok());
+                assert_eq!(
+                    atomic.compare_set_with_ordering(10, 30, Ordering::SeqCst, Ordering::Relaxed),
+                    Err(20)
+                );
+                assert_eq!(
+                    atomic.compare_and_exchange_with_ordering(
+                        20,
+                        30,
+                        Ordering::Release,
+                        Ordering::Relaxed,
+                    ),
+                    20
+                );
+                assert_eq!(atomic.load(), 30);
+            }
+
+            #[test]
+            fn test_compare_set_weak_with_ordering() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(10);
+                let mut current = atomic.load();
+                while let Err(actual) = atomic.compare_set_weak_with_ordering(
+                    current,
+                    20,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                ) {
+                    current = actual;
+                }
+                assert_eq!(atomic.load(), 20);
+                loop {
+                    let prev = atomic.compare_and_exchange_weak_with_ordering(
+                        20,
+                        30,
+                        Ordering::SeqCst,
+                        Ordering::SeqCst,
+                    );
+                    if prev == 20 {
+                        break;
+                    }
+                }
+                assert_eq!(atomic.load(), 30);
+            }
+
+            #[test]
+            fn test_arithmetic_with_ordering() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.fetch_inc_with_ordering(Ordering::Relaxed), 10);
+                assert_eq!(atomic.fetch_dec_with_ordering(Ordering::Relaxed), 11);
+                assert_eq!(atomic.fetch_add_with_ordering(5, Ordering::SeqCst), 10);
+                assert_eq!(atomic.fetch_sub_with_ordering(3, Ordering::SeqCst), 15);
+                assert_eq!(
+                    atomic.fetch_mul_with_ordering(2, Ordering::SeqCst, Ordering::Relaxed),
+                    12
+                );
+                assert_eq!(
+                    atomic.fetch_div_with_ordering(4, Ordering::SeqCst, Ordering::Relaxed),
+                    24
+                );
+                assert_eq!(atomic.load(), 6);
+            }
+
+            #[test]
+            fn test_bit_operations_with_ordering() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(0b1100);
+                assert_eq!(
+                    atomic.fetch_and_with_ordering(0b1010, Ordering::Relaxed),
+                    0b1100
+                );
+                assert_eq!(
+                    atomic.fetch_or_with_ordering(0b0001, Ordering::Relaxed),
+                    0b1000
+                );
+                assert_eq!(
+                    atomic.fetch_xor_with_ordering(0b0011, Ordering::Relaxed),
+                    0b1001
+                );
+                assert_eq!(atomic.load(), 0b1010);
+                assert_eq!(atomic.fetch_not_with_ordering(Ordering::SeqCst), 0b1010);
+                assert_eq!(atomic.load(), !(0b1010 as $value_type));
+            }
+
+            #[test]
+            fn test_max_min_with_ordering() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.fetch_max_with_ordering(20, Ordering::Relaxed), 10);
+                assert_eq!(atomic.fetch_min_with_ordering(5, Ordering::Relaxed), 20);
+                assert_eq!(atomic.load(), 5);
+            }
+
+            #[test]
+            fn test_fetch_update_with_ordering() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(10);
+                let old =
+                    atomic
+                        .fetch_update_with_ordering(Ordering::SeqCst, Ordering::Relaxed, |x| x * 2);
+                assert_eq!(old, 10);
+                let old = atomic.fetch_accumulate_with_ordering(
+                    5,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                    |a, b| a + b,
+                );
+                assert_eq!(old, 20);
+                assert_eq!(atomic.load(), 25);
+            }
+
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic(expected = "invalid memory ordering for a load")]
+            fn test_load_with_release_ordering_panics() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(10);
+                atomic.load_with_ordering(Ordering::Release);
+            }
+
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic(expected = "invalid memory ordering for a store")]
+            fn test_store_with_acquire_ordering_panics() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(10);
+                atomic.store_with_ordering(20, Ordering::Acquire);
+            }
+
+            #[test]
+            #[cfg(debug_assertions)]
+            #[should_panic(expected = "invalid failure ordering")]
+            fn test_compare_set_with_release_failure_ordering_panics() {
+                use std::sync::atomic::Ordering;
+
+                let atomic = <$atomic_type>::new(10);
+                let _ =
+                    atomic.compare_set_with_ordering(10, 20, Ordering::AcqRel, Ordering::Release);
+            }
+
+            #[test]
+            fn test_inc_dec_and_get() {
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.inc_and_get(), 11);
+                assert_eq!(atomic.dec_and_get(), 10);
+                assert_eq!(atomic.load(), 10);
+            }
+
+            #[test]
+            fn test_add_sub_and_get() {
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.add_and_get(5), 15);
+                assert_eq!(atomic.sub_and_get(3), 12);
+                assert_eq!(atomic.load(), 12);
+            }
+
+            #[test]
+            fn test_and_get_wraps_around() {
+                let atomic = <$atomic_type>::new(<$value_type>::MAX);
+                assert_eq!(atomic.inc_and_get(), <$value_type>::MIN);
+                assert_eq!(atomic.dec_and_get(), <$value_type>::MAX);
+                assert_eq!(atomic.add_and_get(2), <$value_type>::MIN + 1);
+                assert_eq!(atomic.sub_and_get(2), <$value_type>::MAX);
+            }
+
+            #[test]
+            fn test_update_and_accumulate_and_get() {
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.update_and_get(|x| x * 2), 20);
+                assert_eq!(atomic.accumulate_and_get(5, |a, b| a + b), 25);
+                assert_eq!(atomic.load(), 25);
+            }
+
+            #[test]
+            fn test_max_min_and_get() {
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.max_and_get(20), 20);
+                assert_eq!(atomic.max_and_get(15), 20);
+                assert_eq!(atomic.min_and_get(5), 5);
+                assert_eq!(atomic.min_and_get(8), 5);
+                assert_eq!(atomic.load(), 5);
+            }
+
+            #[test]
+            fn test_and_get_through_traits() {
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(Atomic::update_and_get(&atomic, |x| x + 1), 11);
+                assert_eq!(AtomicNumber::add_and_get(&atomic, 4), 15);
+                assert_eq!(AtomicNumber::sub_and_get(&atomic, 5), 10);
+            }
+
+            #[test]
+            fn test_concurrent_inc_and_get_unique() {
+                let atomic = Arc::new(<$atomic_type>::new(0));
+                let mut handles = vec![];
+
+                for _ in 0..4 {
+                    let atomic = Arc::clone(&atomic);
+                    handles.push(thread::spawn(move || {
+                        (0..25).map(|_| atomic.inc_and_get()).collect::<Vec<_>>()
+                    }));
+                }
+
+                let mut seen: Vec<$value_type> = handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().unwrap())
+                    .collect();
+                seen.sort();
+                let expected: Vec<$value_type> = (1..=100).collect();
+                assert_eq!(seen, expected);
+            }
+
+            #[test]
+            fn test_fetch_checked_add() {
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.fetch_checked_add(5), Ok(10));
+                assert_eq!(atomic.load(), 15);
+
+                atomic.store(<$value_type>::MAX);
+                assert_eq!(atomic.fetch_checked_add(1), Err(<$value_type>::MAX));
+                assert_eq!(atomic.load(), <$value_type>::MAX);
+            }
+
+            #[test]
+            fn test_fetch_checked_sub() {
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.fetch_checked_sub(3), Ok(10));
+                assert_eq!(atomic.load(), 7);
+
+                atomic.store(<$value_type>::MIN);
+                assert_eq!(atomic.fetch_checked_sub(1), Err(<$value_type>::MIN));
+                assert_eq!(atomic.load(), <$value_type>::MIN);
+            }
+
+            #[test]
+            fn test_fetch_checked_mul() {
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.fetch_checked_mul(3), Ok(10));
+                assert_eq!(atomic.load(), 30);
+
+                atomic.store(<$value_type>::MAX);
+                assert_eq!(atomic.fetch_checked_mul(2), Err(<$value_type>::MAX));
+                assert_eq!(atomic.load(), <$value_type>::MAX);
+            }
+
+            #[test]
+            fn test_fetch_checked_div_and_rem() {
+                let atomic = <$atomic_type>::new(30);
+                assert_eq!(atomic.fetch_checked_div(4), Ok(30));
+                assert_eq!(atomic.load(), 7);
+                assert_eq!(atomic.fetch_checked_rem(4), Ok(7));
+                assert_eq!(atomic.load(), 3);
+
+                assert_eq!(atomic.fetch_checked_div(0), Err(3));
+                assert_eq!(atomic.fetch_checked_rem(0), Err(3));
+                assert_eq!(atomic.load(), 3);
+            }
+
+            #[test]
+            fn test_fetch_saturating_ops() {
+                let atomic = <$atomic_type>::new(<$value_type>::MAX - 1);
+                assert_eq!(atomic.fetch_saturating_add(5), <$value_type>::MAX - 1);
+                assert_eq!(atomic.load(), <$value_type>::MAX);
+                assert_eq!(atomic.fetch_saturating_mul(2), <$value_type>::MAX);
+                assert_eq!(atomic.load(), <$value_type>::MAX);
+
+                atomic.store(<$value_type>::MIN + 1);
+                atomic.fetch_saturating_sub(5);
+                assert_eq!(atomic.load(), <$value_type>::MIN);
+
+                atomic.store(10);
+                assert_eq!(atomic.fetch_saturating_add(1), 10);
+                assert_eq!(atomic.fetch_saturating_sub(2), 11);
+                assert_eq!(atomic.fetch_saturating_mul(3), 9);
+                assert_eq!(atomic.load(), 27);
+            }
+
+            #[test]
+            fn test_concurrent_checked_add_never_overflows() {
+                let start = <$value_type>::MAX - 50;
+                let atomic = Arc::new(<$atomic_type>::new(start));
+                let mut handles = vec![];
+
+                for _ in 0..4 {
+                    let atomic = Arc::clone(&atomic);
+                    handles.push(thread::spawn(move || {
+                        (0..25)
+                            .filter(|_| atomic.fetch_checked_add(1).is_ok())
+                            .count()
+                    }));
+                }
+
+                let succeeded: usize = handles
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .sum();
+                assert_eq!(succeeded, 50);
+                assert_eq!(atomic.load(), <$value_type>::MAX);
+            }
+
+            #[test]
+            fn test_fetch_add_bounded() {
+                let atomic = <$atomic_type>::new(8);
+                assert_eq!(atomic.fetch_add_bounded(2, 10), Ok(8));
+                assert_eq!(atomic.fetch_add_bounded(1, 10), Err(10));
+                assert_eq!(atomic.load(), 10);
+
+                atomic.store(<$value_type>::MAX);
+                assert_eq!(
+                    atomic.fetch_add_bounded(1, <$value_type>::MAX),
+                    Err(<$value_type>::MAX)
+                );
+            }
+
+            #[test]
+            fn test_fetch_sub_bounded() {
+                let atomic = <$atomic_type>::new(2);
+                assert_eq!(atomic.fetch_sub_bounded(1, 0), Ok(2));
+                assert_eq!(atomic.fetch_sub_bounded(2, 0), Err(1));
+                assert_eq!(atomic.fetch_sub_bounded(1, 0), Ok(1));
+                assert_eq!(atomic.fetch_sub_bounded(1, 0), Err(0));
+                assert_eq!(atomic.load(), 0);
+
+                atomic.store(<$value_type>::MIN);
+                assert_eq!(
+                    atomic.fetch_sub_bounded(1, <$value_type>::MIN),
+                    Err(<$value_type>::MIN)
+                );
+            }
+
+            #[test]
+            fn test_try_update() {
+                let atomic = <$atomic_type>::new(10);
+                let double = |x: $value_type| (x < 20).then(|| x * 2);
+                assert_eq!(atomic.try_update(double), Ok(10));
+                assert_eq!(atomic.try_update(double), Err(20));
+                assert_eq!(atomic.load(), 20);
+            }
+
+            #[test]
+            fn test_try_update_result() {
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(atomic.try_update_result(|x| Ok::<_, ()>(x + 1)), Ok(10));
+                assert_eq!(atomic.try_update_result(|_| Err("busy")), Err("busy"));
+                assert_eq!(atomic.load(), 11);
+            }
+
+            #[test]
+            fn test_try_update_through_trait() {
+                let atomic = <$atomic_type>::new(10);
+                assert_eq!(Atomic::try_update(&atomic, |x| Some(x + 1)), Ok(10));
+                assert_eq!(Atomic::try_update(&atomic, |_| None), Err(11));
+                assert_eq!(
+                    Atomic::try_update_result(&atomic, |_| Err::<$value_type, _>(7)),
+                    Err(7)
+                );
+                assert_eq!(atomic.load(), 11);
+            }
+
+            #[test]
+            fn test_fn_mut_closures() {
+                let atomic = <$atomic_type>::new(10);
+                let mut calls = 0;
+                atomic.fetch_update(|x| {
+                    calls += 1;
+                    x + 1
+                });
+                atomic.update_and_get(|x| {
+                    calls += 1;
+                    x + 1
+                });
+                atomic.fetch_accumulate(2, |a, b| {
+                    calls += 1;
+                    a + b
+                });
+                assert_eq!(calls, 3);
+                assert_eq!(atomic.load(), 14);
+            }
+
+            #[test]
+            fn test_fetch_update_counted() {
+                let atomic = <$atomic_type>::new(10);
+                let mut calls = 0;
+                let (old, attempts) = atomic.fetch_update_counted(|x| {
+                    calls += 1;
+                    x * 2
+                });
+                assert_eq!(old, 10);
+                assert_eq!(attempts, calls);
+                assert!(attempts >= 1);
+                assert_eq!(atomic.load(), 20);
+            }
+
+            #[test]
+            fn test_fetch_accumulate_counted() {
+                let atomic = <$atomic_type>::new(10);
+                let (old, attempts) = atomic.fetch_accumulate_counted(5, |a, b| a + b);
+                assert_eq!(old, 10);
+                assert!(attempts >= 1);
+                assert_eq!(atomic.load(), 15);
+            }
+
+            #[test]
+            fn test_concurrent_fetch_update_counted() {
+                let atomic = Arc::new(<$atomic_type>::new(0));
+                let mut handles = vec![];
+
+                for _ in 0..4 {
+                    let atomic = Arc::clone(&atomic);
+                    handles.push(thread::spawn(move || {
+                        (0..25)
+                            .map(|_| atomic.fetch_update_counted(|x| x + 1).1)
+                            .sum::<usize>()
+                    }));
+                }
+
+                let attempts: usize = handles
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .sum();
+                assert!(attempts >= 100);
+                assert_eq!(atomic.load(), 100);
+            }
         }
     };
 }
@@ -7,22 +7,53 @@
  *
  ******************************************************************************/
 
+#[macro_use]
+mod half_float_test_macro;
 #[macro_use]
 mod integer_test_macro;
+#[macro_use]
+mod non_zero_test_macro;
 
+mod atomic_adder_tests;
+mod atomic_bf16_tests;
+mod atomic_bit_set_tests;
+mod atomic_bitfield_tests;
 mod atomic_bool_tests;
+mod atomic_box_tests;
+mod atomic_cell_tests;
+mod atomic_compensated_f64_tests;
+mod atomic_enum_tests;
+mod atomic_f16_tests;
 mod atomic_f32_tests;
 mod atomic_f64_tests;
+mod atomic_i128_tests;
 mod atomic_i16_tests;
 mod atomic_i32_tests;
 mod atomic_i64_tests;
 mod atomic_i8_tests;
 mod atomic_isize_tests;
+mod atomic_non_zero_i128_tests;
+mod atomic_non_zero_i16_tests;
+mod atomic_non_zero_i32_tests;
+mod atomic_non_zero_i64_tests;
+mod atomic_non_zero_i8_tests;
+mod atomic_non_zero_isize_tests;
+mod atomic_non_zero_u128_tests;
+mod atomic_non_zero_u16_tests;
+mod atomic_non_zero_u32_tests;
+mod atomic_non_zero_u64_tests;
+mod atomic_non_zero_u8_tests;
+mod atomic_non_zero_usize_tests;
+mod atomic_option_ref_tests;
 mod atomic_ref_tests;
+mod atomic_u128_tests;
 mod atomic_u16_tests;
 mod atomic_u32_tests;
 mod atomic_u64_tests;
 mod atomic_u8_tests;
 mod atomic_usize_tests;
+mod atomic_weak_tests;
+mod backoff_tests;
 mod concurrent_tests;
+mod ordering_policy_tests;
 mod trait_tests;
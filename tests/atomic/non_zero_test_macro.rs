@@ -0,0 +1,349 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+/// Macro to generate the tests shared by the atomic non-zero integer types
+/// and their optional counterparts.
+#[macro_export]
+macro_rules! test_atomic_non_zero {
+    (
+        $atomic_type:ident,
+        $option_type:ident,
+        $non_zero_type:ident,
+        $value_type:ty,
+        $test_mod:ident
+    ) => {
+        mod $test_mod {
+            use prism3_atomic::atomic::{
+                $atomic_type,
+                $option_type,
+                Atomic,
+                SeqCst,
+            };
+            use std::num::$non_zero_type;
+            use std::sync::Arc;
+            use std::thread;
+
+            fn nz(value: $value_type) -> $non_zero_type {
+                $non_zero_type::new(value).unwrap()
+            }
+
+            #[test]
+            fn test_new() {
+                let atomic = $atomic_type::new(nz(42));
+                assert_eq!(atomic.load(), nz(42));
+            }
+
+            #[test]
+            fn test_from() {
+                let atomic = $atomic_type::from(nz(7));
+                assert_eq!(atomic.load(), nz(7));
+            }
+
+            #[test]
+            fn test_with_policy() {
+                let atomic = $atomic_type::<SeqCst>::with_policy(nz(1));
+                assert_eq!(atomic.fetch_inc(), Ok(nz(1)));
+                assert_eq!(atomic.load(), nz(2));
+            }
+
+            #[test]
+            fn test_store_and_swap() {
+                let atomic = $atomic_type::new(nz(1));
+                atomic.store(nz(5));
+                assert_eq!(atomic.load(), nz(5));
+                assert_eq!(atomic.swap($non_zero_type::MAX), nz(5));
+                assert_eq!(atomic.load(), $non_zero_type::MAX);
+            }
+
+            #[test]
+            fn test_compare_set() {
+                let atomic = $atomic_type::new(nz(1));
+                assert!(atomic.compare_set(nz(1), nz(2)).is_ok());
+                assert_eq!(atomic.compare_set(nz(1), nz(3)), Err(nz(2)));
+                assert_eq!(atomic.load(), nz(2));
+            }
+
+            #[test]
+            fn test_compare_set_weak() {
+                let atomic = $atomic_type::new(nz(1));
+                let mut current = atomic.load();
+                loop {
+                    match atomic.compare_set_weak(current, nz(9)) {
+                        Ok(_) => break,
+                        Err(actual) => current = actual,
+                    }
+                }
+                assert_eq!(atomic.load(), nz(9));
+            }
+
+            #[test]
+            fn test_compare_and_exchange() {
+                let atomic = $atomic_type::new(nz(1));
+                assert_eq!(atomic.compare_and_exchange(nz(1), nz(2)), nz(1));
+                assert_eq!(atomic.compare_and_exchange(nz(1), nz(3)), nz(2));
+                assert_eq!(atomic.load(), nz(2));
+            }
+
+            #[test]
+            fn test_compare_and_exchange_weak() {
+                let atomic = $atomic_type::new(nz(1));
+                let mut current = atomic.load();
+                loop {
+                    let prev = atomic.compare_and_exchange_weak(current, nz(4));
+                    if prev == current {
+                        break;
+                    }
+                    current = prev;
+                }
+                assert_eq!(atomic.load(), nz(4));
+            }
+
+            #[test]
+            fn test_fetch_inc_overflow() {
+                let atomic = $atomic_type::new($non_zero_type::MAX);
+                assert_eq!(atomic.fetch_inc(), Err($non_zero_type::MAX));
+                assert_eq!(atomic.load(), $non_zero_type::MAX);
+            }
+
+            #[test]
+            fn test_fetch_dec() {
+                let atomic = $atomic_type::new(nz(2));
+                assert_eq!(atomic.fetch_dec(), Ok(nz(2)));
+                assert_eq!(atomic.fetch_dec(), Err(nz(1)));
+                assert_eq!(atomic.load(), nz(1));
+            }
+
+            #[test]
+            fn test_fetch_add() {
+                let atomic = $atomic_type::new(nz(1));
+                assert_eq!(atomic.fetch_add(0), Ok(nz(1)));
+                assert_eq!(atomic.fetch_add(9), Ok(nz(1)));
+                assert_eq!(atomic.load(), nz(10));
+                assert_eq!(atomic.fetch_add(<$value_type>::MAX), Err(nz(10)));
+                assert_eq!(atomic.load(), nz(10));
+            }
+
+            #[test]
+            fn test_fetch_sub() {
+                let atomic = $atomic_type::new(nz(10));
+                assert_eq!(atomic.fetch_sub(3), Ok(nz(10)));
+                assert_eq!(atomic.load(), nz(7));
+                // Reaching zero is rejected
+                assert_eq!(atomic.fetch_sub(7), Err(nz(7)));
+                assert_eq!(atomic.fetch_sub(6), Ok(nz(7)));
+                assert_eq!(atomic.load(), nz(1));
+                // So is underflow
+                atomic.store($non_zero_type::MIN);
+                assert_eq!(
+                    atomic.fetch_sub(<$value_type>::MAX),
+                    Err($non_zero_type::MIN)
+                );
+            }
+
+            #[test]
+            fn test_fetch_max_min() {
+                let atomic = $atomic_type::new(nz(5));
+                assert_eq!(atomic.fetch_max(nz(3)), nz(5));
+                assert_eq!(atomic.load(), nz(5));
+                assert_eq!(atomic.fetch_max(nz(8)), nz(5));
+                assert_eq!(atomic.load(), nz(8));
+                assert_eq!(atomic.fetch_min(nz(2)), nz(8));
+                assert_eq!(atomic.load(), nz(2));
+            }
+
+            #[test]
+            fn test_fetch_update() {
+                let atomic = $atomic_type::new(nz(8));
+                assert_eq!(atomic.fetch_update(|x| x.get() / 2), Ok(nz(8)));
+                assert_eq!(atomic.load(), nz(4));
+                assert_eq!(atomic.fetch_update(|_| 0), Err(nz(4)));
+                assert_eq!(atomic.load(), nz(4));
+            }
+
+            #[test]
+            fn test_atomic_trait() {
+                fn update<A: Atomic>(atomic: &A, f: fn(A::Value) -> A::Value) -> A::Value {
+                    atomic.update_and_get(f)
+                }
+
+                let atomic = $atomic_type::new(nz(3));
+                assert_eq!(update(&atomic, |x| nz(x.get() * 2)), nz(6));
+                assert_eq!(Atomic::fetch_update(&atomic, |_| nz(1)), nz(6));
+                assert_eq!(Atomic::compare_exchange(&atomic, nz(1), nz(2)), nz(1));
+                assert_eq!(Atomic::try_update(&atomic, |_| None), Err(nz(2)));
+                assert_eq!(
+                    Atomic::try_update(&atomic, |x| $non_zero_type::new(x.get() + 1)),
+                    Ok(nz(2))
+                );
+                let result: Result<_, &str> = Atomic::try_update_result(&atomic, |_| Err("busy"));
+                assert_eq!(result, Err("busy"));
+                assert_eq!(Atomic::load(&atomic), nz(3));
+            }
+
+            #[test]
+            fn test_debug_and_display() {
+                let atomic = $atomic_type::new(nz(42));
+                assert_eq!(
+                    format!("{:?}", atomic),
+                    concat!(stringify!($atomic_type), " { value: 42 }")
+                );
+                assert_eq!(format!("{}", atomic), "42");
+            }
+
+            #[test]
+            fn test_concurrent_fetch_dec_never_reaches_zero() {
+                let atomic = Arc::new($atomic_type::new(nz(64)));
+                let mut handles = vec![];
+                for _ in 0..4 {
+                    let atomic = atomic.clone();
+                    handles.push(thread::spawn(move || {
+                        let mut succeeded = 0;
+                        for _ in 0..32 {
+                            if atomic.fetch_dec().is_ok() {
+                                succeeded += 1;
+                            }
+                        }
+                        succeeded
+                    }));
+                }
+                let total: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+                assert_eq!(total, 63);
+                assert_eq!(atomic.load(), nz(1));
+            }
+
+            #[test]
+            fn test_layout() {
+                use std::mem::size_of;
+                assert_eq!(size_of::<$atomic_type>(), size_of::<$value_type>());
+                assert_eq!(size_of::<$option_type>(), size_of::<$value_type>());
+            }
+
+            #[test]
+            fn test_option_new_and_default() {
+                assert_eq!($option_type::new(Some(nz(3))).load(), Some(nz(3)));
+                assert_eq!($option_type::new(None).load(), None);
+                assert_eq!($option_type::default().load(), None);
+                assert_eq!($option_type::from(Some(nz(1))).load(), Some(nz(1)));
+            }
+
+            #[test]
+            fn test_option_with_policy() {
+                let atomic = $option_type::<SeqCst>::with_policy(Some(nz(2)));
+                assert_eq!(atomic.load(), Some(nz(2)));
+            }
+
+            #[test]
+            fn test_option_store_swap_take() {
+                let atomic = $option_type::new(None);
+                atomic.store(Some(nz(5)));
+                assert_eq!(atomic.load(), Some(nz(5)));
+                assert_eq!(atomic.swap(Some($non_zero_type::MAX)), Some(nz(5)));
+                assert_eq!(atomic.take(), Some($non_zero_type::MAX));
+                assert_eq!(atomic.take(), None);
+                assert_eq!(atomic.swap(Some(nz(1))), None);
+            }
+
+            #[test]
+            fn test_option_compare_set() {
+                let atomic = $option_type::new(None);
+                assert!(atomic.compare_set(None, Some(nz(1))).is_ok());
+                assert_eq!(atomic.compare_set(None, Some(nz(2))), Err(Some(nz(1))));
+                assert!(atomic.compare_set(Some(nz(1)), None).is_ok());
+                assert_eq!(atomic.load(), None);
+            }
+
+            #[test]
+            fn test_option_compare_set_weak() {
+                let atomic = $option_type::new(None);
+                let mut current = atomic.load();
+                loop {
+                    match atomic.compare_set_weak(current, Some(nz(6))) {
+                        Ok(_) => break,
+                        Err(actual) => current = actual,
+                    }
+                }
+                assert_eq!(atomic.load(), Some(nz(6)));
+            }
+
+            #[test]
+            fn test_option_compare_and_exchange() {
+                let atomic = $option_type::new(Some(nz(1)));
+                assert_eq!(atomic.compare_and_exchange(Some(nz(1)), None), Some(nz(1)));
+                assert_eq!(atomic.compare_and_exchange(Some(nz(1)), Some(nz(2))), None);
+                let mut current = atomic.load();
+                loop {
+                    let prev = atomic.compare_and_exchange_weak(current, Some(nz(3)));
+                    if prev == current {
+                        break;
+                    }
+                    current = prev;
+                }
+                assert_eq!(atomic.load(), Some(nz(3)));
+            }
+
+            #[test]
+            fn test_option_fetch_update() {
+                let atomic = $option_type::new(None);
+                assert_eq!(atomic.fetch_update(|x| x.or(Some(nz(1)))), None);
+                assert_eq!(atomic.fetch_update(|x| x.or(Some(nz(2)))), Some(nz(1)));
+                assert_eq!(atomic.fetch_update(|_| None), Some(nz(1)));
+                assert_eq!(atomic.load(), None);
+            }
+
+            #[test]
+            fn test_option_atomic_trait() {
+                let atomic = $option_type::new(None);
+                assert_eq!(
+                    Atomic::update_and_get(&atomic, |x| x.or(Some(nz(4)))),
+                    Some(nz(4))
+                );
+                assert_eq!(Atomic::fetch_update(&atomic, |_| None), Some(nz(4)));
+                assert_eq!(Atomic::compare_exchange(&atomic, None, Some(nz(5))), None);
+                assert_eq!(Atomic::try_update(&atomic, |_| None), Err(Some(nz(5))));
+                assert_eq!(Atomic::try_update(&atomic, |_| Some(None)), Ok(Some(nz(5))));
+                let result: Result<_, &str> = Atomic::try_update_result(&atomic, |x| Ok(x));
+                assert_eq!(result, Ok(None));
+                assert_eq!(Atomic::swap(&atomic, Some(nz(1))), None);
+                assert_eq!(Atomic::load(&atomic), Some(nz(1)));
+            }
+
+            #[test]
+            fn test_option_debug() {
+                let atomic = $option_type::new(Some(nz(42)));
+                assert_eq!(
+                    format!("{:?}", atomic),
+                    concat!(stringify!($option_type), " { value: Some(42) }")
+                );
+                assert_eq!(
+                    format!("{:?}", $option_type::new(None)),
+                    concat!(stringify!($option_type), " { value: None }")
+                );
+            }
+
+            #[test]
+            fn test_option_concurrent_claim() {
+                let owner = Arc::new($option_type::new(None));
+                let mut handles = vec![];
+                for i in 1..=4 {
+                    let owner = owner.clone();
+                    handles.push(thread::spawn(move || {
+                        owner.compare_set(None, Some(nz(i))).is_ok()
+                    }));
+                }
+                let claimed = handles
+                    .into_iter()
+                    .map(|h| h.join().unwrap())
+                    .filter(|ok| *ok)
+                    .count();
+                assert_eq!(claimed, 1);
+                assert!(owner.load().is_some());
+            }
+        }
+    };
+}
@@ -0,0 +1,201 @@
+/*******************************************************************************
+ *
+ *    Copyright (c) 2025.
+ *    3-Prism Co. Ltd.
+ *
+ *    All rights reserved.
+ *
+ ******************************************************************************/
+
+use prism3_atomic::atomic::{
+    AcqRel,
+    AtomicBool,
+    AtomicF32,
+    AtomicF64,
+    AtomicI64,
+    AtomicNumber,
+    AtomicRef,
+    AtomicU32,
+    AtomicU64,
+    DefaultOrdering,
+    OrderingPolicy,
+    PublishingPolicy,
+    RelaxedCounter,
+    SeqCst,
+};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+
+/// Policy that only strengthens counter arithmetic.
+struct PublishingCounter;
+
+impl OrderingPolicy for PublishingCounter {
+    const ARITHMETIC: Ordering = Ordering::AcqRel;
+}
+
+/// Policy with an invalid load ordering.
+#[cfg(debug_assertions)]
+struct InvalidLoad;
+
+#[cfg(debug_assertions)]
+impl OrderingPolicy for InvalidLoad {
+    const LOAD: Ordering = Ordering::Release;
+}
+
+#[test]
+fn test_default_policy_orderings() {
+    assert_eq!(DefaultOrdering::LOAD, Ordering::Acquire);
+    assert_eq!(DefaultOrdering::STORE, Ordering::Release);
+    assert_eq!(DefaultOrdering::RMW, Ordering::AcqRel);
+    assert_eq!(DefaultOrdering::CAS_SUCCESS, Ordering::AcqRel);
+    assert_eq!(DefaultOrdering::CAS_FAILURE, Ordering::Acquire);
+    assert_eq!(DefaultOrdering::ARITHMETIC, Ordering::Relaxed);
+    assert_eq!(DefaultOrdering::BIT_OP, Ordering::AcqRel);
+    assert_eq!(DefaultOrdering::MAX_MIN, Ordering::AcqRel);
+}
+
+#[test]
+fn test_builtin_policy_orderings() {
+    assert_eq!(AcqRel::ARITHMETIC, Ordering::AcqRel);
+    assert_eq!(AcqRel::LOAD, Ordering::Acquire);
+    assert_eq!(SeqCst::LOAD, Ordering::SeqCst);
+    assert_eq!(SeqCst::ARITHMETIC, Ordering::SeqCst);
+    assert_eq!(SeqCst::CAS_FAILURE, Ordering::SeqCst);
+    assert_eq!(RelaxedCounter::STORE, Ordering::Relaxed);
+    assert_eq!(RelaxedCounter::MAX_MIN, Ordering::Relaxed);
+    assert_eq!(PublishingCounter::ARITHMETIC, Ordering::AcqRel);
+    assert_eq!(PublishingCounter::LOAD, Ordering::Acquire);
+}
+
+#[test]
+fn test_integer_with_seq_cst_policy() {
+    let atomic = AtomicI64::<SeqCst>::with_policy(10);
+    assert_eq!(atomic.fetch_inc(), 10);
+    assert_eq!(atomic.fetch_add(5), 11);
+    assert_eq!(atomic.fetch_and(0b1111), 16);
+    assert_eq!(atomic.fetch_max(20), 0);
+    assert!(atomic.compare_set(20, 30).is_ok());
+    assert_eq!(atomic.fetch_update(|x| x * 2), 30);
+    assert_eq!(atomic.swap(1), 60);
+    atomic.store(2);
+    assert_eq!(atomic.load(), 2);
+}
+
+#[test]
+fn test_integer_with_relaxed_counter_policy() {
+    let counter = Arc::new(AtomicU64::<RelaxedCounter>::with_policy(0));
+    let mut handles = vec![];
+
+    for _ in 0..10 {
+        let counter = Arc::clone(&counter);
+        let handle = thread::spawn(move || {
+            for _ in 0..100 {
+                counter.fetch_inc();
+            }
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(counter.load(), 1000);
+}
+
+#[test]
+fn test_integer_with_custom_policy() {
+    let atomic = AtomicU32::<PublishingCounter>::with_policy(0);
+    atomic.fetch_inc();
+    atomic.fetch_add(2);
+    assert_eq!(atomic.load(), 3);
+}
+
+#[test]
+fn test_float_with_policy() {
+    let atomic = AtomicF64::<SeqCst>::with_policy(1.5);
+    assert_eq!(atomic.fetch_add(1.0), 1.5);
+    assert_eq!(atomic.fetch_mul(2.0), 2.5);
+    assert_eq!(atomic.load(), 5.0);
+
+    let atomic = AtomicF32::<RelaxedCounter>::with_policy(1.0);
+    assert_eq!(atomic.fetch_sub(0.5), 1.0);
+    assert_eq!(atomic.swap(3.0), 0.5);
+    assert_eq!(atomic.load(), 3.0);
+}
+
+#[test]
+fn test_bool_with_policy() {
+    let flag = AtomicBool::<SeqCst>::with_policy(false);
+    assert!(!flag.fetch_set());
+    assert!(flag.fetch_xor(true));
+    assert!(flag.compare_set(false, true).is_ok());
+    assert!(flag.load());
+
+    let flag = AtomicBool::<RelaxedCounter>::with_policy(true);
+    assert!(flag.fetch_clear());
+    assert!(!flag.load());
+}
+
+#[test]
+fn test_ref_with_policy() {
+    let atomic = AtomicRef::<_, SeqCst>::with_policy(Arc::new(1));
+    let old = atomic.swap(Arc::new(2));
+    assert_eq!(*old, 1);
+    let current = atomic.load();
+    assert!(atomic.compare_set(&current, Arc::new(3)).is_ok());
+    assert_eq!(*atomic.fetch_update(|x| Arc::new(**x + 1)), 3);
+    assert_eq!(*atomic.load(), 4);
+
+    let cloned = atomic.clone();
+    assert_eq!(*cloned.load(), 4);
+
+    let atomic = AtomicRef::<_, AcqRel>::with_policy(Arc::new("a"));
+    atomic.store(Arc::new("b"));
+    assert_eq!(*atomic.load(), "b");
+}
+
+#[test]
+fn test_policy_is_zero_cost() {
+    use std::mem::size_of;
+
+    assert_eq!(size_of::<AtomicI64<SeqCst>>(), size_of::<i64>());
+    assert_eq!(size_of::<AtomicF64<RelaxedCounter>>(), size_of::<f64>());
+    assert_eq!(size_of::<AtomicBool<AcqRel>>(), size_of::<bool>());
+}
+
+#[test]
+fn test_generic_code_over_policies() {
+    fn bump<A: AtomicNumber<Value = i64>>(atomic: &A) -> i64 {
+        atomic.fetch_add(1);
+        atomic.load()
+    }
+
+    fn publish<P: PublishingPolicy>(atomic: &AtomicRef<i32, P>, value: i32) {
+        atomic.store(Arc::new(value));
+    }
+
+    assert_eq!(bump(&AtomicI64::new(0)), 1);
+    assert_eq!(bump(&AtomicI64::<SeqCst>::with_policy(1)), 2);
+    assert_eq!(bump(&AtomicI64::<RelaxedCounter>::with_policy(2)), 3);
+
+    let atomic = AtomicRef::<i32, SeqCst>::with_policy(Arc::new(0));
+    publish(&atomic, 5);
+    assert_eq!(*atomic.load(), 5);
+}
+
+#[test]
+fn test_debug_and_display_with_policy() {
+    let atomic = AtomicI64::<SeqCst>::with_policy(42);
+    assert_eq!(format!("{}", atomic), "42");
+    assert!(format!("{:?}", atomic).contains("42"));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "invalid memory ordering for a load")]
+fn test_invalid_policy_panics() {
+    let atomic = AtomicI64::<InvalidLoad>::with_policy(0);
+    atomic.load();
+}
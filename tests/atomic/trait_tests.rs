@@ -142,6 +142,12 @@ fn test_atomic_number_trait_via_generic<T: AtomicNumber<Value = i32>>(atomic: &T
 
     let old = atomic.fetch_add(5);
     assert_eq!(old, 10); // returns old value
+
+    assert_eq!(atomic.inc_and_get(), 16);
+    assert_eq!(atomic.dec_and_get(), 15);
+    assert_eq!(atomic.max_and_get(20), 20);
+    assert_eq!(atomic.min_and_get(-3), -3);
+    assert_eq!(atomic.accumulate_and_get(4, |a, b| a * b), -12);
 }
 
 #[test]
@@ -322,3 +328,33 @@ fn test_debug_display_traits() {
     assert!(format!("{:?}", atomic_f64).contains("3.14"));
     assert!(format!("{}", atomic_f64).contains("3.14"));
 }
+
+// Test fallible updates through the Atomic trait
+#[test]
+fn test_try_update_via_generic() {
+    fn take_token<T: Atomic<Value = i32>>(atomic: &T) -> Result<i32, i32> {
+        atomic.try_update(|x| (x > 0).then(|| x - 1))
+    }
+
+    let atomic = AtomicI32::new(1);
+    assert_eq!(take_token(&atomic), Ok(1));
+    assert_eq!(take_token(&atomic), Err(0));
+    assert_eq!(atomic.load(), 0);
+}
+
+// Test FnMut closures through the Atomic trait
+#[test]
+fn test_fn_mut_via_generic() {
+    fn bump_and_count<T: Atomic<Value = i32>>(atomic: &T) -> usize {
+        let mut calls = 0;
+        atomic.fetch_update(|x| {
+            calls += 1;
+            x + 1
+        });
+        calls
+    }
+
+    let atomic = AtomicI32::new(0);
+    assert!(bump_and_count(&atomic) >= 1);
+    assert_eq!(atomic.load(), 1);
+}